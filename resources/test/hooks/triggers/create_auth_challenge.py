@@ -0,0 +1,7 @@
+def handler(event, context):
+    attempt = len(event["request"]["session"]) + 1
+    response = event["response"]
+    response["publicChallengeParameters"] = {"attempt": str(attempt)}
+    response["privateChallengeParameters"] = {"answer": "answer-%d" % attempt}
+    response["challengeMetadata"] = "MAGIC_LINK_%d" % attempt
+    return event
@@ -0,0 +1,13 @@
+def handler(event, context):
+    request = event["request"]
+    response = event["response"]
+    brand = request["clientMetadata"].get("brand", "Example")
+    if event["triggerSource"] == "CustomMessage_AdminCreateUser":
+        response["emailSubject"] = "Welcome to " + brand
+        response["emailMessage"] = "Sign in as {} with {}".format(
+            request["usernameParameter"], request["codeParameter"]
+        )
+    else:
+        response["emailSubject"] = brand + " verification"
+        response["emailMessage"] = "<p>{} is your code.</p>".format(request["codeParameter"])
+    return event
@@ -0,0 +1,14 @@
+def handler(event, context):
+    session = event["request"]["session"]
+    response = event["response"]
+    if session and session[-1]["challengeResult"]:
+        response["issueTokens"] = True
+        response["failAuthentication"] = False
+    elif len(session) >= 3:
+        response["issueTokens"] = False
+        response["failAuthentication"] = True
+    else:
+        response["issueTokens"] = False
+        response["failAuthentication"] = False
+        response["challengeName"] = "CUSTOM_CHALLENGE"
+    return event
@@ -0,0 +1,4 @@
+def handler(event, context):
+    if event["request"]["clientMetadata"].get("fail") == "true":
+        raise Exception("Post confirmation failed")
+    return event
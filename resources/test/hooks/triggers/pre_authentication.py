@@ -0,0 +1,4 @@
+def handler(event, context):
+    if event["request"]["validationData"].get("blocked") == "true":
+        raise Exception("User is blocked")
+    return event
@@ -0,0 +1,7 @@
+def handler(event, context):
+    if event["request"]["validationData"].get("deny") == "true":
+        raise Exception("Sign up is denied")
+    response = event["response"]
+    response["autoConfirmUser"] = True
+    response["autoVerifyEmail"] = "email" in event["request"]["userAttributes"]
+    return event
@@ -0,0 +1,16 @@
+def handler(event, context):
+    request = event["request"]
+    response = event["response"]
+    if event["triggerSource"] == "UserMigration_Authentication":
+        if request["password"] != "Legacy0rd!":
+            raise Exception("Bad password")
+        response["finalUserStatus"] = "CONFIRMED"
+    elif "finalUserStatus" in request["clientMetadata"]:
+        response["finalUserStatus"] = request["clientMetadata"]["finalUserStatus"]
+    response["userAttributes"] = {
+        "email": "legacy@example.com",
+        "email_verified": "true",
+    }
+    response["messageAction"] = request["clientMetadata"].get("messageAction", "SUPPRESS")
+    response["desiredDeliveryMediums"] = ["EMAIL"]
+    return event
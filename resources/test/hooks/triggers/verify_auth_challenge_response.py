@@ -0,0 +1,5 @@
+def handler(event, context):
+    request = event["request"]
+    expected = request["privateChallengeParameters"]["answer"]
+    event["response"]["answerCorrect"] = request["challengeAnswer"] == expected
+    return event
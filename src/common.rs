@@ -6,6 +6,31 @@ pub fn is_blank(value: &Option<String>) -> bool {
     value.as_ref().unwrap_or(&"".to_string()).is_empty()
 }
 
+/// Returns json value without null object fields (recursively).
+pub fn without_nulls(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, without_nulls(v)))
+                .collect(),
+        ),
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(without_nulls).collect())
+        }
+        v => v,
+    }
+}
+
+/// Returns random string consisted of given characters.
+pub fn random_string(chars: &[u8], len: usize) -> String {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    (0..len)
+        .map(|_| chars[rng.gen_range(0..chars.len())] as char)
+        .collect()
+}
+
 /// Arn regex
 pub static ARN_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"arn:[\w+=/,.@-]+:[\w+=/,.@-]+:([\w+=/,.@-]*)?:[0-9]+:[\w+=/,.@-]+(:[\w+=/,.@-]+)?(:[\w+=/,.@-]+)?").unwrap()
@@ -54,6 +79,7 @@ pub static URL_REGEX: Lazy<Regex> =
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn none_is_blank() {
@@ -69,4 +95,20 @@ mod tests {
     fn string_is_not_blank() {
         assert!(!is_blank(&Some("some".to_string())));
     }
+
+    #[test]
+    fn removes_null_fields() {
+        let value = serde_json::json!({"a": null, "b": {"c": null, "d": 1}, "e": [{"f": null}]});
+        assert_eq!(
+            serde_json::json!({"b": {"d": 1}, "e": [{}]}),
+            without_nulls(value)
+        );
+    }
+
+    #[test]
+    fn random_string_has_length() {
+        let s = random_string(b"abc", 10);
+        assert_eq!(10, s.len());
+        assert!(s.chars().all(|c| "abc".contains(c)));
+    }
 }
@@ -4,6 +4,7 @@ use pyo3::types::PyList;
 use std::path::Path;
 
 const DEFAULT_HOOK_DIR_PATH: &str = "hooks";
+const TRIGGER_PACKAGE_NAME: &str = "triggers";
 
 pub fn call_request_hook<T>(
     action: &str,
@@ -21,23 +22,56 @@ where
     }
 
     Python::with_gil(|py| {
-        let syspath: &PyList = py
-            .import("sys")
-            .unwrap()
-            .getattr("path")
-            .unwrap()
-            .try_into()
-            .unwrap();
-        // error[E0277]: the trait bound `std::path::Display<'_>: pyo3::conversion::ToPyObject` is not satisfied
-        // syspath.insert(0, path.display()).unwrap();
-        syspath.insert(0, dir).unwrap();
-
+        add_sys_path(py, dir)?;
         let hook = py.import(format!("{}", &pyname).as_str())?;
         let arg = serde_json::to_string(value).unwrap_or_else(|_| "".to_string());
         hook.getattr("hook")?.call1((arg,))?.extract()
     })
 }
 
+/// Returns the python module name of the lambda function ARN
+/// (e.g. `define_auth` of `arn:aws:lambda:us-east-1:123456789012:function:define-auth:1`).
+pub fn trigger_module_name(arn: &str) -> String {
+    let function = arn.split(":function:").nth(1).unwrap_or(arn);
+    let name = function.split(':').next().unwrap_or(function);
+    AsSnakeCase(name).to_string()
+}
+
+/// Adds the directory to the head of `sys.path` unless it is already there.
+fn add_sys_path(py: Python, dir: &str) -> PyResult<()> {
+    let syspath: &PyList = py.import("sys")?.getattr("path")?.downcast()?;
+    if !syspath.contains(dir)? {
+        syspath.insert(0, dir)?;
+    }
+    Ok(())
+}
+
+/// Calls a `handler` function of the lambda function python script in `triggers` directory of hooks
+/// like Lambda (`handler(event, context)` returning the event), and returns the result json string.
+///
+/// Returns `None` when the script does not exist.
+pub fn call_trigger_hook(
+    arn: &str,
+    event: &str,
+    hooks_dir: Option<&str>,
+) -> Result<Option<String>, PyErr> {
+    let module_name = trigger_module_name(arn);
+    let dir = hooks_dir.unwrap_or(DEFAULT_HOOK_DIR_PATH);
+    let path = format!("{}/{}/{}.py", dir, TRIGGER_PACKAGE_NAME, &module_name);
+    if !Path::new(&path).exists() {
+        return Ok(None);
+    }
+
+    Python::with_gil(|py| {
+        add_sys_path(py, dir)?;
+        let json = py.import("json")?;
+        let trigger = py.import(format!("{}.{}", TRIGGER_PACKAGE_NAME, &module_name).as_str())?;
+        let event = json.getattr("loads")?.call1((event,))?;
+        let result = trigger.getattr("handler")?.call1((event, py.None()))?;
+        json.getattr("dumps")?.call1((result,))?.extract().map(Some)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,4 +83,52 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!("{ \"foo\": \"bar\" }", result.unwrap());
     }
+
+    #[test]
+    fn trigger_module_name_of_arn() {
+        assert_eq!(
+            "define_auth",
+            trigger_module_name("arn:aws:lambda:us-east-1:123456789012:function:define-auth:1")
+        );
+        assert_eq!(
+            "pre_sign_up",
+            trigger_module_name("arn:aws:lambda:us-east-1:123456789012:function:PreSignUp")
+        );
+    }
+
+    #[test]
+    fn call_trigger_hook_test() {
+        let hooks_dir = Some("resources/test/hooks");
+        let arn = "arn:aws:lambda:us-east-1:123456789012:function:define-auth-challenge";
+        let result = call_trigger_hook(
+            arn,
+            r#"{"request":{"session":[]},"response":{}}"#,
+            hooks_dir,
+        );
+        let event: serde_json::Value = serde_json::from_str(&result.unwrap().unwrap()).unwrap();
+        assert_eq!("CUSTOM_CHALLENGE", event["response"]["challengeName"]);
+
+        let arn = "arn:aws:lambda:us-east-1:123456789012:function:unknown";
+        assert_eq!(None, call_trigger_hook(arn, "{}", hooks_dir).unwrap());
+    }
+
+    #[test]
+    fn sys_path_is_added_once() {
+        Python::with_gil(|py| {
+            add_sys_path(py, "resources/test/hooks").unwrap();
+            add_sys_path(py, "resources/test/hooks").unwrap();
+            let syspath: &PyList = py
+                .import("sys")
+                .unwrap()
+                .getattr("path")
+                .unwrap()
+                .downcast()
+                .unwrap();
+            let count = syspath
+                .iter()
+                .filter(|p| p.extract::<&str>().ok() == Some("resources/test/hooks"))
+                .count();
+            assert_eq!(1, count);
+        });
+    }
 }
@@ -1,4 +1,5 @@
 use fakey_cognito::*;
+use warp::Filter;
 
 const DEFAULT_LOG_LEVEL: &str = "info";
 const LOG_LEVEL_KEY: &str = "RUST_LOG";
@@ -17,14 +18,19 @@ async fn main() {
     opts::init_opt().await;
     setup_logger();
     let templates_opt = opts::get_opt_templates();
-    tokio::join!(
+    let (_, _, _, signing_key) = tokio::join!(
         user_pools::init_config(opts::get_opt_config()),
         templates::init_template(templates_opt.map(String::as_str)),
-        templates::init_default_template()
+        templates::init_default_template(),
+        user_pools::init_signing_key(opts::get_opt_signing_key())
     );
+    if let Err(e) = signing_key {
+        log::error!("{}", e);
+        std::process::exit(1);
+    }
 
     let port = opts::get_opt_port().unwrap_or(8080);
-    warp::serve(routes::user_pools_routes())
+    warp::serve(routes::user_pools_routes().or(routes::oauth2_routes()))
         .run(([0, 0, 0, 0], port))
         .await;
 }
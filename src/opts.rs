@@ -26,15 +26,37 @@ pub struct Opt {
     /// Log level (debug, info, warn, error)
     #[structopt(long, name = "level")]
     pub log_level: Option<String>,
+
+    /// Region used for generated resource ids (default: us-east-1)
+    #[structopt(long, name = "region")]
+    pub region: Option<String>,
+
+    /// Read specific RSA private key (PEM) path for signing tokens
+    #[structopt(long, name = "signing_key_path", parse(from_os_str))]
+    pub signing_key: Option<PathBuf>,
+
+    /// Base url of this server used for token issuer (default: http://localhost:<port>)
+    #[structopt(long, name = "base_url")]
+    pub base_url: Option<String>,
+
+    /// Fixed unix time for verifying TOTP codes (for deterministic codes in tests)
+    #[structopt(long, name = "unix_time")]
+    pub totp_time: Option<i64>,
 }
 
 pub async fn init_opt() {
     OPT.get_or_init(|| async move { Opt::from_args() }).await;
 }
 
+/// Hooks directory of test fixtures.
+const TEST_HOOKS_DIR_PATH: &str = "resources/test/hooks";
+
+/// Initializes options for tests.
 pub async fn init_fake_opt() {
-    OPT.get_or_init(|| async move { Opt::from_iter(Vec::<String>::new()) })
-        .await;
+    OPT.get_or_init(|| async move {
+        Opt::from_iter(vec!["fakey-cognito", "--hooks", TEST_HOOKS_DIR_PATH])
+    })
+    .await;
 }
 
 pub fn get_opt_config() -> Option<&'static PathBuf> {
@@ -56,3 +78,19 @@ pub fn get_opt_port() -> Option<u16> {
 pub fn get_opt_log_level() -> Option<String> {
     OPT.get().unwrap().log_level.clone()
 }
+
+pub fn get_opt_region() -> Option<String> {
+    OPT.get().and_then(|o| o.region.clone())
+}
+
+pub fn get_opt_signing_key() -> Option<&'static PathBuf> {
+    OPT.get().and_then(|o| o.signing_key.as_ref())
+}
+
+pub fn get_opt_base_url() -> Option<String> {
+    OPT.get().and_then(|o| o.base_url.clone())
+}
+
+pub fn get_opt_totp_time() -> Option<i64> {
+    OPT.get().and_then(|o| o.totp_time)
+}
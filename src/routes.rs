@@ -1,4 +1,5 @@
 use crate::common;
+use crate::templates;
 use crate::user_pools;
 use crate::user_pools::UserPoolsResponseResult;
 use bytes::Bytes;
@@ -104,6 +105,38 @@ fn post_action_routes(action: &str, body: &Bytes) -> UserPoolsResponseResult {
         user_pools::CREATE_USER_IMPORT_JOB_ACTION_NAME => user_pools::CreateUserImportJobRequest,
         user_pools::CREATE_USER_POOL_ACTION_NAME => user_pools::CreateUserPoolRequest,
         user_pools::CREATE_USER_POOL_CLIENT_ACTION_NAME => user_pools::CreateUserPoolClientRequest,
+        user_pools::DELETE_GROUP_ACTION_NAME => user_pools::DeleteGroupRequest,
+        user_pools::DELETE_USER_ACTION_NAME => user_pools::DeleteUserRequest,
+        user_pools::DELETE_USER_ATTRIBUTES_ACTION_NAME => user_pools::DeleteUserAttributesRequest,
+        user_pools::DELETE_USER_POOL_ACTION_NAME => user_pools::DeleteUserPoolRequest,
+        user_pools::DELETE_USER_POOL_CLIENT_ACTION_NAME => user_pools::DeleteUserPoolClientRequest,
+        user_pools::DESCRIBE_USER_POOL_ACTION_NAME => user_pools::DescribeUserPoolRequest,
+        user_pools::DESCRIBE_USER_POOL_CLIENT_ACTION_NAME => user_pools::DescribeUserPoolClientRequest,
+        user_pools::FORGOT_PASSWORD_ACTION_NAME => user_pools::ForgotPasswordRequest,
+        user_pools::GET_GROUP_ACTION_NAME => user_pools::GetGroupRequest,
+        user_pools::GET_USER_ACTION_NAME => user_pools::GetUserRequest,
+        user_pools::GET_USER_ATTRIBUTE_VERIFICATION_CODE_ACTION_NAME => user_pools::GetUserAttributeVerificationCodeRequest,
+        user_pools::GLOBAL_SIGN_OUT_ACTION_NAME => user_pools::GlobalSignOutRequest,
+        user_pools::INITIATE_AUTH_ACTION_NAME => user_pools::InitiateAuthRequest,
+        user_pools::LIST_GROUPS_ACTION_NAME => user_pools::ListGroupsRequest,
+        user_pools::LIST_IDENTITY_PROVIDERS_ACTION_NAME => user_pools::ListIdentityProvidersRequest,
+        user_pools::LIST_RESOURCE_SERVERS_ACTION_NAME => user_pools::ListResourceServersRequest,
+        user_pools::LIST_USER_IMPORT_JOBS_ACTION_NAME => user_pools::ListUserImportJobsRequest,
+        user_pools::LIST_USER_POOL_CLIENTS_ACTION_NAME => user_pools::ListUserPoolClientsRequest,
+        user_pools::LIST_USER_POOLS_ACTION_NAME => user_pools::ListUserPoolsRequest,
+        user_pools::LIST_USERS_ACTION_NAME => user_pools::ListUsersRequest,
+        user_pools::LIST_USERS_IN_GROUP_ACTION_NAME => user_pools::ListUsersInGroupRequest,
+        user_pools::RESEND_CONFIRMATION_CODE_ACTION_NAME => user_pools::ResendConfirmationCodeRequest,
+        user_pools::RESPOND_TO_AUTH_CHALLENGE_ACTION_NAME => user_pools::RespondToAuthChallengeRequest,
+        user_pools::REVOKE_TOKEN_ACTION_NAME => user_pools::RevokeTokenRequest,
+        user_pools::SET_USER_MFA_PREFERENCE_ACTION_NAME => user_pools::SetUserMFAPreferenceRequest,
+        user_pools::SIGN_UP_ACTION_NAME => user_pools::SignUpRequest,
+        user_pools::UPDATE_GROUP_ACTION_NAME => user_pools::UpdateGroupRequest,
+        user_pools::UPDATE_USER_ATTRIBUTES_ACTION_NAME => user_pools::UpdateUserAttributesRequest,
+        user_pools::UPDATE_USER_POOL_ACTION_NAME => user_pools::UpdateUserPoolRequest,
+        user_pools::UPDATE_USER_POOL_CLIENT_ACTION_NAME => user_pools::UpdateUserPoolClientRequest,
+        user_pools::VERIFY_SOFTWARE_TOKEN_ACTION_NAME => user_pools::VerifySoftwareTokenRequest,
+        user_pools::VERIFY_USER_ATTRIBUTE_ACTION_NAME => user_pools::VerifyUserAttributeRequest,
     )
 }
 
@@ -133,9 +166,201 @@ fn post_routes(
     post_action_routes(target.as_ref(), body)
 }
 
+/// Returns json response of the user pool well-known document.
+fn well_known_response<F>(user_pool_id: &str, document: F) -> warp::reply::Response
+where
+    F: FnOnce() -> serde_json::Value,
+{
+    use warp::Reply;
+    match user_pools::with_store(|store| store.user_pool(user_pool_id).map(|_| ())) {
+        Ok(_) => warp::reply::json(&document()).into_response(),
+        Err(e) => warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({ "message": e.message })),
+            warp::http::StatusCode::NOT_FOUND,
+        )
+        .into_response(),
+    }
+}
+
+/// GET routes for token verification (JWKS and OpenID Connect discovery).
+fn well_known_routes() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+{
+    let jwks = warp::path!(String / ".well-known" / "jwks.json")
+        .map(|user_pool_id: String| well_known_response(&user_pool_id, user_pools::jwks));
+    let openid_configuration =
+        warp::path!(String / ".well-known" / "openid-configuration").map(|user_pool_id: String| {
+            well_known_response(&user_pool_id, || {
+                user_pools::openid_configuration(&user_pool_id)
+            })
+        });
+    warp::get().and(jwks.or(openid_configuration))
+}
+
+/// Routes for inspecting the messages captured instead of sending email or SMS.
+fn outbox_routes() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let path = warp::path!("_outbox").and(warp::query::<user_pools::OutboxFilter>());
+    let find = warp::get()
+        .and(path)
+        .map(|filter: user_pools::OutboxFilter| {
+            user_pools::with_store(|store| {
+                let messages = store.outbox.find(&filter);
+                warp::reply::json(&serde_json::json!({ "Messages": messages }))
+            })
+        });
+    let remove = warp::delete()
+        .and(path)
+        .map(|filter: user_pools::OutboxFilter| {
+            user_pools::with_store(|store| store.outbox.remove(&filter));
+            warp::http::StatusCode::NO_CONTENT
+        });
+    find.or(remove)
+}
+
+/// Returns a redirect to the location.
+fn redirect_response(location: &str) -> warp::reply::Response {
+    use warp::Reply;
+    warp::reply::with_status(
+        warp::reply::with_header(warp::reply(), "location", location),
+        warp::http::StatusCode::FOUND,
+    )
+    .into_response()
+}
+
+/// Returns the OAuth error redirected to the client, or responded with the status.
+fn oauth_error_response(
+    error: user_pools::OAuthError,
+    status: warp::http::StatusCode,
+) -> warp::reply::Response {
+    use warp::Reply;
+    match error.redirect_uri.as_ref() {
+        Some(location) => redirect_response(location),
+        None => {
+            warp::reply::with_status(warp::reply::json(&error.to_json()), status).into_response()
+        }
+    }
+}
+
+/// Returns the sign-in page of the hosted UI keeping the authorization request in the form.
+fn login_page_response(
+    request: &user_pools::AuthorizeRequest,
+    error: Option<&str>,
+) -> warp::reply::Response {
+    use warp::Reply;
+    let context = serde_json::json!({ "error": error, "request": request });
+    match templates::render_page("login", &context) {
+        Some(page) => warp::reply::html(page).into_response(),
+        None => warp::http::StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+/// Hosted UI and OAuth 2.0 endpoints of user pool clients.
+pub fn oauth2_routes() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone
+{
+    use warp::http::StatusCode;
+    use warp::Reply;
+
+    let authorize = warp::get()
+        .and(warp::path!("oauth2" / "authorize"))
+        .and(warp::query::<user_pools::AuthorizeRequest>())
+        .map(|request: user_pools::AuthorizeRequest| {
+            match user_pools::check_authorize_request(&request) {
+                Ok(_) => redirect_response(&user_pools::login_url(&request)),
+                Err(e) => oauth_error_response(e, StatusCode::BAD_REQUEST),
+            }
+        });
+    let login_page = warp::get()
+        .and(warp::path!("login"))
+        .and(warp::query::<user_pools::AuthorizeRequest>())
+        .map(|request: user_pools::AuthorizeRequest| {
+            match user_pools::check_authorize_request(&request) {
+                Ok(_) => login_page_response(&request, None),
+                Err(e) => oauth_error_response(e, StatusCode::BAD_REQUEST),
+            }
+        });
+    let login = warp::post()
+        .and(warp::path!("login"))
+        .and(warp::body::form::<user_pools::LoginForm>())
+        .map(
+            |form: user_pools::LoginForm| match user_pools::hosted_ui_sign_in(&form) {
+                Ok(location) => redirect_response(&location),
+                Err(user_pools::LoginError::SignIn(e)) => {
+                    login_page_response(&form.request, Some(&e.message))
+                }
+                Err(user_pools::LoginError::OAuth(e)) => {
+                    oauth_error_response(e, StatusCode::BAD_REQUEST)
+                }
+            },
+        );
+    let token = warp::post()
+        .and(warp::path!("oauth2" / "token"))
+        .and(warp::header::optional::<String>("authorization"))
+        .and(warp::body::form::<user_pools::TokenRequest>())
+        .map(
+            |authorization: Option<String>, request: user_pools::TokenRequest| {
+                match user_pools::grant_tokens(&request, authorization.as_deref()) {
+                    Ok(response) => warp::reply::json(&response).into_response(),
+                    Err(e) => oauth_error_response(e, StatusCode::BAD_REQUEST),
+                }
+            },
+        );
+    let user_info = warp::get()
+        .or(warp::post())
+        .unify()
+        .and(warp::path!("oauth2" / "userInfo"))
+        .and(warp::header::optional::<String>("authorization"))
+        .map(|authorization: Option<String>| {
+            match user_pools::user_info(authorization.as_deref()) {
+                Ok(info) => warp::reply::json(&info).into_response(),
+                Err(e) => {
+                    let authenticate = format!("Bearer error=\"{}\"", e.error);
+                    warp::reply::with_header(
+                        oauth_error_response(e, StatusCode::UNAUTHORIZED),
+                        "www-authenticate",
+                        authenticate,
+                    )
+                    .into_response()
+                }
+            }
+        });
+    let revoke = warp::post()
+        .and(warp::path!("oauth2" / "revoke"))
+        .and(warp::header::optional::<String>("authorization"))
+        .and(warp::body::form::<user_pools::RevokeRequest>())
+        .map(
+            |authorization: Option<String>, request: user_pools::RevokeRequest| {
+                match user_pools::revoke_oauth_token(&request, authorization.as_deref()) {
+                    Ok(_) => StatusCode::OK.into_response(),
+                    Err(e) => oauth_error_response(e, StatusCode::BAD_REQUEST),
+                }
+            },
+        );
+    let logout = warp::get()
+        .and(warp::path!("logout"))
+        .and(warp::query::<user_pools::LogoutRequest>())
+        .map(
+            |request: user_pools::LogoutRequest| match user_pools::logout_location(&request) {
+                Ok(location) => redirect_response(&location),
+                Err(e) => oauth_error_response(e, StatusCode::BAD_REQUEST),
+            },
+        );
+    let cors = warp::cors()
+        .allow_any_origin()
+        .allow_methods(vec!["GET", "POST"])
+        .allow_headers(vec!["authorization", "content-type"]);
+    authorize
+        .or(login_page)
+        .or(login)
+        .or(token)
+        .or(user_info)
+        .or(revoke)
+        .or(logout)
+        .with(cors)
+        .with(warp::log("info"))
+}
+
 pub fn user_pools_routes(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
-    warp::path::end()
+    let post = warp::path::end()
         .and(warp::post())
         .and(warp::body::bytes())
         .and(warp::header::optional::<String>(AWS_ACTION_TARGET_HEADER))
@@ -149,6 +374,8 @@ pub fn user_pools_routes(
             log::debug!("response headers: {:?}", &result.headers());
             log::debug!("response body: {:?}", &result.body());
             result
-        })
+        });
+    post.or(well_known_routes())
+        .or(outbox_routes())
         .with(warp::log("info"))
 }
@@ -52,14 +52,29 @@ where
     let map2: std::collections::HashMap<String, serde_json::Value> =
         serde_json::from_str(&additional_values).unwrap();
     let map = map.into_iter().chain(map2).collect();
+    render_with_default(file_name.as_str(), &map)
+}
+
+/// Renders the HTML page of the hosted UI, values are escaped by the `.html` extension.
+pub fn render_page(page_name: &str, context_value: &serde_json::Value) -> Option<String> {
+    let file_name = format!("oauth2/{}.html", page_name);
+    let map = serde_json::from_value(context_value.clone()).unwrap_or_default();
+    render_with_default(file_name.as_str(), &map)
+}
+
+/// Renders the user template, or the default template if the user template is not found.
+fn render_with_default(
+    file_name: &str,
+    context_values: &std::collections::HashMap<String, serde_json::Value>,
+) -> Option<String> {
     if let Some(o) = TEMPLATES.get() {
-        let t = render_template_internal(o, file_name.as_str(), &map);
+        let t = render_template_internal(o, file_name, context_values);
         if t.is_ok() {
             return t.ok();
         }
     }
     if let Some(o) = DEFAULT_TEMPLATES.get() {
-        return render_template_internal(o, file_name.as_str(), &map).ok();
+        return render_template_internal(o, file_name, context_values).ok();
     }
     log::error!("Template '{}' not found", file_name);
     None
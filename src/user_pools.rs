@@ -26,6 +26,7 @@ mod admin_update_device_status;
 mod admin_update_user_attributes;
 mod admin_user_global_sign_out;
 mod associate_software_token;
+mod auth;
 mod change_password;
 mod configs;
 mod confirm_device;
@@ -37,9 +38,54 @@ mod create_resource_server;
 mod create_user_import_job;
 mod create_user_pool;
 mod create_user_pool_client;
+mod custom_auth;
 mod data_types;
+mod delete_group;
+mod delete_user;
+mod delete_user_attributes;
+mod delete_user_pool;
+mod delete_user_pool_client;
+mod delivery;
+mod describe_user_pool;
+mod describe_user_pool_client;
 mod errors;
+mod forgot_password;
+mod get_group;
+mod get_user;
+mod get_user_attribute_verification_code;
+mod global_sign_out;
+mod initiate_auth;
+mod jwt;
+mod list_groups;
+mod list_identity_providers;
+mod list_resource_servers;
+mod list_user_import_jobs;
+mod list_user_pool_clients;
+mod list_user_pools;
+mod list_users;
+mod list_users_in_group;
+mod mfa;
+mod oauth2;
+mod outbox;
+mod pagination;
+mod password_policy;
+mod resend_confirmation_code;
+mod respond_to_auth_challenge;
 mod responses;
+mod revoke_token;
+mod set_user_mfa_preference;
+mod sign_up;
+mod srp;
+mod store;
+mod totp;
+mod triggers;
+mod update_group;
+mod update_user_attributes;
+mod update_user_pool;
+mod update_user_pool_client;
+mod user_filter;
+mod verify_software_token;
+mod verify_user_attribute;
 
 pub use self::add_custom_attributes::*;
 pub use self::admin_add_user_to_group::*;
@@ -79,8 +125,54 @@ pub use self::create_resource_server::*;
 pub use self::create_user_import_job::*;
 pub use self::create_user_pool::*;
 pub use self::create_user_pool_client::*;
+pub use self::delete_group::*;
+pub use self::delete_user::*;
+pub use self::delete_user_attributes::*;
+pub use self::delete_user_pool::*;
+pub use self::delete_user_pool_client::*;
+pub use self::describe_user_pool::*;
+pub use self::describe_user_pool_client::*;
+pub use self::forgot_password::*;
+pub use self::get_group::*;
+pub use self::get_user::*;
+pub use self::get_user_attribute_verification_code::*;
+pub use self::global_sign_out::*;
+pub use self::initiate_auth::*;
+pub use self::list_groups::*;
+pub use self::list_identity_providers::*;
+pub use self::list_resource_servers::*;
+pub use self::list_user_import_jobs::*;
+pub use self::list_user_pool_clients::*;
+pub use self::list_user_pools::*;
+pub use self::list_users::*;
+pub use self::list_users_in_group::*;
+pub use self::resend_confirmation_code::*;
+pub use self::respond_to_auth_challenge::*;
+pub use self::revoke_token::*;
+pub use self::set_user_mfa_preference::*;
+pub use self::sign_up::*;
+pub use self::update_group::*;
+pub use self::update_user_attributes::*;
+pub use self::update_user_pool::*;
+pub use self::update_user_pool_client::*;
+pub use self::verify_software_token::*;
+pub use self::verify_user_attribute::*;
 
+pub use self::auth::*;
 pub use self::configs::*;
+pub use self::custom_auth::*;
 pub use self::data_types::*;
+pub use self::delivery::*;
 pub use self::errors::*;
+pub use self::jwt::*;
+pub use self::mfa::*;
+pub use self::oauth2::*;
+pub use self::outbox::*;
+pub use self::pagination::*;
+pub use self::password_policy::*;
 pub use self::responses::*;
+pub use self::srp::*;
+pub use self::store::*;
+pub use self::totp::*;
+pub use self::triggers::*;
+pub use self::user_filter::*;
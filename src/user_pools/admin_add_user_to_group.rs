@@ -44,10 +44,21 @@ impl super::ToActionName for AdminAddUserToGroupRequest {
 impl super::ToResponse for AdminAddUserToGroupRequest {
     type E = AdminAddUserToGroupError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, admin_add_user_to_group)
     }
 }
 
+fn admin_add_user_to_group(request: &AdminAddUserToGroupRequest) -> Result<(), super::ActionError> {
+    let group_name = request.group_name.as_ref().unwrap();
+    super::with_store(|store| {
+        let pool = store.user_pool_mut(request.user_pool_id.as_ref().unwrap())?;
+        pool.group(group_name)?;
+        let user = pool.user_mut(request.username.as_ref().unwrap())?;
+        user.groups.insert(group_name.clone());
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -17,7 +17,7 @@ super::gen_response_err!(
     | ResourceNotFoundException
     | TooManyFailedAttemptsException
     | TooManyRequestsException
-    | UseLambdaValidationException
+    | UserLambdaValidationException
     | UserNotFoundException => http::status_code(400),
     InternalErrorException => http::status_code(500)
 );
@@ -45,10 +45,30 @@ impl super::ToActionName for AdminConfirmSignUpRequest {
 impl super::ToResponse for AdminConfirmSignUpRequest {
     type E = AdminConfirmSignUpError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, admin_confirm_sign_up)
     }
 }
 
+fn admin_confirm_sign_up(request: &AdminConfirmSignUpRequest) -> Result<(), super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let username = super::with_store(|store| {
+        let user = store.user_pool_mut(user_pool_id)?.user_mut(username)?;
+        super::check_unconfirmed(user)?;
+        user.codes.remove(&super::CodePurpose::SignUp);
+        user.status = super::UserStatus::CONFIRMED;
+        user.touch();
+        Ok::<_, super::ActionError>(user.username.clone())
+    })?;
+    super::post_confirmation(
+        user_pool_id,
+        None,
+        "PostConfirmation_ConfirmSignUp",
+        &username,
+        &request.client_metadata.clone().unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,10 +75,112 @@ impl super::ToActionName for AdminCreateUserRequest {
 impl super::ToResponse for AdminCreateUserRequest {
     type E = AdminCreateUserError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_CREATE_USER_NAME)
+        super::to_stateful_json_response(self, ADMIN_CREATE_USER_NAME, create_user)
     }
 }
 
+fn user_attributes(
+    request: &AdminCreateUserRequest,
+) -> Result<Vec<(String, String)>, super::ActionError> {
+    request
+        .user_attributes
+        .iter()
+        .flatten()
+        .map(|attr| match (attr.get("Name"), attr.get("Value")) {
+            (Some(name), value) => Ok((name.clone(), value.cloned().unwrap_or_default())),
+            _ => Err(super::ActionError::invalid_parameter(
+                "Attribute name is required.",
+            )),
+        })
+        .collect()
+}
+
+/// Returns deliveries of the invitation by `DesiredDeliveryMediums` (SMS by default).
+fn invitation_deliveries(
+    request: &AdminCreateUserRequest,
+    user: &super::User,
+) -> Vec<super::CodeDelivery> {
+    let default_mediums = vec!["SMS".to_string()];
+    request
+        .desired_delivery_mediums
+        .as_ref()
+        .unwrap_or(&default_mediums)
+        .iter()
+        .filter_map(|medium| match medium.as_str() {
+            "EMAIL" => super::CodeDelivery::for_attribute(user, super::EMAIL_ATTRIBUTE),
+            _ => super::CodeDelivery::for_attribute(user, super::PHONE_NUMBER_ATTRIBUTE),
+        })
+        .collect()
+}
+
+fn create_user(request: &AdminCreateUserRequest) -> Result<serde_json::Value, super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let attributes = user_attributes(request)?;
+    let temporary_password = request
+        .temporary_password
+        .clone()
+        .unwrap_or_else(super::generate_temporary_password);
+    let resend = request.message_action.as_deref() == Some("RESEND");
+    let mut user = super::with_store(|store| {
+        let pool = store.user_pool_mut(user_pool_id)?;
+        if request.temporary_password.is_some() {
+            super::check_password_policy(pool, &temporary_password)?;
+        }
+        if resend {
+            // resending the invitation resets the temporary password.
+            let user = pool.user_mut(username)?;
+            if user.status != super::UserStatus::FORCE_CHANGE_PASSWORD {
+                return Err(super::ActionError::new(
+                    "UnsupportedUserStateException",
+                    "Resend not possible. User is not in FORCE_CHANGE_PASSWORD state.",
+                ));
+            }
+            user.set_password(&temporary_password);
+            return Ok(user.clone());
+        }
+        let mut user = super::User::new(username);
+        user.status = super::UserStatus::FORCE_CHANGE_PASSWORD;
+        user.set_password(&temporary_password);
+        user.set_attributes(attributes)?;
+        super::set_username_attribute(pool, &mut user, username)?;
+        Ok(user)
+    })?;
+    if !resend {
+        let validation_data = request
+            .validation_data
+            .iter()
+            .flatten()
+            .filter_map(|attr| attr.get("Name").cloned().zip(attr.get("Value").cloned()))
+            .collect();
+        super::pre_sign_up(
+            user_pool_id,
+            None,
+            "PreSignUp_AdminCreateUser",
+            &user,
+            &validation_data,
+            &request.client_metadata.clone().unwrap_or_default(),
+        )?
+        .auto_verify(&mut user)?;
+        user =
+            super::with_store(|store| store.user_pool_mut(user_pool_id)?.add_user(user).cloned())?;
+    }
+
+    if request.message_action.as_deref() != Some("SUPPRESS") {
+        for delivery in invitation_deliveries(request, &user) {
+            super::send_message(
+                user_pool_id,
+                &user.username,
+                super::MessageType::AdminCreateUser,
+                &delivery,
+                &temporary_password,
+                &super::TriggerContext::new(None, request.client_metadata.as_ref()),
+            )?;
+        }
+    }
+    Ok(serde_json::json!({ "User": user.to_user_type() }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
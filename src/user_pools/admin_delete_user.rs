@@ -39,10 +39,21 @@ impl super::ToActionName for AdminDeleteUserRequest {
 impl super::ToResponse for AdminDeleteUserRequest {
     type E = AdminDeleteUserError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, delete_user)
     }
 }
 
+fn delete_user(request: &AdminDeleteUserRequest) -> Result<(), super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    super::with_store(|store| {
+        store
+            .user_pool_mut(user_pool_id)?
+            .remove_user(username)
+            .map(|_| ())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,7 +64,6 @@ mod tests {
         let request = AdminDeleteUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -63,7 +73,6 @@ mod tests {
         let request = AdminDeleteUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
@@ -4,8 +4,6 @@ use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 use validator::{Validate, ValidationError};
 
-use super::AdminAddUserToGroupError;
-
 pub const ADMIN_DELETE_USER_ATTRIBUTES_NAME: &str = "AdminDeleteUserAttributes";
 pub const ADMIN_DELETE_USER_ATTRIBUTES_ACTION_NAME: &str =
     "AWSCognitoIdentityProviderService.AdminDeleteUserAttributes";
@@ -48,12 +46,26 @@ impl super::ToActionName for AdminDeleteUserAttributesRequest {
 }
 
 impl super::ToResponse for AdminDeleteUserAttributesRequest {
-    type E = AdminAddUserToGroupError;
+    type E = AdminDeleteUserAttributesError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, delete_user_attributes)
     }
 }
 
+fn delete_user_attributes(
+    request: &AdminDeleteUserAttributesRequest,
+) -> Result<(), super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let names = request.user_attribute_names.as_ref().unwrap();
+    super::with_store(|store| {
+        store
+            .user_pool_mut(user_pool_id)?
+            .user_mut(username)?
+            .delete_attributes(names)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,7 +77,6 @@ mod tests {
             user_attribute_names: Some(vec!["foo".to_string()]),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -76,7 +87,6 @@ mod tests {
             user_attribute_names: Some(vec!["foo".to_string()]),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
@@ -52,7 +52,6 @@ mod tests {
         let request = AdminDisableProviderForUserRequest {
             user: Some(Default::default()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -62,7 +61,6 @@ mod tests {
         let request = AdminDisableProviderForUserRequest {
             user: Some(Default::default()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
@@ -40,10 +40,21 @@ impl super::ToActionName for AdminDisableUserRequest {
 impl super::ToResponse for AdminDisableUserRequest {
     type E = AdminDisableUserError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, disable_user)
     }
 }
 
+fn disable_user(request: &AdminDisableUserRequest) -> Result<(), super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    super::with_store(|store| {
+        let user = store.user_pool_mut(user_pool_id)?.user_mut(username)?;
+        user.enabled = false;
+        user.touch();
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,7 +65,6 @@ mod tests {
         let request = AdminDisableUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -64,7 +74,6 @@ mod tests {
         let request = AdminDisableUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
@@ -60,7 +60,6 @@ mod tests {
             device_key: Some("device_key".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -71,7 +70,6 @@ mod tests {
             device_key: Some("device_key".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
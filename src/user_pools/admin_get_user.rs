@@ -39,10 +39,24 @@ impl super::ToActionName for AdminGetUserRequest {
 impl super::ToResponse for AdminGetUserRequest {
     type E = AdminGetUserError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_GET_USER_NAME)
+        super::to_stateful_json_response(self, ADMIN_GET_USER_NAME, get_user)
     }
 }
 
+fn get_user(request: &AdminGetUserRequest) -> Result<serde_json::Value, super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    super::with_store(|store| {
+        let user = store.user_pool(user_pool_id)?.user(username)?;
+        Ok(serde_json::json!({
+            "User": user.to_user_type(),
+            "MFAOptions": super::mfa_options(user),
+            "PreferredMfaSetting": user.preferred_mfa,
+            "UserMFASettingList": user.mfa_settings,
+        }))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,7 +67,6 @@ mod tests {
         let request = AdminGetUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -63,7 +76,6 @@ mod tests {
         let request = AdminGetUserRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
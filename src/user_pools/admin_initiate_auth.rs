@@ -8,7 +8,7 @@ pub const ADMIN_INITIATE_AUTH_NAME: &str = "AdminInitiateAuth";
 pub const ADMIN_INITIATE_AUTH_ACTION_NAME: &str =
     "AWSCognitoIdentityProviderService.AdminInitiateAuth";
 
-fn validate_auth_flow(value: &str) -> Result<(), ValidationError> {
+pub(crate) fn validate_auth_flow(value: &str) -> Result<(), ValidationError> {
     includes(
         value,
         vec![
@@ -73,10 +73,24 @@ impl super::ToActionName for AdminInitiateAuthRequest {
 impl super::ToResponse for AdminInitiateAuthRequest {
     type E = AdminInitiateAuthError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_INITIATE_AUTH_NAME)
+        super::to_stateful_json_response(self, ADMIN_INITIATE_AUTH_NAME, initiate_auth)
     }
 }
 
+fn initiate_auth(
+    request: &AdminInitiateAuthRequest,
+) -> Result<super::AuthResponse, super::ActionError> {
+    let auth_flow = request.auth_flow.as_ref().unwrap();
+    super::check_initiate_auth_method(auth_flow, true)?;
+    super::initiate_auth_flow(
+        request.user_pool_id.as_ref().unwrap(),
+        request.client_id.as_ref().unwrap(),
+        auth_flow,
+        &request.auth_parameters.clone().unwrap_or_default(),
+        &request.client_metadata.clone().unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
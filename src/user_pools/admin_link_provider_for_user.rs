@@ -55,7 +55,6 @@ mod tests {
             destination_user: Some(Default::default()),
             source_user: Some(Default::default()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -66,7 +65,6 @@ mod tests {
             destination_user: Some(Default::default()),
             source_user: Some(Default::default()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
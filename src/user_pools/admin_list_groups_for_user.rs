@@ -49,10 +49,42 @@ impl super::ToActionName for AdminListGroupsForUserRequest {
 impl super::ToResponse for AdminListGroupsForUserRequest {
     type E = AdminListGroupsForUserError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_LIST_GROUPS_FOR_USER_NAME)
+        super::to_stateful_json_response(
+            self,
+            ADMIN_LIST_GROUPS_FOR_USER_NAME,
+            admin_list_groups_for_user,
+        )
     }
 }
 
+fn admin_list_groups_for_user(
+    request: &AdminListGroupsForUserRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        let user = pool.user(request.username.as_ref().unwrap())?;
+        let page = super::paginate(
+            user.groups
+                .iter()
+                .filter_map(|name| pool.groups.get(name))
+                .collect(),
+            |group| group.name.clone(),
+            super::page_size(request.limit),
+            request.next_token.as_ref(),
+        )?;
+        let groups = page
+            .items
+            .iter()
+            .map(|group| group.to_group_type(user_pool_id))
+            .collect::<Vec<_>>();
+        Ok(serde_json::json!({
+            "Groups": groups,
+            "NextToken": page.next_token,
+        }))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
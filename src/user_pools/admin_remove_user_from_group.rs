@@ -44,10 +44,23 @@ impl super::ToActionName for AdminRemoveUserFromGroupRequest {
 impl super::ToResponse for AdminRemoveUserFromGroupRequest {
     type E = AdminRemoveUserFromGroupError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, admin_remove_user_from_group)
     }
 }
 
+fn admin_remove_user_from_group(
+    request: &AdminRemoveUserFromGroupRequest,
+) -> Result<(), super::ActionError> {
+    let group_name = request.group_name.as_ref().unwrap();
+    super::with_store(|store| {
+        let pool = store.user_pool_mut(request.user_pool_id.as_ref().unwrap())?;
+        pool.group(group_name)?;
+        let user = pool.user_mut(request.username.as_ref().unwrap())?;
+        user.groups.remove(group_name);
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,7 +72,6 @@ mod tests {
             group_name: Some("group_name".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -70,7 +82,6 @@ mod tests {
             group_name: Some("group_name".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
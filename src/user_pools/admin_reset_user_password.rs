@@ -48,10 +48,35 @@ impl super::ToActionName for AdminResetUserPasswordRequest {
 impl super::ToResponse for AdminResetUserPasswordRequest {
     type E = AdminResetUserPasswordError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, reset_user_password)
     }
 }
 
+fn reset_user_password(request: &AdminResetUserPasswordRequest) -> Result<(), super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let delivery = super::with_store(|store| {
+        let pool = store.user_pool_mut(user_pool_id)?;
+        // the code is sent only when the user has a verified email or phone number,
+        // otherwise the password must be set by AdminSetUserPassword.
+        let delivery = super::recovery_delivery(pool, pool.user(username)?).ok();
+        let user = pool.user_mut(username)?;
+        user.status = super::UserStatus::RESET_REQUIRED;
+        user.touch();
+        Ok::<_, super::ActionError>(delivery)
+    })?;
+    if let Some(delivery) = delivery {
+        super::send_code(
+            user_pool_id,
+            username,
+            super::MessageType::ForgotPassword,
+            &delivery,
+            &super::TriggerContext::new(None, request.client_metadata.as_ref()),
+        )?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
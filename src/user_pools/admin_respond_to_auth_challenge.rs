@@ -8,7 +8,7 @@ pub const ADMIN_RESPOND_TO_AUTH_CHALLENGE_NAME: &str = "AdminRespondToAuthChalle
 pub const ADMIN_RESPOND_TO_AUTH_CHALLENGE_ACTION_NAME: &str =
     "AWSCognitoIdentityProviderService.AdminRespondToAuthChallenge";
 
-fn validate_challenge_name(value: &str) -> Result<(), ValidationError> {
+pub(crate) fn validate_challenge_name(value: &str) -> Result<(), ValidationError> {
     includes(
         value,
         vec![
@@ -58,7 +58,7 @@ pub struct AdminRespondToAuthChallengeRequest {
     #[validate(length(min = 1))]
     #[validate(custom(function = "validate_challenge_name"))]
     pub challenge_name: Option<String>,
-    pub challenge_response: Option<std::collections::HashMap<String, String>>,
+    pub challenge_responses: Option<std::collections::HashMap<String, String>>,
     #[validate(required)]
     #[validate(length(min = 1, max = 128))]
     #[validate(regex = "CLIENT_ID_REGEX")]
@@ -82,10 +82,27 @@ impl super::ToActionName for AdminRespondToAuthChallengeRequest {
 impl super::ToResponse for AdminRespondToAuthChallengeRequest {
     type E = AdminRespondToAuthChallengeError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ADMIN_RESPOND_TO_AUTH_CHALLENGE_NAME)
+        super::to_stateful_json_response(
+            self,
+            ADMIN_RESPOND_TO_AUTH_CHALLENGE_NAME,
+            respond_to_auth_challenge,
+        )
     }
 }
 
+fn respond_to_auth_challenge(
+    request: &AdminRespondToAuthChallengeRequest,
+) -> Result<super::AuthResponse, super::ActionError> {
+    super::respond_to_challenge(
+        request.user_pool_id.as_ref().unwrap(),
+        request.client_id.as_ref().unwrap(),
+        request.challenge_name.as_ref().unwrap(),
+        &request.challenge_responses.clone().unwrap_or_default(),
+        request.session.as_deref(),
+        &request.client_metadata.clone().unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,10 +44,25 @@ impl super::ToActionName for AdminSetUserMFAPreferenceRequest {
 impl super::ToResponse for AdminSetUserMFAPreferenceRequest {
     type E = AdminSetUserMFAPreferenceError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, admin_set_user_mfa_preference)
     }
 }
 
+fn admin_set_user_mfa_preference(
+    request: &AdminSetUserMFAPreferenceRequest,
+) -> Result<(), super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    super::with_store(|store| {
+        let user = store.user_pool_mut(user_pool_id)?.user_mut(username)?;
+        super::set_mfa_preference(
+            user,
+            request.sms_mfa_settings.as_ref(),
+            request.software_token_mfa_settings.as_ref(),
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,8 +4,6 @@ use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 use validator::Validate;
 
-use super::AdminAddUserToGroupError;
-
 pub const ADMIN_SET_USER_PASSWORD_NAME: &str = "AdminSetUserPassword";
 pub const ADMIN_SET_USER_PASSWORD_ACTION_NAME: &str =
     "AWSCognitoIdentityProviderService.AdminSetUserPassword";
@@ -46,12 +44,30 @@ impl super::ToActionName for AdminSetUserPasswordRequest {
 }
 
 impl super::ToResponse for AdminSetUserPasswordRequest {
-    type E = AdminAddUserToGroupError;
+    type E = AdminSetUserPasswordError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, set_user_password)
     }
 }
 
+fn set_user_password(request: &AdminSetUserPasswordRequest) -> Result<(), super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let password = request.password.as_ref().unwrap();
+    super::with_store(|store| {
+        let pool = store.user_pool_mut(user_pool_id)?;
+        super::check_password_policy(pool, password)?;
+        let user = pool.user_mut(username)?;
+        user.set_password(password);
+        user.status = if request.permanent.unwrap_or(false) {
+            super::UserStatus::CONFIRMED
+        } else {
+            super::UserStatus::FORCE_CHANGE_PASSWORD
+        };
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,10 +52,21 @@ impl super::ToActionName for AdminSetUserSettingsRequest {
 impl super::ToResponse for AdminSetUserSettingsRequest {
     type E = AdminSetUserSettingsError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, admin_set_user_settings)
     }
 }
 
+fn admin_set_user_settings(
+    request: &AdminSetUserSettingsRequest,
+) -> Result<(), super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    super::with_store(|store| {
+        let user = store.user_pool_mut(user_pool_id)?.user_mut(username)?;
+        super::set_mfa_options(user, request.mfa_options.as_ref().unwrap())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,7 +78,6 @@ mod tests {
             mfa_options: Some(Default::default()),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -78,7 +88,6 @@ mod tests {
             mfa_options: Some(Default::default()),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
@@ -69,7 +69,6 @@ mod tests {
             feedback_value: Some("Valid".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -81,7 +80,6 @@ mod tests {
             feedback_value: Some("feedback_value".to_string()),
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
@@ -51,10 +51,34 @@ impl super::ToActionName for AdminUpdateUserAttributesRequest {
 impl super::ToResponse for AdminUpdateUserAttributesRequest {
     type E = AdminUpdateUserAttributesError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, update_user_attributes)
     }
 }
 
+fn update_user_attributes(
+    request: &AdminUpdateUserAttributesRequest,
+) -> Result<(), super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let attributes = request
+        .user_attributes
+        .iter()
+        .flatten()
+        .map(|attr| {
+            (
+                attr.name.clone().unwrap_or_default(),
+                attr.value.clone().unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+    super::with_store(|store| {
+        store
+            .user_pool_mut(user_pool_id)?
+            .user_mut(username)?
+            .set_attributes(attributes)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
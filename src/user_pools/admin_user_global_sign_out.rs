@@ -40,10 +40,22 @@ impl super::ToActionName for AdminUserGlobalSignOutRequest {
 impl super::ToResponse for AdminUserGlobalSignOutRequest {
     type E = AdminUserGlobalSignOutError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, admin_user_global_sign_out)
     }
 }
 
+fn admin_user_global_sign_out(
+    request: &AdminUserGlobalSignOutRequest,
+) -> Result<(), super::ActionError> {
+    super::with_store(|store| {
+        let user = store
+            .user_pool_mut(request.user_pool_id.as_ref().unwrap())?
+            .user_mut(request.username.as_ref().unwrap())?;
+        user.origin_jtis.clear();
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,7 +66,6 @@ mod tests {
         let request = AdminUserGlobalSignOutRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -64,7 +75,6 @@ mod tests {
         let request = AdminUserGlobalSignOutRequest {
             username: Some("username".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
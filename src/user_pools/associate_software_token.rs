@@ -35,10 +35,40 @@ impl super::ToActionName for AssociateSoftwareTokenRequest {
 impl super::ToResponse for AssociateSoftwareTokenRequest {
     type E = AssociateSoftwareTokenError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, ASSOCIATE_SOFTWARE_TOKEN_NAME)
+        super::to_stateful_json_response(
+            self,
+            ASSOCIATE_SOFTWARE_TOKEN_NAME,
+            associate_software_token,
+        )
     }
 }
 
+fn associate_software_token(
+    request: &AssociateSoftwareTokenRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| match (&request.access_token, &request.session) {
+        (Some(access_token), _) => {
+            let (user_pool_id, username) = super::verify_access_token(store, access_token)?;
+            let user = store.user_pool_mut(&user_pool_id)?.user_mut(&username)?;
+            let secret_code = super::associate_software_token(user);
+            Ok(serde_json::json!({ "SecretCode": secret_code, "Session": null }))
+        }
+        (None, Some(session)) => {
+            let mut session = super::take_mfa_setup_session(store, session)?;
+            let user = store
+                .user_pool_mut(&session.user_pool_id)?
+                .user_mut(&session.username)?;
+            let secret_code = super::associate_software_token(user);
+            session.renew();
+            let session = store.add_session(session);
+            Ok(serde_json::json!({ "SecretCode": secret_code, "Session": session }))
+        }
+        (None, None) => Err(super::ActionError::invalid_parameter(
+            "Either AccessToken or Session is required",
+        )),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
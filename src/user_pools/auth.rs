@@ -0,0 +1,1185 @@
+use super::{
+    ActionError, AuthSession, ClientMetadata, GroupOverrideDetails, SrpSession, Store,
+    TokenOverrides, User, UserPool, UserPoolClient, UserStatus,
+};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use std::collections::HashMap;
+
+const TOKEN_TYPE: &str = "Bearer";
+const USER_ATTRIBUTE_PREFIX: &str = "userAttributes.";
+const ACCESS_TOKEN_SCOPE: &str = "aws.cognito.signin.user.admin";
+const GROUPS_CLAIM: &str = "cognito:groups";
+const DEFAULT_EXPLICIT_AUTH_FLOWS: [&str; 3] = [
+    "ALLOW_USER_SRP_AUTH",
+    "ALLOW_REFRESH_TOKEN_AUTH",
+    "ALLOW_CUSTOM_AUTH",
+];
+
+pub type AuthParameters = HashMap<String, String>;
+
+/// Response of an auth flow step, either tokens or the next challenge.
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuthResponse {
+    pub authentication_result: Option<AuthenticationResultType>,
+    pub challenge_name: Option<String>,
+    pub challenge_parameters: HashMap<String, String>,
+    pub session: Option<String>,
+}
+
+impl AuthResponse {
+    pub fn authenticated(result: AuthenticationResultType) -> Self {
+        AuthResponse {
+            authentication_result: Some(result),
+            ..Default::default()
+        }
+    }
+}
+
+/// Authentication result, same as AuthenticationResultType.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuthenticationResultType {
+    pub access_token: String,
+    pub expires_in: i64,
+    pub id_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    pub token_type: String,
+}
+
+/// Origin of issued tokens with the scope of the access token and the nonce of the ID token.
+struct TokenGrant<'a> {
+    origin_jti: &'a str,
+    scope: &'a str,
+    nonce: Option<&'a str>,
+}
+
+/// Returns explicit auth flow names of the client enabling the auth flow.
+fn explicit_auth_flows_for(auth_flow: &str) -> Vec<&'static str> {
+    match auth_flow {
+        "ADMIN_USER_PASSWORD_AUTH" | "ADMIN_NO_SRP_AUTH" => {
+            vec!["ALLOW_ADMIN_USER_PASSWORD_AUTH", "ADMIN_NO_SRP_AUTH"]
+        }
+        "USER_PASSWORD_AUTH" => vec!["ALLOW_USER_PASSWORD_AUTH", "USER_PASSWORD_AUTH"],
+        "USER_SRP_AUTH" => vec!["ALLOW_USER_SRP_AUTH"],
+        "CUSTOM_AUTH" => vec!["ALLOW_CUSTOM_AUTH", "CUSTOM_AUTH_FLOW_ONLY"],
+        "REFRESH_TOKEN_AUTH" | "REFRESH_TOKEN" => vec!["ALLOW_REFRESH_TOKEN_AUTH"],
+        _ => vec![],
+    }
+}
+
+/// Checks the auth flow is enabled by `ExplicitAuthFlows` of the client.
+pub fn check_auth_flow(client: &UserPoolClient, auth_flow: &str) -> Result<(), ActionError> {
+    let enabled = match client.config.explicit_auth_flows.as_ref() {
+        Some(flows) if !flows.is_empty() => flows.iter().map(String::as_str).collect(),
+        _ => DEFAULT_EXPLICIT_AUTH_FLOWS.to_vec(),
+    };
+    if explicit_auth_flows_for(auth_flow)
+        .iter()
+        .any(|f| enabled.contains(f))
+    {
+        Ok(())
+    } else {
+        Err(ActionError::invalid_parameter(
+            "Auth flow not enabled for this client",
+        ))
+    }
+}
+
+/// Checks the auth flow is available in InitiateAuth (`admin` is `false`)
+/// or AdminInitiateAuth (`admin` is `true`).
+pub fn check_initiate_auth_method(auth_flow: &str, admin: bool) -> Result<(), ActionError> {
+    let supported = match auth_flow {
+        "ADMIN_USER_PASSWORD_AUTH" | "ADMIN_NO_SRP_AUTH" => admin,
+        "USER_PASSWORD_AUTH" => !admin,
+        _ => true,
+    };
+    if supported {
+        Ok(())
+    } else {
+        Err(ActionError::invalid_parameter(
+            "Initiate Auth method not supported.",
+        ))
+    }
+}
+
+/// Returns a required auth parameter.
+pub fn auth_parameter<'a>(params: &'a AuthParameters, name: &str) -> Result<&'a str, ActionError> {
+    params.get(name).map(String::as_str).ok_or_else(|| {
+        ActionError::invalid_parameter(&format!("Missing required parameter {}", name))
+    })
+}
+
+/// Returns `SECRET_HASH` for the client.
+pub fn secret_hash(client_secret: &str, username: &str, client_id: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(client_secret.as_bytes()).unwrap();
+    mac.update(username.as_bytes());
+    mac.update(client_id.as_bytes());
+    STANDARD.encode(mac.finalize().into_bytes())
+}
+
+/// Verifies `SECRET_HASH` when the client has a secret.
+pub fn verify_secret_hash(
+    client: &UserPoolClient,
+    username: &str,
+    hash: Option<&String>,
+) -> Result<(), ActionError> {
+    let secret = match client.client_secret.as_ref() {
+        Some(secret) => secret,
+        None => return Ok(()),
+    };
+    match hash {
+        Some(hash) if *hash == secret_hash(secret, username, &client.client_id) => Ok(()),
+        Some(_) => Err(ActionError::new(
+            "NotAuthorizedException",
+            &format!(
+                "Unable to verify secret hash for client {}",
+                client.client_id
+            ),
+        )),
+        None => Err(ActionError::new(
+            "NotAuthorizedException",
+            &format!(
+                "Client {} is configured for secret but secret was not received",
+                client.client_id
+            ),
+        )),
+    }
+}
+
+/// Checks the user can sign in.
+pub fn check_user_status(user: &User) -> Result<(), ActionError> {
+    if !user.enabled {
+        return Err(ActionError::new(
+            "NotAuthorizedException",
+            "User is disabled.",
+        ));
+    }
+    match user.status {
+        UserStatus::UNCONFIRMED => Err(ActionError::new(
+            "UserNotConfirmedException",
+            "User is not confirmed.",
+        )),
+        UserStatus::RESET_REQUIRED => Err(ActionError::new(
+            "PasswordResetRequiredException",
+            "Password reset required for the user",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn incorrect_username_or_password() -> ActionError {
+    ActionError::new("NotAuthorizedException", "Incorrect username or password.")
+}
+
+/// Authenticates the user with the password.
+pub fn authenticate_password<'a>(
+    pool: &'a UserPool,
+    username: &str,
+    password: &str,
+) -> Result<&'a User, ActionError> {
+    let user = pool.user(username)?;
+    if user.password.as_deref() != Some(password) {
+        return Err(incorrect_username_or_password());
+    }
+    check_user_status(user)?;
+    super::check_temporary_password(pool, user)?;
+    Ok(user)
+}
+
+/// Returns `true`/`false` attribute value as boolean claim.
+fn attribute_claim(name: &str, value: &str) -> serde_json::Value {
+    match (name.ends_with("_verified"), value) {
+        (true, "true") => serde_json::json!(true),
+        (true, "false") => serde_json::json!(false),
+        _ => serde_json::json!(value),
+    }
+}
+
+/// Issues ID, access and refresh tokens of a new origin for the user,
+/// customized by PreTokenGeneration trigger invoked with the trigger source.
+pub fn issue_tokens(
+    user_pool_id: &str,
+    client_id: &str,
+    username: &str,
+    trigger_source: &str,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthenticationResultType, ActionError> {
+    let overrides = super::pre_token_generation(
+        user_pool_id,
+        client_id,
+        trigger_source,
+        username,
+        &[ACCESS_TOKEN_SCOPE],
+        client_metadata,
+    )?;
+    super::with_store(|store| {
+        issue_origin_tokens(
+            store,
+            user_pool_id,
+            client_id,
+            username,
+            &overrides,
+            ACCESS_TOKEN_SCOPE,
+            None,
+        )
+    })
+}
+
+/// Issues ID, access and refresh tokens of a new origin for the user signed in to the hosted UI,
+/// the access token has the granted OAuth scopes.
+pub fn issue_hosted_ui_tokens(
+    user_pool_id: &str,
+    client_id: &str,
+    username: &str,
+    scopes: &[String],
+    nonce: Option<&str>,
+) -> Result<AuthenticationResultType, ActionError> {
+    let overrides = super::pre_token_generation(
+        user_pool_id,
+        client_id,
+        "TokenGeneration_HostedAuth",
+        username,
+        &scopes.iter().map(String::as_str).collect::<Vec<_>>(),
+        &ClientMetadata::new(),
+    )?;
+    super::with_store(|store| {
+        issue_origin_tokens(
+            store,
+            user_pool_id,
+            client_id,
+            username,
+            &overrides,
+            &scopes.join(" "),
+            nonce,
+        )
+    })
+}
+
+/// Signs tokens of a new origin for the user, and records the origin
+/// for refreshing and revoking them.
+fn issue_origin_tokens(
+    store: &mut Store,
+    user_pool_id: &str,
+    client_id: &str,
+    username: &str,
+    overrides: &TokenOverrides,
+    scope: &str,
+    nonce: Option<&str>,
+) -> Result<AuthenticationResultType, ActionError> {
+    let origin_jti = uuid::Uuid::new_v4().to_string();
+    let pool = store.user_pool(user_pool_id)?;
+    let grant = TokenGrant {
+        origin_jti: &origin_jti,
+        scope,
+        nonce,
+    };
+    let result = sign_tokens(
+        pool,
+        pool.client(client_id)?,
+        pool.user(username)?,
+        overrides,
+        &grant,
+        true,
+    );
+    store
+        .user_pool_mut(user_pool_id)?
+        .user_mut(username)?
+        .origin_jtis
+        .insert(origin_jti);
+    Ok(result)
+}
+
+/// Returns groups and IAM roles of the user, the preferred role is the role of the group with
+/// the lowest precedence unless other group with a role has the same precedence.
+pub fn group_configuration(pool: &UserPool, user: &User) -> GroupOverrideDetails {
+    let groups = pool.groups_of(user);
+    let with_roles = groups
+        .iter()
+        .filter(|group| group.role_arn.is_some())
+        .collect::<Vec<_>>();
+    let preferred_role = match with_roles.as_slice() {
+        [first, second, ..] if first.precedence == second.precedence => None,
+        [first, ..] => first.role_arn.clone(),
+        [] => None,
+    };
+    GroupOverrideDetails {
+        groups_to_override: Some(groups.iter().map(|group| group.name.clone()).collect()),
+        iam_roles_to_override: Some(
+            with_roles
+                .iter()
+                .filter_map(|group| group.role_arn.clone())
+                .collect(),
+        ),
+        preferred_role,
+    }
+}
+
+/// Sets `cognito:groups` and IAM role claims, empty groups remove the claim.
+fn set_group_claims(
+    details: &GroupOverrideDetails,
+    id_claims: &mut serde_json::Map<String, serde_json::Value>,
+    access_claims: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    if let Some(groups) = details.groups_to_override.as_ref() {
+        for claims in [&mut *id_claims, &mut *access_claims] {
+            if groups.is_empty() {
+                claims.remove(GROUPS_CLAIM);
+            } else {
+                claims.insert(GROUPS_CLAIM.to_string(), serde_json::json!(groups));
+            }
+        }
+    }
+    if let Some(roles) = details
+        .iam_roles_to_override
+        .as_ref()
+        .filter(|roles| !roles.is_empty())
+    {
+        id_claims.insert("cognito:roles".to_string(), serde_json::json!(roles));
+    }
+    if let Some(role) = details.preferred_role.as_ref() {
+        id_claims.insert(
+            "cognito:preferred_role".to_string(),
+            serde_json::json!(role),
+        );
+    }
+}
+
+fn sign_tokens(
+    pool: &UserPool,
+    client: &UserPoolClient,
+    user: &User,
+    overrides: &TokenOverrides,
+    grant: &TokenGrant,
+    with_refresh_token: bool,
+) -> AuthenticationResultType {
+    let now = Utc::now().timestamp();
+    let issuer = super::issuer(&pool.id);
+    let event_id = uuid::Uuid::new_v4().to_string();
+
+    let mut id_claims = serde_json::Map::new();
+    for (name, value) in user.attributes.iter() {
+        id_claims.insert(name.clone(), attribute_claim(name, value));
+    }
+    if let serde_json::Value::Object(claims) = serde_json::json!({
+        "iss": issuer,
+        "cognito:username": user.username,
+        "origin_jti": grant.origin_jti,
+        "aud": client.client_id,
+        "event_id": event_id,
+        "token_use": "id",
+        "auth_time": now,
+        "exp": now + client.id_token_validity().num_seconds(),
+        "iat": now,
+        "jti": uuid::Uuid::new_v4().to_string(),
+    }) {
+        id_claims.extend(claims);
+    }
+    if let Some(nonce) = grant.nonce {
+        id_claims.insert("nonce".to_string(), serde_json::json!(nonce));
+    }
+
+    let expires_in = client.access_token_validity().num_seconds();
+    let mut access_claims = serde_json::Map::new();
+    if let serde_json::Value::Object(claims) = serde_json::json!({
+        "sub": user.sub(),
+        "iss": issuer,
+        "client_id": client.client_id,
+        "origin_jti": grant.origin_jti,
+        "event_id": event_id,
+        "token_use": "access",
+        "scope": overrides.access_token.apply_scopes(grant.scope),
+        "auth_time": now,
+        "exp": now + expires_in,
+        "iat": now,
+        "jti": uuid::Uuid::new_v4().to_string(),
+        "username": user.username,
+    }) {
+        access_claims.extend(claims);
+    }
+    set_group_claims(
+        &group_configuration(pool, user),
+        &mut id_claims,
+        &mut access_claims,
+    );
+    if let Some(details) = overrides.group_override_details.as_ref() {
+        set_group_claims(details, &mut id_claims, &mut access_claims);
+    }
+    overrides.id_token.apply(&mut id_claims);
+    overrides.access_token.apply(&mut access_claims);
+
+    let refresh_token = with_refresh_token.then(|| {
+        super::sign(&serde_json::json!({
+            "sub": user.sub(),
+            "iss": issuer,
+            "client_id": client.client_id,
+            "origin_jti": grant.origin_jti,
+            "scope": grant.scope,
+            "token_use": "refresh",
+            "exp": now + client.refresh_token_validity().num_seconds(),
+            "iat": now,
+            "jti": uuid::Uuid::new_v4().to_string(),
+            "username": user.username,
+        }))
+    });
+
+    AuthenticationResultType {
+        access_token: super::sign(&serde_json::Value::Object(access_claims)),
+        expires_in,
+        id_token: super::sign(&serde_json::Value::Object(id_claims)),
+        refresh_token,
+        token_type: TOKEN_TYPE.to_string(),
+    }
+}
+
+/// Issues new ID and access tokens from the refresh token.
+fn refresh_tokens(
+    user_pool_id: &str,
+    client_id: &str,
+    refresh_token: &str,
+    params: &AuthParameters,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthenticationResultType, ActionError> {
+    let claims = super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        let client = pool.client(client_id)?;
+        let claims = verify_refresh_token(pool, client, refresh_token)?;
+        let username = claims["username"].as_str().unwrap_or_default();
+        verify_secret_hash(
+            client,
+            params.get("USERNAME").map_or(username, String::as_str),
+            params.get("SECRET_HASH"),
+        )?;
+        Ok::<_, ActionError>(claims)
+    })?;
+    reissue_tokens(user_pool_id, client_id, &claims, client_metadata)
+}
+
+fn invalid_refresh_token() -> ActionError {
+    ActionError::new("NotAuthorizedException", "Invalid Refresh Token")
+}
+
+/// Verifies the refresh token issued to the client by this server, and returns its claims.
+pub fn verify_refresh_token(
+    pool: &UserPool,
+    client: &UserPoolClient,
+    refresh_token: &str,
+) -> Result<serde_json::Value, ActionError> {
+    let claims = super::verify(refresh_token).map_err(|_| invalid_refresh_token())?;
+    if claims["token_use"] != "refresh"
+        || claims["client_id"] != client.client_id.as_str()
+        || claims["iss"] != super::issuer(&pool.id)
+        || !claims["username"].is_string()
+    {
+        return Err(invalid_refresh_token());
+    }
+    Ok(claims)
+}
+
+/// Checks the origin of the verified refresh token is not revoked and the user can sign in.
+fn check_refresh_origin<'a>(
+    pool: &'a UserPool,
+    claims: &serde_json::Value,
+) -> Result<&'a User, ActionError> {
+    let username = claims["username"].as_str().unwrap_or_default();
+    let user = pool.user(username).map_err(|_| invalid_refresh_token())?;
+    if !user
+        .origin_jtis
+        .contains(claims["origin_jti"].as_str().unwrap_or_default())
+    {
+        return Err(ActionError::new(
+            "NotAuthorizedException",
+            "Refresh Token has been revoked",
+        ));
+    }
+    check_user_status(user)?;
+    Ok(user)
+}
+
+/// Issues new ID and access tokens of the origin and scopes of the verified refresh token.
+pub fn reissue_tokens(
+    user_pool_id: &str,
+    client_id: &str,
+    claims: &serde_json::Value,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthenticationResultType, ActionError> {
+    let username = super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        check_refresh_origin(pool, claims).map(|user| user.username.clone())
+    })?;
+    let scope = claims["scope"].as_str().unwrap_or(ACCESS_TOKEN_SCOPE);
+    let overrides = super::pre_token_generation(
+        user_pool_id,
+        client_id,
+        "TokenGeneration_RefreshTokens",
+        &username,
+        &scope.split(' ').collect::<Vec<_>>(),
+        client_metadata,
+    )?;
+    super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        let user = check_refresh_origin(pool, claims)?;
+        let grant = TokenGrant {
+            origin_jti: claims["origin_jti"].as_str().unwrap_or_default(),
+            scope,
+            nonce: None,
+        };
+        Ok(sign_tokens(
+            pool,
+            pool.client(client_id)?,
+            user,
+            &overrides,
+            &grant,
+            false,
+        ))
+    })
+}
+
+/// Verifies the access token issued by this server,
+/// and returns the user pool id and the username of the token.
+pub fn verify_access_token(
+    store: &Store,
+    access_token: &str,
+) -> Result<(String, String), ActionError> {
+    verify_access_token_claims(store, access_token)
+        .map(|(user_pool_id, username, _)| (user_pool_id, username))
+}
+
+/// Same as `verify_access_token`, but also returns the claims of the token.
+pub fn verify_access_token_claims(
+    store: &Store,
+    access_token: &str,
+) -> Result<(String, String, serde_json::Value), ActionError> {
+    let invalid_token = || ActionError::new("NotAuthorizedException", "Invalid Access Token");
+    let claims = super::verify(access_token).map_err(|e| match e.kind() {
+        jsonwebtoken::errors::ErrorKind::ExpiredSignature => {
+            ActionError::new("NotAuthorizedException", "Access Token has expired")
+        }
+        _ => invalid_token(),
+    })?;
+    let issuer = claims["iss"].as_str().ok_or_else(invalid_token)?;
+    let user_pool_id = issuer.rsplit('/').next().unwrap_or_default();
+    if claims["token_use"] != "access" || super::issuer(user_pool_id) != issuer {
+        return Err(invalid_token());
+    }
+    let username = claims["username"].as_str().ok_or_else(invalid_token)?;
+    let user = store.user_pool(user_pool_id)?.user(username)?;
+    if !user
+        .origin_jtis
+        .contains(claims["origin_jti"].as_str().unwrap_or_default())
+    {
+        return Err(ActionError::new(
+            "NotAuthorizedException",
+            "Access Token has been revoked",
+        ));
+    }
+    if !user.enabled {
+        return Err(ActionError::new(
+            "NotAuthorizedException",
+            "User is disabled.",
+        ));
+    }
+    Ok((user_pool_id.to_string(), user.username.clone(), claims))
+}
+
+/// Returns `client_id` claim of the access token verified by `verify_access_token`.
+pub fn access_token_client_id(access_token: &str) -> Option<String> {
+    super::verify(access_token).ok()?["client_id"]
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Starts the auth flow, shared by InitiateAuth and AdminInitiateAuth.
+pub fn initiate_auth_flow(
+    user_pool_id: &str,
+    client_id: &str,
+    auth_flow: &str,
+    params: &AuthParameters,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthResponse, ActionError> {
+    // the client and the parameters are checked before any trigger is invoked.
+    super::with_store(|store| {
+        let client = store.user_pool(user_pool_id)?.client(client_id)?;
+        check_auth_flow(client, auth_flow)?;
+        match auth_flow {
+            "ADMIN_USER_PASSWORD_AUTH" | "ADMIN_NO_SRP_AUTH" | "USER_PASSWORD_AUTH" => {
+                let username = auth_parameter(params, "USERNAME")?;
+                auth_parameter(params, "PASSWORD")?;
+                verify_secret_hash(client, username, params.get("SECRET_HASH"))
+            }
+            "USER_SRP_AUTH" => {
+                let username = auth_parameter(params, "USERNAME")?;
+                auth_parameter(params, "SRP_A")?;
+                verify_secret_hash(client, username, params.get("SECRET_HASH"))
+            }
+            _ => Ok(()),
+        }
+    })?;
+    match auth_flow {
+        "ADMIN_USER_PASSWORD_AUTH" | "ADMIN_NO_SRP_AUTH" | "USER_PASSWORD_AUTH" => {
+            let username = auth_parameter(params, "USERNAME")?;
+            let password = auth_parameter(params, "PASSWORD")?;
+            super::migrate_user(
+                user_pool_id,
+                client_id,
+                "UserMigration_Authentication",
+                username,
+                Some(password),
+                client_metadata,
+            )?;
+            super::pre_authentication(user_pool_id, client_id, username, client_metadata)?;
+            let username = super::with_store(|store| {
+                let pool = store.user_pool(user_pool_id)?;
+                authenticate_password(pool, username, password).map(|user| user.username.clone())
+            })?;
+            password_verified(
+                user_pool_id,
+                client_id,
+                &username,
+                "TokenGeneration_Authentication",
+                client_metadata,
+            )
+        }
+        "USER_SRP_AUTH" => {
+            let username = auth_parameter(params, "USERNAME")?;
+            let srp_a = auth_parameter(params, "SRP_A")?;
+            super::pre_authentication(user_pool_id, client_id, username, client_metadata)?;
+            super::with_store(|store| {
+                let pool = store.user_pool(user_pool_id)?;
+                let user = pool.user(username)?;
+                let password = user
+                    .password
+                    .as_deref()
+                    .ok_or_else(incorrect_username_or_password)?;
+                let srp = SrpSession::new(
+                    super::srp_pool_name(&pool.id),
+                    &user.username,
+                    password,
+                    srp_a,
+                )
+                .ok_or_else(|| ActionError::invalid_parameter("SRP_A cannot be 0 mod N"))?;
+
+                // SECRET_BLOCK identifies the session, PASSWORD_VERIFIER has no Session.
+                let mut session =
+                    AuthSession::new(user_pool_id, client_id, &user.username, "PASSWORD_VERIFIER");
+                session.id = super::generate_secret_block();
+                session.parameters = srp.to_parameters();
+                let mut challenge_parameters: HashMap<String, String> =
+                    srp.challenge_parameters().into_iter().collect();
+                challenge_parameters.insert("SECRET_BLOCK".to_string(), session.id.clone());
+                challenge_parameters.insert("USER_ID_FOR_SRP".to_string(), user.username.clone());
+                challenge_parameters.insert("USERNAME".to_string(), user.username.clone());
+                store.add_session(session);
+                Ok(AuthResponse {
+                    challenge_name: Some("PASSWORD_VERIFIER".to_string()),
+                    challenge_parameters,
+                    ..Default::default()
+                })
+            })
+        }
+        "REFRESH_TOKEN_AUTH" | "REFRESH_TOKEN" => {
+            let refresh_token = auth_parameter(params, "REFRESH_TOKEN")?;
+            refresh_tokens(
+                user_pool_id,
+                client_id,
+                refresh_token,
+                params,
+                client_metadata,
+            )
+            .map(AuthResponse::authenticated)
+        }
+        "CUSTOM_AUTH" => {
+            super::initiate_custom_auth(user_pool_id, client_id, params, client_metadata)
+        }
+        _ => Err(ActionError::invalid_parameter(&format!(
+            "Unsupported auth flow {}",
+            auth_flow
+        ))),
+    }
+}
+
+/// Takes the session of the challenge, and checks it is of the user and the client.
+fn take_challenge_session(
+    store: &mut Store,
+    user_pool_id: &str,
+    client_id: &str,
+    challenge_name: &str,
+    responses: &AuthParameters,
+    session: Option<&str>,
+) -> Result<AuthSession, ActionError> {
+    let session = match challenge_name {
+        "PASSWORD_VERIFIER" => auth_parameter(responses, "PASSWORD_CLAIM_SECRET_BLOCK")?,
+        _ => session
+            .ok_or_else(|| ActionError::invalid_parameter("Missing required parameter Session"))?,
+    };
+    let session = store.take_session(session)?;
+    let username = auth_parameter(responses, "USERNAME")?;
+    let pool = store.user_pool(user_pool_id)?;
+    if session.user_pool_id != user_pool_id
+        || session.client_id != client_id
+        || session.challenge_name != challenge_name
+        || pool.resolve_username(username) != Some(session.username.as_str())
+    {
+        return Err(ActionError::new(
+            "NotAuthorizedException",
+            "Invalid session for the user.",
+        ));
+    }
+    verify_secret_hash(
+        pool.client(client_id)?,
+        username,
+        responses.get("SECRET_HASH"),
+    )?;
+    Ok(session)
+}
+
+/// Responds to the challenge of the session, shared by RespondToAuthChallenge
+/// and AdminRespondToAuthChallenge.
+pub fn respond_to_challenge(
+    user_pool_id: &str,
+    client_id: &str,
+    challenge_name: &str,
+    responses: &AuthParameters,
+    session: Option<&str>,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthResponse, ActionError> {
+    let session = super::with_store(|store| {
+        take_challenge_session(
+            store,
+            user_pool_id,
+            client_id,
+            challenge_name,
+            responses,
+            session,
+        )
+    })?;
+    match challenge_name {
+        "PASSWORD_VERIFIER" => {
+            super::with_store(|store| {
+                let pool = store.user_pool(user_pool_id)?;
+                verify_password_claim(pool, &session, responses)?;
+                let user = pool.user(&session.username)?;
+                check_user_status(user)?;
+                super::check_temporary_password(pool, user)
+            })?;
+            password_verified(
+                user_pool_id,
+                client_id,
+                &session.username,
+                "TokenGeneration_Authentication",
+                client_metadata,
+            )
+        }
+        "NEW_PASSWORD_REQUIRED" => {
+            super::with_store(|store| {
+                set_new_password(store, user_pool_id, &session.username, responses)
+            })?;
+            password_verified(
+                user_pool_id,
+                client_id,
+                &session.username,
+                "TokenGeneration_NewPasswordChallenge",
+                client_metadata,
+            )
+        }
+        super::SMS_MFA | super::SOFTWARE_TOKEN_MFA | super::MFA_SETUP => {
+            super::with_store(|store| {
+                let user = store
+                    .user_pool_mut(user_pool_id)?
+                    .user_mut(&session.username)?;
+                let verified = super::verify_mfa_response(user, challenge_name, responses);
+                if verified.is_err() {
+                    // the session is kept for another attempt with the right code.
+                    store.add_session(session.clone());
+                }
+                verified
+            })?;
+            complete_authentication(
+                user_pool_id,
+                client_id,
+                &session.username,
+                "TokenGeneration_Authentication",
+                client_metadata,
+            )
+        }
+        super::CUSTOM_CHALLENGE => {
+            super::respond_to_custom_challenge(&session, responses, client_metadata)
+        }
+        super::SELECT_MFA_TYPE => {
+            let challenge = super::with_store(|store| {
+                let pool = store.user_pool(user_pool_id)?;
+                let user = pool.user(&session.username)?;
+                let challenge = super::select_mfa_type(pool, client_id, user, responses);
+                if challenge.is_err() {
+                    store.add_session(session.clone());
+                }
+                challenge
+            })?;
+            start_challenge(user_pool_id, challenge, client_metadata)
+        }
+        _ => Err(ActionError::invalid_parameter(&format!(
+            "Unsupported challenge {}",
+            challenge_name
+        ))),
+    }
+}
+
+/// Returns the next challenge (new password or MFA) of the user whose password is verified,
+/// or issues tokens with the trigger source of PreTokenGeneration.
+fn password_verified(
+    user_pool_id: &str,
+    client_id: &str,
+    username: &str,
+    trigger_source: &str,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthResponse, ActionError> {
+    let challenge = super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        let user = pool.user(username)?;
+        Ok::<_, ActionError>(match user.status {
+            UserStatus::FORCE_CHANGE_PASSWORD => {
+                Some(new_password_required_challenge(pool, client_id, user))
+            }
+            _ => super::mfa_challenge(pool, client_id, user),
+        })
+    })?;
+    match challenge {
+        Some(challenge) => start_challenge(user_pool_id, challenge, client_metadata),
+        None => complete_authentication(
+            user_pool_id,
+            client_id,
+            username,
+            trigger_source,
+            client_metadata,
+        ),
+    }
+}
+
+/// Invokes PostAuthentication trigger and issues tokens of a new origin for the authenticated user.
+pub fn complete_authentication(
+    user_pool_id: &str,
+    client_id: &str,
+    username: &str,
+    trigger_source: &str,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthResponse, ActionError> {
+    super::post_authentication(user_pool_id, client_id, username, client_metadata)?;
+    issue_tokens(
+        user_pool_id,
+        client_id,
+        username,
+        trigger_source,
+        client_metadata,
+    )
+    .map(AuthResponse::authenticated)
+}
+
+/// Revokes the refresh token and access tokens issued with it, tokens which cannot be
+/// verified are ignored.
+pub fn revoke_token(
+    store: &mut Store,
+    token: &str,
+    client_id: &str,
+    client_secret: Option<&String>,
+) -> Result<(), ActionError> {
+    let client = store.find_client(client_id)?;
+    if client.client_secret.is_some() && client.client_secret.as_ref() != client_secret {
+        return Err(ActionError::new(
+            "UnauthorizedException",
+            "Invalid client secret.",
+        ));
+    }
+    if client.config.enable_token_revocation == Some(false) {
+        return Err(ActionError::new(
+            "UnsupportedOperationException",
+            "Token revocation is not enabled for the client.",
+        ));
+    }
+    let claims = match super::verify(token) {
+        Ok(claims) => claims,
+        Err(_) => return Ok(()),
+    };
+    if claims["token_use"] != "refresh" {
+        return Err(ActionError::new(
+            "UnsupportedTokenTypeException",
+            "Only refresh tokens can be revoked.",
+        ));
+    }
+    if claims["client_id"] != client_id {
+        return Err(ActionError::new(
+            "UnauthorizedException",
+            "Token was not issued to the client.",
+        ));
+    }
+    let user_pool_id = client.user_pool_id().to_string();
+    let username = claims["username"].as_str().unwrap_or_default();
+    let origin_jti = claims["origin_jti"].as_str().unwrap_or_default();
+    if let Ok(user) = store.user_pool_mut(&user_pool_id)?.user_mut(username) {
+        user.origin_jtis.remove(origin_jti);
+    }
+    Ok(())
+}
+
+/// Stores the session of the challenge and returns the challenge,
+/// a code is sent to the user for `SMS_MFA` challenge.
+fn start_challenge(
+    user_pool_id: &str,
+    (session, mut challenge_parameters): (AuthSession, HashMap<String, String>),
+    client_metadata: &ClientMetadata,
+) -> Result<AuthResponse, ActionError> {
+    if session.challenge_name == super::SMS_MFA {
+        super::send_sms_mfa_code(
+            user_pool_id,
+            &session.username,
+            &mut challenge_parameters,
+            &super::TriggerContext::new(Some(&session.client_id), Some(client_metadata)),
+        )?;
+    }
+    let response = AuthResponse {
+        challenge_name: Some(session.challenge_name.clone()),
+        challenge_parameters,
+        session: Some(session.id.clone()),
+        ..Default::default()
+    };
+    super::with_store(|store| store.add_session(session));
+    Ok(response)
+}
+
+/// Returns `NEW_PASSWORD_REQUIRED` challenge for the user with a temporary password.
+fn new_password_required_challenge(
+    pool: &UserPool,
+    client_id: &str,
+    user: &User,
+) -> (AuthSession, HashMap<String, String>) {
+    let session = AuthSession::new(&pool.id, client_id, &user.username, "NEW_PASSWORD_REQUIRED");
+    let required_attributes = pool
+        .required_attributes()
+        .into_iter()
+        .filter(|name| user.attribute(name).is_none())
+        .map(|name| format!("{}{}", USER_ATTRIBUTE_PREFIX, name))
+        .collect::<Vec<_>>();
+    let user_attributes = user
+        .attributes
+        .iter()
+        .filter(|(name, _)| name.as_str() != super::SUB_ATTRIBUTE)
+        .collect::<HashMap<_, _>>();
+    let challenge_parameters = vec![
+        ("USER_ID_FOR_SRP".to_string(), user.username.clone()),
+        (
+            "requiredAttributes".to_string(),
+            serde_json::to_string(&required_attributes).unwrap(),
+        ),
+        (
+            "userAttributes".to_string(),
+            serde_json::to_string(&user_attributes).unwrap(),
+        ),
+    ];
+    (session, challenge_parameters.into_iter().collect())
+}
+
+/// Sets `NEW_PASSWORD` and `userAttributes.*` responses to the user, and confirms the user.
+fn set_new_password(
+    store: &mut Store,
+    user_pool_id: &str,
+    username: &str,
+    responses: &AuthParameters,
+) -> Result<(), ActionError> {
+    let new_password = auth_parameter(responses, "NEW_PASSWORD")?;
+    let pool = store.user_pool_mut(user_pool_id)?;
+    let attributes = responses
+        .iter()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(USER_ATTRIBUTE_PREFIX)
+                .map(|name| (name.to_string(), value.clone()))
+        })
+        .collect::<Vec<_>>();
+    if let Some((name, _)) = attributes
+        .iter()
+        .find(|(name, _)| !pool.has_attribute(name))
+    {
+        return Err(ActionError::invalid_parameter(&format!(
+            "Invalid user attributes: {}{}: Attribute does not exist in the schema.",
+            USER_ATTRIBUTE_PREFIX, name
+        )));
+    }
+    let user = pool.user(username)?;
+    if user.status != UserStatus::FORCE_CHANGE_PASSWORD {
+        return Err(ActionError::new(
+            "NotAuthorizedException",
+            "Invalid session for the user.",
+        ));
+    }
+    if let Some(name) = pool
+        .required_attributes()
+        .into_iter()
+        .find(|name| user.attribute(name).is_none() && attributes.iter().all(|(n, _)| n != name))
+    {
+        return Err(ActionError::invalid_parameter(&format!(
+            "Invalid attributes given, {} is missing",
+            name
+        )));
+    }
+    super::check_password_policy(pool, new_password)?;
+
+    let user = pool.user_mut(username)?;
+    user.set_attributes(attributes)?;
+    user.set_password(new_password);
+    user.status = UserStatus::CONFIRMED;
+    Ok(())
+}
+
+/// Verifies `PASSWORD_CLAIM_SIGNATURE` of the SRP session.
+fn verify_password_claim(
+    pool: &UserPool,
+    session: &AuthSession,
+    responses: &AuthParameters,
+) -> Result<(), ActionError> {
+    let signature = auth_parameter(responses, "PASSWORD_CLAIM_SIGNATURE")?;
+    let timestamp = auth_parameter(responses, "TIMESTAMP")?;
+    if !super::is_valid_srp_timestamp(timestamp) {
+        return Err(ActionError::invalid_parameter(
+            "TIMESTAMP format is invalid",
+        ));
+    }
+    let srp = SrpSession::from_parameters(&session.parameters)
+        .ok_or_else(|| ActionError::new("InternalErrorException", "Invalid SRP session."))?;
+    if srp.verify_password_claim(
+        super::srp_pool_name(&pool.id),
+        &session.username,
+        &session.id,
+        timestamp,
+        signature,
+    ) {
+        Ok(())
+    } else {
+        Err(incorrect_username_or_password())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::CreateUserPoolClientRequest;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn default_auth_flows() {
+        let client = UserPoolClient::new(CreateUserPoolClientRequest::default());
+        assert!(check_auth_flow(&client, "USER_SRP_AUTH").is_ok());
+        assert!(check_auth_flow(&client, "ADMIN_USER_PASSWORD_AUTH").is_err());
+    }
+
+    #[test]
+    fn initiate_auth_methods() {
+        assert!(check_initiate_auth_method("ADMIN_NO_SRP_AUTH", true).is_ok());
+        assert!(check_initiate_auth_method("ADMIN_NO_SRP_AUTH", false).is_err());
+        assert!(check_initiate_auth_method("USER_PASSWORD_AUTH", true).is_err());
+        assert!(check_initiate_auth_method("USER_PASSWORD_AUTH", false).is_ok());
+        assert!(check_initiate_auth_method("REFRESH_TOKEN_AUTH", false).is_ok());
+    }
+
+    #[test]
+    fn verify_secret_hash_for_client_with_secret() {
+        let client = UserPoolClient::new(CreateUserPoolClientRequest {
+            generate_secret: Some(true),
+            ..Default::default()
+        });
+        let hash = secret_hash(
+            client.client_secret.as_ref().unwrap(),
+            "username",
+            &client.client_id,
+        );
+        assert!(verify_secret_hash(&client, "username", Some(&hash)).is_ok());
+        assert!(verify_secret_hash(&client, "other", Some(&hash)).is_err());
+        assert!(verify_secret_hash(&client, "username", None).is_err());
+    }
+
+    fn grant(scope: &str) -> TokenGrant<'_> {
+        TokenGrant {
+            origin_jti: "origin_jti",
+            scope,
+            nonce: None,
+        }
+    }
+
+    #[test]
+    fn issued_tokens_have_claims() {
+        let pool = UserPool::new("us-east-1_abcdefghi", Default::default());
+        let client = UserPoolClient::new(CreateUserPoolClientRequest::default());
+        let mut user = User::new("username");
+        user.set_attributes(vec![
+            ("email".to_string(), "user@example.com".to_string()),
+            ("email_verified".to_string(), "true".to_string()),
+        ])
+        .unwrap();
+
+        let result = sign_tokens(
+            &pool,
+            &client,
+            &user,
+            &Default::default(),
+            &grant(ACCESS_TOKEN_SCOPE),
+            true,
+        );
+        assert_eq!(3600, result.expires_in);
+
+        let id_claims = super::super::verify(&result.id_token).unwrap();
+        assert_eq!("id", id_claims["token_use"]);
+        assert_eq!("username", id_claims["cognito:username"]);
+        assert_eq!(client.client_id, id_claims["aud"]);
+        assert_eq!(true, id_claims["email_verified"]);
+        assert_eq!(user.sub(), id_claims["sub"]);
+
+        let access_claims = super::super::verify(&result.access_token).unwrap();
+        assert_eq!("access", access_claims["token_use"]);
+        assert_eq!(client.client_id, access_claims["client_id"]);
+        assert_eq!(id_claims["iss"], access_claims["iss"]);
+    }
+
+    #[test]
+    fn verify_refresh_token_of_origin() {
+        let mut pool = UserPool::new("us-east-1_abcdefghi", Default::default());
+        let client = UserPoolClient::new(CreateUserPoolClientRequest::default());
+        let mut user = User::new("username");
+        user.origin_jtis.insert("origin_jti".to_string());
+        pool.add_user(user).unwrap();
+        let user = pool.user("username").unwrap();
+        let refresh_token = sign_tokens(
+            &pool,
+            &client,
+            user,
+            &Default::default(),
+            &grant(ACCESS_TOKEN_SCOPE),
+            true,
+        )
+        .refresh_token
+        .unwrap();
+
+        let claims = verify_refresh_token(&pool, &client, &refresh_token).unwrap();
+        assert_eq!(
+            "username",
+            check_refresh_origin(&pool, &claims).unwrap().username
+        );
+        let result = sign_tokens(
+            &pool,
+            &client,
+            user,
+            &Default::default(),
+            &grant(claims["scope"].as_str().unwrap()),
+            false,
+        );
+        assert!(result.refresh_token.is_none());
+
+        let other_client = UserPoolClient::new(CreateUserPoolClientRequest::default());
+        assert!(verify_refresh_token(&pool, &other_client, &refresh_token).is_err());
+        assert!(verify_refresh_token(&pool, &client, "invalid").is_err());
+        pool.user_mut("username").unwrap().origin_jtis.clear();
+        assert!(check_refresh_origin(&pool, &claims).is_err());
+    }
+}
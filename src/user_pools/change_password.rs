@@ -47,10 +47,29 @@ impl super::ToActionName for ChangePasswordRequest {
 impl super::ToResponse for ChangePasswordRequest {
     type E = ChangePasswordError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, change_password)
     }
 }
 
+fn change_password(request: &ChangePasswordRequest) -> Result<(), super::ActionError> {
+    let proposed_password = request.proposed_password.as_ref().unwrap();
+    super::with_store(|store| {
+        let (user_pool_id, username) =
+            super::verify_access_token(store, request.access_token.as_ref().unwrap())?;
+        let pool = store.user_pool_mut(&user_pool_id)?;
+        let user = pool.user(&username)?;
+        if user.password != request.previous_password {
+            return Err(super::ActionError::new(
+                "NotAuthorizedException",
+                "Incorrect username or password.",
+            ));
+        }
+        super::check_password_policy(pool, proposed_password)?;
+        pool.user_mut(&username)?.set_password(proposed_password);
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,10 +62,46 @@ impl super::ToActionName for ConfirmForgotPasswordRequest {
 impl super::ToResponse for ConfirmForgotPasswordRequest {
     type E = ConfirmForgotPasswordError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, confirm_forgot_password)
     }
 }
 
+fn confirm_forgot_password(
+    request: &ConfirmForgotPasswordRequest,
+) -> Result<(), super::ActionError> {
+    let client_id = request.client_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let password = request.password.as_ref().unwrap();
+    let (user_pool_id, username) = super::with_store(|store| {
+        let client = store.find_client(client_id)?;
+        super::verify_secret_hash(client, username, request.secret_hash.as_ref())?;
+        let user_pool_id = client.user_pool_id().to_string();
+        let pool = store.user_pool_mut(&user_pool_id)?;
+        super::check_password_policy(pool, password)?;
+        let user = pool.user_mut(username)?;
+        user.verify_code(
+            &super::CodePurpose::ForgotPassword,
+            request.confirmation_code.as_ref().unwrap(),
+        )?;
+        user.set_password(password);
+        if matches!(
+            user.status,
+            super::UserStatus::RESET_REQUIRED | super::UserStatus::FORCE_CHANGE_PASSWORD
+        ) {
+            user.status = super::UserStatus::CONFIRMED;
+        }
+        let username = user.username.clone();
+        Ok::<_, super::ActionError>((user_pool_id, username))
+    })?;
+    super::post_confirmation(
+        &user_pool_id,
+        Some(client_id),
+        "PostConfirmation_ConfirmForgotPassword",
+        &username,
+        &request.client_metadata.clone().unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
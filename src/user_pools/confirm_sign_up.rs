@@ -58,10 +58,53 @@ impl super::ToActionName for ConfirmSignUpRequest {
 impl super::ToResponse for ConfirmSignUpRequest {
     type E = ConfirmSignUpError;
     fn to_response(&self) -> super::Response {
-        super::to_empty_response(self)
+        super::to_stateful_empty_response(self, confirm_sign_up)
     }
 }
 
+/// Returns an error when the user is not waiting for confirmation.
+pub(crate) fn check_unconfirmed(user: &super::User) -> Result<(), super::ActionError> {
+    if user.status == super::UserStatus::UNCONFIRMED {
+        Ok(())
+    } else {
+        Err(super::ActionError::new(
+            "NotAuthorizedException",
+            &format!(
+                "User cannot be confirmed. Current status is {}",
+                user.status
+            ),
+        ))
+    }
+}
+
+fn confirm_sign_up(request: &ConfirmSignUpRequest) -> Result<(), super::ActionError> {
+    let client_id = request.client_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let code = request.confirmation_code.as_ref().unwrap();
+    let (user_pool_id, username) = super::with_store(|store| {
+        let client = store.find_client(client_id)?;
+        super::verify_secret_hash(client, username, request.secret_hash.as_ref())?;
+        let user_pool_id = client.user_pool_id().to_string();
+        let user = store.user_pool_mut(&user_pool_id)?.user_mut(username)?;
+        check_unconfirmed(user)?;
+        let verified = user.verify_code(&super::CodePurpose::SignUp, code)?;
+        user.status = super::UserStatus::CONFIRMED;
+        let verified_attributes = verified
+            .attribute_name
+            .map(|name| (format!("{}_verified", name), "true".to_string()));
+        user.set_attributes(verified_attributes)?;
+        let username = user.username.clone();
+        Ok::<_, super::ActionError>((user_pool_id, username))
+    })?;
+    super::post_confirmation(
+        &user_pool_id,
+        Some(client_id),
+        "PostConfirmation_ConfirmSignUp",
+        &username,
+        &request.client_metadata.clone().unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,10 +46,24 @@ impl super::ToActionName for CreateGroupRequest {
 impl super::ToResponse for CreateGroupRequest {
     type E = CreateGroupError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_GROUP_NAME)
+        super::to_stateful_json_response(self, CREATE_GROUP_NAME, create_group)
     }
 }
 
+fn create_group(request: &CreateGroupRequest) -> Result<serde_json::Value, super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    let group = super::Group {
+        description: request.description.clone(),
+        precedence: request.precedence,
+        role_arn: request.role_arn.clone(),
+        ..super::Group::new(request.group_name.as_ref().unwrap())
+    };
+    super::with_store(|store| {
+        let group = store.user_pool_mut(user_pool_id)?.add_group(group)?;
+        Ok(serde_json::json!({ "Group": group.to_group_type(user_pool_id) }))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
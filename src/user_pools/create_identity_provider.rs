@@ -36,7 +36,8 @@ fn validate_provider_type(value: &str) -> Result<(), ValidationError> {
 
 super::gen_response_err!(
     CreateIdentityProviderError,
-    InvalidParameterException
+    DuplicateProviderException
+    | InvalidParameterException
     | InvalidLambdaResponseException
     | InvalidSmsRoleAccessPolicyException
     | InvalidSmsRoleTrustRelationshipException
@@ -53,7 +54,7 @@ super::gen_response_err!(
     InternalErrorException => http::status_code(500)
 );
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct CreateIdentityProviderRequest {
     pub attribute_mapping: Option<std::collections::HashMap<String, String>>,
@@ -84,10 +85,34 @@ impl super::ToActionName for CreateIdentityProviderRequest {
 impl super::ToResponse for CreateIdentityProviderRequest {
     type E = CreateIdentityProviderError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_IDENTITY_PROVIDER_NAME)
+        super::to_stateful_json_response(
+            self,
+            CREATE_IDENTITY_PROVIDER_NAME,
+            create_identity_provider,
+        )
     }
 }
 
+fn create_identity_provider(
+    request: &CreateIdentityProviderRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let provider_name = request.provider_name.as_ref().unwrap();
+    super::with_store(|store| {
+        let pool = store.user_pool_mut(request.user_pool_id.as_ref().unwrap())?;
+        if pool.identity_providers.contains_key(provider_name) {
+            return Err(super::ActionError::new(
+                "DuplicateProviderException",
+                &format!("A provider with the name {} already exists.", provider_name),
+            ));
+        }
+        let provider = pool
+            .identity_providers
+            .entry(provider_name.clone())
+            .or_insert_with(|| super::IdentityProvider::new(request.clone()));
+        Ok(serde_json::json!({ "IdentityProvider": provider.to_identity_provider_type() }))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,4 @@
-use crate::common::USER_POOL_ID_REGEX;
+use crate::common::{self, USER_POOL_ID_REGEX};
 use crate::http;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -23,7 +23,7 @@ super::gen_response_err!(
     InternalErrorException => http::status_code(500)
 );
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct CreateResourceServerRequest {
     #[validate(required)]
@@ -51,10 +51,30 @@ impl super::ToActionName for CreateResourceServerRequest {
 impl super::ToResponse for CreateResourceServerRequest {
     type E = CreateResourceServerError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_RESOURCE_SERVER_NAME)
+        super::to_stateful_json_response(self, CREATE_RESOURCE_SERVER_NAME, create_resource_server)
     }
 }
 
+fn create_resource_server(
+    request: &CreateResourceServerRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let identifier = request.identifier.as_ref().unwrap();
+    super::with_store(|store| {
+        let pool = store.user_pool_mut(request.user_pool_id.as_ref().unwrap())?;
+        if pool.resource_servers.contains_key(identifier) {
+            return Err(super::ActionError::invalid_parameter(&format!(
+                "A resource server with the identifier {} already exists.",
+                identifier
+            )));
+        }
+        pool.resource_servers
+            .insert(identifier.clone(), request.clone());
+        Ok(serde_json::json!({
+            "ResourceServer": common::without_nulls(serde_json::json!(request)),
+        }))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -22,7 +22,7 @@ super::gen_response_err!(
     InternalErrorException => http::status_code(500)
 );
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct CreateUserImportJobRequest {
     #[validate(required)]
@@ -48,10 +48,22 @@ impl super::ToActionName for CreateUserImportJobRequest {
 impl super::ToResponse for CreateUserImportJobRequest {
     type E = CreateUserImportJobError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_USER_IMPORT_JOB_NAME)
+        super::to_stateful_json_response(self, CREATE_USER_IMPORT_JOB_NAME, create_user_import_job)
     }
 }
 
+fn create_user_import_job(
+    request: &CreateUserImportJobRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| {
+        let pool = store.user_pool_mut(request.user_pool_id.as_ref().unwrap())?;
+        let job = super::UserImportJob::new(request.clone());
+        let job_type = job.to_user_import_job_type(pool.region());
+        pool.user_import_jobs.insert(job.job_id.clone(), job);
+        Ok(serde_json::json!({ "UserImportJob": job_type }))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,7 +77,6 @@ mod tests {
             ),
             job_name: Some("job-name".to_string()),
             user_pool_id: Some("user_pool_id".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_ok());
     }
@@ -78,7 +89,6 @@ mod tests {
             ),
             job_name: Some("job_name".to_string()),
             user_pool_id: Some("".to_string()),
-            ..Default::default()
         };
         assert!(request.validate().is_err());
     }
@@ -55,45 +55,45 @@ super::gen_response_err!(
     InternalErrorException => http::status_code(500)
 );
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct CreateUserPoolRequest {
-    account_recovery_setting: Option<super::data_types::AccountRecoverySettingType>,
-    admin_create_user_config: Option<super::data_types::AdminCreateUserConfigType>,
+    pub account_recovery_setting: Option<super::data_types::AccountRecoverySettingType>,
+    pub admin_create_user_config: Option<super::data_types::AdminCreateUserConfigType>,
     #[validate(custom(function = "validate_alias_attributes"))]
-    alias_attributes: Option<Vec<String>>,
+    pub alias_attributes: Option<Vec<String>>,
     #[validate(custom(function = "validate_auto_verified_attributes"))]
-    auto_verified_attributes: Option<Vec<String>>,
-    device_configuration: Option<super::data_types::DeviceConfigurationType>,
-    email_configuration: Option<super::data_types::EmailConfigurationType>,
+    pub auto_verified_attributes: Option<Vec<String>>,
+    pub device_configuration: Option<super::data_types::DeviceConfigurationType>,
+    pub email_configuration: Option<super::data_types::EmailConfigurationType>,
     #[validate(length(min = 6, max = 20000))]
     #[validate(regex = "EMAIL_VERIFICATION_MESSAGE_REGEX")]
-    email_verification_message: Option<String>,
+    pub email_verification_message: Option<String>,
     #[validate(length(min = 1, max = 140))]
     #[validate(regex = "EMAIL_VERIFICATION_SUBJECT_REGEX")]
-    email_verification_subject: Option<String>,
-    lambda_config: Option<super::data_types::LambdaConfigType>,
+    pub email_verification_subject: Option<String>,
+    pub lambda_config: Option<super::data_types::LambdaConfigType>,
     #[validate(custom(function = "validate_mfa_configuration"))]
-    mfa_configuration: Option<String>,
-    plicies: Option<super::data_types::UserPoolPolicyType>,
+    pub mfa_configuration: Option<String>,
+    pub policies: Option<super::data_types::UserPoolPolicyType>,
     #[validate(required)]
     #[validate(length(min = 1, max = 128))]
     #[validate(regex = "POOL_NAME_REGEX")]
-    pool_name: Option<String>,
-    schema: Option<Vec<super::data_types::SchemaAttributeType>>,
+    pub pool_name: Option<String>,
+    pub schema: Option<Vec<super::data_types::SchemaAttributeType>>,
     #[validate(length(min = 6, max = 140))]
     #[validate(regex = "SMS_AUTHENTICATION_MESSAGE_REGEX")]
-    sms_authentication_message: Option<String>,
-    sms_configuration: Option<super::data_types::SmsConfigurationType>,
+    pub sms_authentication_message: Option<String>,
+    pub sms_configuration: Option<super::data_types::SmsConfigurationType>,
     #[validate(length(min = 6, max = 140))]
     #[validate(regex = "SMS_VERIFICATION_MESSAGE_REGEX")]
-    sms_verification_message: Option<String>,
+    pub sms_verification_message: Option<String>,
     #[validate(custom(function = "validate_username_attributes"))]
-    username_attributes: Option<Vec<String>>,
-    username_configuration: Option<super::data_types::UsernameConfigurationType>,
-    user_pool_add_ons: Option<super::data_types::UserPoolAddOnsType>,
-    user_pool_tags: Option<std::collections::HashMap<String, String>>,
-    verification_message_template: Option<super::data_types::VerificationMessageTemplateType>,
+    pub username_attributes: Option<Vec<String>>,
+    pub username_configuration: Option<super::data_types::UsernameConfigurationType>,
+    pub user_pool_add_ons: Option<super::data_types::UserPoolAddOnsType>,
+    pub user_pool_tags: Option<std::collections::HashMap<String, String>>,
+    pub verification_message_template: Option<super::data_types::VerificationMessageTemplateType>,
 }
 
 impl super::ToActionName for CreateUserPoolRequest {
@@ -105,10 +105,19 @@ impl super::ToActionName for CreateUserPoolRequest {
 impl super::ToResponse for CreateUserPoolRequest {
     type E = CreateUserPoolError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_USER_POOL_NAME)
+        super::to_stateful_json_response(self, CREATE_USER_POOL_NAME, create_user_pool)
     }
 }
 
+fn create_user_pool(
+    request: &CreateUserPoolRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| {
+        let pool = store.add_user_pool(request.clone());
+        Ok(serde_json::json!({ "UserPool": pool.to_user_pool_type() }))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
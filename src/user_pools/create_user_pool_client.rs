@@ -67,60 +67,60 @@ super::gen_response_err!(
     InternalErrorException => http::status_code(500)
 );
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct CreateUserPoolClientRequest {
     #[validate(range(min = 1, max = 86400))]
-    access_token_validity: Option<i64>,
+    pub access_token_validity: Option<i64>,
     #[serde(rename = "AllowedOAuthFlows")]
     #[validate(length(min = 0, max = 3))]
     #[validate(custom(function = "validate_allowed_oauth_flows"))]
-    allowed_oatuh_flows: Option<Vec<String>>,
+    pub allowed_oauth_flows: Option<Vec<String>>,
     #[serde(rename = "AllowedOAuthFlowsUserPoolClient")]
-    allowed_oauth_flows_user_pool_client: Option<bool>,
+    pub allowed_oauth_flows_user_pool_client: Option<bool>,
     #[serde(rename = "AllowedOAuthScopes")]
     #[validate(length(max = 50))]
     #[validate(custom(function = "validate_allowed_oauth_scopes"))]
-    allowed_oauth_scopes: Option<Vec<String>>,
-    analytics_configuration: Option<super::data_types::AnalyticsConfigurationType>,
+    pub allowed_oauth_scopes: Option<Vec<String>>,
+    pub analytics_configuration: Option<super::data_types::AnalyticsConfigurationType>,
     #[serde(rename = "CallbackURLs")]
     #[validate(length(min = 0, max = 100))]
     #[validate(custom(function = "validate_urls"))]
-    callback_urls: Option<Vec<String>>,
+    pub callback_urls: Option<Vec<String>>,
     #[validate(required)]
     #[validate(length(min = 1))]
     #[validate(regex = "CLIENT_NAME_REGEX")]
-    client_name: Option<String>,
+    pub client_name: Option<String>,
     #[serde(rename = "DefaultRedirectURI")]
     #[validate(length(min = 1, max = 1024))]
     #[validate(regex = "URL_REGEX")]
-    default_redirect_uri: Option<String>,
-    enable_token_revocation: Option<bool>,
+    pub default_redirect_uri: Option<String>,
+    pub enable_token_revocation: Option<bool>,
     #[validate(custom(function = "validate_explicit_oauth_flows"))]
-    explicit_auth_flows: Option<Vec<String>>,
-    generate_secret: Option<bool>,
+    pub explicit_auth_flows: Option<Vec<String>>,
+    pub generate_secret: Option<bool>,
     #[validate(range(min = 1, max = 86400))]
-    id_token_validity: Option<i64>,
+    pub id_token_validity: Option<i64>,
     #[serde(rename = "LogoutURLs")]
     #[validate(length(min = 1, max = 100))]
     #[validate(custom(function = "validate_urls"))]
-    logout_urls: Option<Vec<String>>,
+    pub logout_urls: Option<Vec<String>>,
     #[validate(custom(function = "validate_prevent_user_extence_errors"))]
-    prevent_user_existence_errors: Option<String>,
+    pub prevent_user_existence_errors: Option<String>,
     #[validate(length(min = 1, max = 2048))]
-    read_attributes: Option<Vec<String>>,
+    pub read_attributes: Option<Vec<String>>,
     #[validate(range(min = 0, max = 315360000))]
-    refresh_token_validity: Option<i64>,
+    pub refresh_token_validity: Option<i64>,
     #[validate(length(min = 1, max = 32))]
     #[validate(custom(function = "validate_supported_identity_providers"))]
-    supported_identity_providers: Option<Vec<String>>,
-    token_validity_units: Option<super::data_types::TokenValidityUnitsType>,
+    pub supported_identity_providers: Option<Vec<String>>,
+    pub token_validity_units: Option<super::data_types::TokenValidityUnitsType>,
     #[validate(required)]
     #[validate(length(min = 1, max = 55))]
     #[validate(regex = "USER_POOL_ID_REGEX")]
-    user_pool_id: Option<String>,
+    pub user_pool_id: Option<String>,
     #[validate(length(min = 1, max = 2048))]
-    write_attributes: Option<Vec<String>>,
+    pub write_attributes: Option<Vec<String>>,
 }
 
 impl super::ToActionName for CreateUserPoolClientRequest {
@@ -132,10 +132,26 @@ impl super::ToActionName for CreateUserPoolClientRequest {
 impl super::ToResponse for CreateUserPoolClientRequest {
     type E = CreateUserPoolClientError;
     fn to_response(&self) -> super::Response {
-        super::to_json_response(self, CREATE_USER_POOL_CLIENT_NAME)
+        super::to_stateful_json_response(
+            self,
+            CREATE_USER_POOL_CLIENT_NAME,
+            create_user_pool_client,
+        )
     }
 }
 
+fn create_user_pool_client(
+    request: &CreateUserPoolClientRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    super::with_store(|store| {
+        let client = store
+            .user_pool_mut(user_pool_id)?
+            .add_client(request.clone());
+        Ok(serde_json::json!({ "UserPoolClient": client.to_user_pool_client_type() }))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,241 @@
+use super::{ActionError, AuthParameters, AuthResponse, AuthSession, ClientMetadata, Trigger};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+pub const CUSTOM_CHALLENGE: &str = "CUSTOM_CHALLENGE";
+const SESSION_HISTORY: &str = "session";
+const SESSION_PRIVATE_CHALLENGE_PARAMETERS: &str = "privateChallengeParameters";
+const SESSION_CHALLENGE_METADATA: &str = "challengeMetadata";
+
+/// Result of an answered challenge, same as `request.session` items of the trigger events.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeResult {
+    pub challenge_name: String,
+    pub challenge_result: bool,
+    pub challenge_metadata: Option<String>,
+}
+
+fn incorrect_username_or_password() -> ActionError {
+    ActionError::new("NotAuthorizedException", "Incorrect username or password.")
+}
+
+/// Starts `CUSTOM_AUTH` flow of the user driven by DefineAuthChallenge trigger.
+pub fn initiate_custom_auth(
+    user_pool_id: &str,
+    client_id: &str,
+    params: &AuthParameters,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthResponse, ActionError> {
+    let username = super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        if !super::has_trigger(pool, Trigger::DefineAuthChallenge) {
+            return Err(ActionError::invalid_parameter(
+                "Custom auth lambda trigger is not configured for the user pool.",
+            ));
+        }
+        let username = super::auth_parameter(params, "USERNAME")?;
+        super::verify_secret_hash(pool.client(client_id)?, username, params.get("SECRET_HASH"))?;
+        let user = pool.user(username)?;
+        super::check_user_status(user)?;
+        Ok(user.username.clone())
+    })?;
+    super::pre_authentication(user_pool_id, client_id, &username, client_metadata)?;
+    next_challenge(
+        user_pool_id,
+        client_id,
+        &username,
+        Vec::new(),
+        client_metadata,
+    )
+}
+
+/// Verifies `ANSWER` to `CUSTOM_CHALLENGE` by VerifyAuthChallengeResponse trigger,
+/// and continues the flow with the result.
+pub fn respond_to_custom_challenge(
+    session: &AuthSession,
+    responses: &AuthParameters,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthResponse, ActionError> {
+    let answer = super::auth_parameter(responses, "ANSWER")?;
+    let session_value = |name: &str| {
+        session
+            .parameters
+            .get(name)
+            .and_then(|v| serde_json::from_str::<Value>(v).ok())
+            .unwrap_or(Value::Null)
+    };
+    let mut history: Vec<ChallengeResult> =
+        serde_json::from_value(session_value(SESSION_HISTORY)).unwrap_or_default();
+    let event = super::with_store(|store| {
+        let pool = store.user_pool(&session.user_pool_id)?;
+        let user = pool.user(&session.username)?;
+        Ok::<_, ActionError>(super::trigger_event(
+            pool,
+            Trigger::VerifyAuthChallengeResponse,
+            "VerifyAuthChallengeResponse_Authentication",
+            Some(&session.client_id),
+            &user.username,
+            serde_json::json!({
+                "userAttributes": super::event_user_attributes(user),
+                "privateChallengeParameters": session_value(SESSION_PRIVATE_CHALLENGE_PARAMETERS),
+                "challengeAnswer": answer,
+                "clientMetadata": client_metadata,
+                "userNotFound": false,
+            }),
+            serde_json::json!({ "answerCorrect": null }),
+        ))
+    })?;
+    let response = super::invoke_trigger(event)?.ok_or_else(|| {
+        ActionError::invalid_parameter(
+            "Verify auth challenge response lambda trigger is not configured for the user pool.",
+        )
+    })?;
+    history.push(ChallengeResult {
+        challenge_name: CUSTOM_CHALLENGE.to_string(),
+        challenge_result: response["answerCorrect"].as_bool().unwrap_or_default(),
+        challenge_metadata: session_value(SESSION_CHALLENGE_METADATA)
+            .as_str()
+            .map(str::to_string),
+    });
+    next_challenge(
+        &session.user_pool_id,
+        &session.client_id,
+        &session.username,
+        history,
+        client_metadata,
+    )
+}
+
+/// Asks DefineAuthChallenge trigger to issue tokens, fail or present the next challenge
+/// created by CreateAuthChallenge trigger.
+fn next_challenge(
+    user_pool_id: &str,
+    client_id: &str,
+    username: &str,
+    history: Vec<ChallengeResult>,
+    client_metadata: &ClientMetadata,
+) -> Result<AuthResponse, ActionError> {
+    let (user_attributes, define) = super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        let user_attributes = super::event_user_attributes(pool.user(username)?);
+        let define = super::trigger_event(
+            pool,
+            Trigger::DefineAuthChallenge,
+            "DefineAuthChallenge_Authentication",
+            Some(client_id),
+            username,
+            serde_json::json!({
+                "userAttributes": user_attributes,
+                "session": history,
+                "clientMetadata": client_metadata,
+                "userNotFound": false,
+            }),
+            serde_json::json!({
+                "challengeName": null,
+                "issueTokens": null,
+                "failAuthentication": null,
+            }),
+        );
+        Ok::<_, ActionError>((user_attributes, define))
+    })?;
+    let define = super::invoke_trigger(define)?.ok_or_else(incorrect_username_or_password)?;
+    if define["failAuthentication"].as_bool() == Some(true) {
+        return Err(incorrect_username_or_password());
+    }
+    if define["issueTokens"].as_bool() == Some(true) {
+        return super::complete_authentication(
+            user_pool_id,
+            client_id,
+            username,
+            "TokenGeneration_Authentication",
+            client_metadata,
+        );
+    }
+    let challenge_name = define["challengeName"].as_str().unwrap_or_default();
+    if challenge_name != CUSTOM_CHALLENGE {
+        return Err(ActionError::new(
+            "InvalidLambdaResponseException",
+            &format!(
+                "Unsupported challenge name {} returned by DefineAuthChallenge.",
+                challenge_name
+            ),
+        ));
+    }
+
+    let create = super::with_store(|store| {
+        Ok::<_, ActionError>(super::trigger_event(
+            store.user_pool(user_pool_id)?,
+            Trigger::CreateAuthChallenge,
+            "CreateAuthChallenge_Authentication",
+            Some(client_id),
+            username,
+            serde_json::json!({
+                "userAttributes": user_attributes,
+                "challengeName": challenge_name,
+                "session": history,
+                "clientMetadata": client_metadata,
+                "userNotFound": false,
+            }),
+            serde_json::json!({
+                "publicChallengeParameters": {},
+                "privateChallengeParameters": {},
+                "challengeMetadata": null,
+            }),
+        ))
+    })?;
+    let create = super::invoke_trigger(create)?.ok_or_else(|| {
+        ActionError::invalid_parameter(
+            "Create auth challenge lambda trigger is not configured for the user pool.",
+        )
+    })?;
+    let mut challenge_parameters: HashMap<String, String> =
+        serde_json::from_value(create["publicChallengeParameters"].clone()).unwrap_or_default();
+    challenge_parameters.insert("USERNAME".to_string(), username.to_string());
+
+    let mut session = AuthSession::new(user_pool_id, client_id, username, CUSTOM_CHALLENGE);
+    session.parameters.insert(
+        SESSION_HISTORY.to_string(),
+        serde_json::to_string(&history).unwrap(),
+    );
+    session.parameters.insert(
+        SESSION_PRIVATE_CHALLENGE_PARAMETERS.to_string(),
+        create["privateChallengeParameters"].to_string(),
+    );
+    session.parameters.insert(
+        SESSION_CHALLENGE_METADATA.to_string(),
+        create["challengeMetadata"].to_string(),
+    );
+    let response = AuthResponse {
+        challenge_name: Some(CUSTOM_CHALLENGE.to_string()),
+        challenge_parameters,
+        session: Some(session.id.clone()),
+        ..Default::default()
+    };
+    super::with_store(|store| store.add_session(session));
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn challenge_result_as_trigger_event() {
+        let result = ChallengeResult {
+            challenge_name: CUSTOM_CHALLENGE.to_string(),
+            challenge_result: true,
+            challenge_metadata: None,
+        };
+        assert_eq!(
+            serde_json::json!({
+                "challengeName": "CUSTOM_CHALLENGE",
+                "challengeResult": true,
+                "challengeMetadata": null,
+            }),
+            serde_json::to_value(&result).unwrap()
+        );
+    }
+}
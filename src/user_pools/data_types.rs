@@ -15,6 +15,7 @@ mod message_template_type;
 mod mfa_option_type;
 mod number_attribute_constraints_type;
 mod password_policy_type;
+mod pre_token_generation_version_config_type;
 mod provider_user_identifier_type;
 mod recovery_option_type;
 mod resource_server_scope_type;
@@ -47,6 +48,7 @@ pub use message_template_type::*;
 pub use mfa_option_type::*;
 pub use number_attribute_constraints_type::*;
 pub use password_policy_type::*;
+pub use pre_token_generation_version_config_type::*;
 pub use provider_user_identifier_type::*;
 pub use recovery_option_type::*;
 pub use resource_server_scope_type::*;
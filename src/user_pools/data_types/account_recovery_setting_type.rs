@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct AccountRecoverySettingType {
     #[validate]
     #[validate(length(min = 1, max = 2))]
-    recovery_mechanism: Option<Vec<super::RecoveryOptionType>>,
+    pub recovery_mechanism: Option<Vec<super::RecoveryOptionType>>,
 }
@@ -3,17 +3,17 @@ use crate::common::ARN_REGEX;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct AnalyticsConfigurationType {
     #[validate(length(min = 20, max = 2048))]
     #[validate(regex = "ARN_REGEX")]
-    application_arn: Option<String>,
+    pub application_arn: Option<String>,
     #[validate(regex = "APPLICATION_ID_REGEX")]
-    application_id: Option<String>,
-    external_id: Option<String>,
+    pub application_id: Option<String>,
+    pub external_id: Option<String>,
     #[validate(length(min = 20, max = 2048))]
     #[validate(regex = "ARN_REGEX")]
-    role_arn: Option<String>,
-    user_data_shared: Option<bool>,
+    pub role_arn: Option<String>,
+    pub user_data_shared: Option<bool>,
 }
@@ -3,14 +3,14 @@ use crate::validator::includes_lambda_version;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct CustomSMSLambdaVersionConfigType {
     #[validate(required)]
     #[validate(length(min = 20, max = 2048))]
     #[validate(regex = "ARN_REGEX")]
-    lambda_arn: Option<String>,
+    pub lambda_arn: Option<String>,
     #[validate(required)]
     #[validate(custom(function = "includes_lambda_version"))]
-    lambda_version: Option<String>,
+    pub lambda_version: Option<String>,
 }
@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct DeviceConfigurationType {
-    challenge_required_on_new_device: Option<bool>,
-    device_only_remembered_on_user_prompt: Option<bool>,
+    pub challenge_required_on_new_device: Option<bool>,
+    pub device_only_remembered_on_user_prompt: Option<bool>,
 }
@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct DeviceSecretVerifierConfigType {
-    password_verifier: Option<String>,
-    salt: Option<String>,
+    pub password_verifier: Option<String>,
+    pub salt: Option<String>,
 }
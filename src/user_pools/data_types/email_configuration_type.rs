@@ -12,18 +12,18 @@ fn includes_email_sending_account(value: &str) -> Result<(), ValidationError> {
     includes(value, vec!["COGNITO_DEFAULT", "DEVELOPER"])
 }
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct EmailConfigurationType {
     #[validate(length(min = 1, max = 64))]
     #[validate(regex = "CONFIGURATION_SET_REGEX")]
-    configuration_set: Option<String>,
+    pub configuration_set: Option<String>,
     #[validate(custom(function = "includes_email_sending_account"))]
-    email_sending_account: Option<String>,
-    from: Option<String>,
+    pub email_sending_account: Option<String>,
+    pub from: Option<String>,
     #[validate(regex = "EMAIL_REGEX")]
-    reply_to_email_address: Option<String>,
+    pub reply_to_email_address: Option<String>,
     #[validate(length(min = 20, max = 2048))]
     #[validate(regex = "ARN_REGEX")]
-    source_arn: Option<String>,
+    pub source_arn: Option<String>,
 }
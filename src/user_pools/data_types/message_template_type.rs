@@ -12,16 +12,16 @@ static EMAIL_SUBJECT_REGEX: Lazy<Regex> =
 
 static SMS_MESSAGE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r".*\{####\}.*").unwrap());
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct MessageTemplateType {
     #[validate(length(min = 6, max = 20000))]
     #[validate(regex = "EMAIL_MESSAGE_REGEX")]
-    email_message: Option<String>,
+    pub email_message: Option<String>,
     #[validate(length(min = 6, max = 140))]
     #[validate(regex = "EMAIL_SUBJECT_REGEX")]
-    email_subject: Option<String>,
+    pub email_subject: Option<String>,
     #[validate(length(min = 6, max = 140))]
     #[validate(regex = "SMS_MESSAGE_REGEX")]
-    sms_message: Option<String>,
+    pub sms_message: Option<String>,
 }
@@ -7,12 +7,12 @@ fn includes_delivery_medium(value: &str) -> Result<(), ValidationError> {
     includes(value, vec!["SMS", "EMAIL"])
 }
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct MFAOptionType {
     #[validate(length(min = 1, max = 32))]
     #[validate(regex = "NAME_REGEX")]
-    attribute_name: Option<String>,
+    pub attribute_name: Option<String>,
     #[validate(custom(function = "includes_delivery_medium"))]
-    delivery_medium: Option<String>,
+    pub delivery_medium: Option<String>,
 }
@@ -5,10 +5,10 @@ use validator::Validate;
 
 static DEVICE_KEY_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w-]+_[0-9a-f-]+").unwrap());
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct NumberAttributeConstraintsType {
-    max_value: Option<String>,
+    pub max_value: Option<String>,
     #[validate(regex = "DEVICE_KEY_REGEX")]
-    min_value: Option<String>,
+    pub min_value: Option<String>,
 }
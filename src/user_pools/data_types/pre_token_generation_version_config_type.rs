@@ -0,0 +1,16 @@
+use crate::common::ARN_REGEX;
+use crate::validator::includes_pre_token_generation_lambda_version;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct PreTokenGenerationVersionConfigType {
+    #[validate(required)]
+    #[validate(length(min = 20, max = 2048))]
+    #[validate(regex = "ARN_REGEX")]
+    pub lambda_arn: Option<String>,
+    #[validate(required)]
+    #[validate(custom(function = "includes_pre_token_generation_lambda_version"))]
+    pub lambda_version: Option<String>,
+}
@@ -2,7 +2,7 @@ use crate::common::NAME_REGEX;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct ProviderUserIdentifierType {
     pub provider_attribute_name: Option<String>,
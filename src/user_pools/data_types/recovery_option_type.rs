@@ -10,13 +10,13 @@ fn includes_valid_name(value: &str) -> Result<(), ValidationError> {
     )
 }
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct RecoveryOptionType {
     #[validate(required)]
     #[validate(custom(function = "includes_valid_name"))]
-    name: Option<String>,
+    pub name: Option<String>,
     #[validate(required)]
     #[validate(range(min = 1, max = 2))]
-    priority: Option<i64>,
+    pub priority: Option<i64>,
 }
@@ -6,7 +6,7 @@ use validator::Validate;
 static SCOPE_NAME_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"[\x21\x23-\x2E\x30-\x5B\x5D-\x7E]+").unwrap());
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct ResourceServerScopeType {
     #[validate(length(min = 1, max = 256))]
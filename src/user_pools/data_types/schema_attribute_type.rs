@@ -8,7 +8,7 @@ fn includes_valid_attribute_data_type(value: &str) -> Result<(), ValidationError
     includes(value, vec!["String", "Number", "DateTime", "Boolean"])
 }
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct SchemaAttributeType {
     #[validate(custom(function = "includes_valid_attribute_data_type"))]
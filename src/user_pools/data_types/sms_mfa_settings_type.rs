@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct SMSMfaSettingsType {
     pub enabled: Option<bool>,
-    pub prefrred_mfa: Option<bool>,
+    pub preferred_mfa: Option<bool>,
 }
@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct StringAttributeConstraintsType {
-    max_length: Option<String>,
-    min_length: Option<String>,
+    pub max_length: Option<String>,
+    pub min_length: Option<String>,
 }
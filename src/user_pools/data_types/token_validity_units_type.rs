@@ -6,13 +6,13 @@ fn includes_valid_time_unit(value: &str) -> Result<(), ValidationError> {
     includes(value, vec!["seconds", "minutes", "hours", "days"])
 }
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct TokenValidityUnitsType {
     #[validate(custom(function = "includes_valid_time_unit"))]
-    access_token: Option<String>,
+    pub access_token: Option<String>,
     #[validate(custom(function = "includes_valid_time_unit"))]
-    id_token: Option<String>,
+    pub id_token: Option<String>,
     #[validate(custom(function = "includes_valid_time_unit"))]
-    refresh_token: Option<String>,
+    pub refresh_token: Option<String>,
 }
@@ -6,9 +6,9 @@ fn includes_valid_advanced_secure_mode(value: &str) -> Result<(), ValidationErro
     includes(value, vec!["OFF", "AUDIT", "ENFORCED"])
 }
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct UserPoolAddOnsType {
     #[validate(custom(function = "includes_valid_advanced_secure_mode"))]
-    advanced_security_mode: Option<String>,
+    pub advanced_security_mode: Option<String>,
 }
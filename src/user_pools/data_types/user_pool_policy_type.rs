@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct UserPoolPolicyType {
     #[validate]
-    password_policy: Option<super::PasswordPolicyType>,
+    pub password_policy: Option<super::PasswordPolicyType>,
 }
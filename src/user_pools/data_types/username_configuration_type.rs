@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct UsernameConfigurationType {
     #[validate(required)]
-    case_sensitive: Option<bool>,
+    pub case_sensitive: Option<bool>,
 }
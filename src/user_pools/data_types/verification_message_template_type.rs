@@ -21,24 +21,24 @@ fn includes_valid_email_option(value: &str) -> Result<(), ValidationError> {
     includes(value, vec!["CONFIRM_WITH_LINK", "CONFIRM_WITH_CODE"])
 }
 
-#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Validate)]
 #[serde(rename_all = "PascalCase")]
 pub struct VerificationMessageTemplateType {
     #[validate(custom(function = "includes_valid_email_option"))]
-    default_email_option: Option<String>,
+    pub default_email_option: Option<String>,
     #[validate(length(min = 6, max = 20000))]
     #[validate(regex = "EMAIL_MESSAGE_REGEX")]
-    email_message: Option<String>,
+    pub email_message: Option<String>,
     #[validate(length(min = 6, max = 20000))]
     #[validate(regex = "EMAIL_MESSAGE_BY_LINK_REGEX")]
-    email_message_by_link: Option<String>,
+    pub email_message_by_link: Option<String>,
     #[validate(length(min = 1, max = 140))]
     #[validate(regex = "EMAIL_SUBJECT_REGEX")]
-    email_subject: Option<String>,
+    pub email_subject: Option<String>,
     #[validate(length(min = 1, max = 140))]
     #[validate(regex = "EMAIL_SUBJECT_REGEX")]
-    email_subject_by_link: Option<String>,
+    pub email_subject_by_link: Option<String>,
     #[validate(length(min = 6, max = 140))]
     #[validate(regex = "SMS_MESSAGE_REGEX")]
-    sms_message: Option<String>,
+    pub sms_message: Option<String>,
 }
@@ -0,0 +1,87 @@
+use crate::common::{NAME_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DELETE_GROUP_NAME: &str = "DeleteGroup";
+pub const DELETE_GROUP_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.DeleteGroup";
+
+super::gen_response_err!(
+    DeleteGroupError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteGroupRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "NAME_REGEX")]
+    pub group_name: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for DeleteGroupRequest {
+    fn to_action_name() -> &'static str {
+        DELETE_GROUP_NAME
+    }
+}
+
+impl super::ToResponse for DeleteGroupRequest {
+    type E = DeleteGroupError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_empty_response(self, delete_group)
+    }
+}
+
+fn delete_group(request: &DeleteGroupRequest) -> Result<(), super::ActionError> {
+    super::with_store(|store| {
+        store
+            .user_pool_mut(request.user_pool_id.as_ref().unwrap())?
+            .remove_group(request.group_name.as_ref().unwrap())?;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DeleteGroupRequest {
+            group_name: Some("group_name".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DeleteGroupRequest {
+            group_name: Some("".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = DeleteGroupError::ResourceNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DeleteGroupError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::common::TOKEN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DELETE_USER_NAME: &str = "DeleteUser";
+pub const DELETE_USER_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.DeleteUser";
+
+super::gen_response_err!(
+    DeleteUserError,
+    ForbiddenException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteUserRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex = "TOKEN_REGEX")]
+    pub access_token: Option<String>,
+}
+
+impl super::ToActionName for DeleteUserRequest {
+    fn to_action_name() -> &'static str {
+        DELETE_USER_NAME
+    }
+}
+
+impl super::ToResponse for DeleteUserRequest {
+    type E = DeleteUserError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_empty_response(self, delete_user)
+    }
+}
+
+fn delete_user(request: &DeleteUserRequest) -> Result<(), super::ActionError> {
+    super::with_store(|store| {
+        let (user_pool_id, username) =
+            super::verify_access_token(store, request.access_token.as_ref().unwrap())?;
+        store.user_pool_mut(&user_pool_id)?.remove_user(&username)?;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DeleteUserRequest {
+            access_token: Some("access_token".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DeleteUserRequest {
+            access_token: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = DeleteUserError::NotAuthorizedException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DeleteUserError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::common::{NAME_REGEX, TOKEN_REGEX};
+use crate::{http, validator::regex_in_array};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const DELETE_USER_ATTRIBUTES_NAME: &str = "DeleteUserAttributes";
+pub const DELETE_USER_ATTRIBUTES_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.DeleteUserAttributes";
+
+fn validate_user_attribute_names_regex(value: &[String]) -> Result<(), ValidationError> {
+    regex_in_array(value, &NAME_REGEX)
+}
+
+super::gen_response_err!(
+    DeleteUserAttributesError,
+    ForbiddenException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteUserAttributesRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex = "TOKEN_REGEX")]
+    pub access_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 32))]
+    #[validate(custom(function = "validate_user_attribute_names_regex"))]
+    pub user_attribute_names: Option<Vec<String>>,
+}
+
+impl super::ToActionName for DeleteUserAttributesRequest {
+    fn to_action_name() -> &'static str {
+        DELETE_USER_ATTRIBUTES_NAME
+    }
+}
+
+impl super::ToResponse for DeleteUserAttributesRequest {
+    type E = DeleteUserAttributesError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_empty_response(self, delete_user_attributes)
+    }
+}
+
+fn delete_user_attributes(request: &DeleteUserAttributesRequest) -> Result<(), super::ActionError> {
+    let names = request.user_attribute_names.as_ref().unwrap();
+    super::with_store(|store| {
+        let (user_pool_id, username) =
+            super::verify_access_token(store, request.access_token.as_ref().unwrap())?;
+        store
+            .user_pool_mut(&user_pool_id)?
+            .user_mut(&username)?
+            .delete_attributes(names)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DeleteUserAttributesRequest {
+            access_token: Some("access_token".to_string()),
+            user_attribute_names: Some(vec!["foo".to_string()]),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DeleteUserAttributesRequest {
+            access_token: Some("access_token".to_string()),
+            user_attribute_names: Some(vec![]),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = DeleteUserAttributesError::NotAuthorizedException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DeleteUserAttributesError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
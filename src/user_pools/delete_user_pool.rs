@@ -0,0 +1,80 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DELETE_USER_POOL_NAME: &str = "DeleteUserPool";
+pub const DELETE_USER_POOL_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.DeleteUserPool";
+
+super::gen_response_err!(
+    DeleteUserPoolError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserImportInProgressException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteUserPoolRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for DeleteUserPoolRequest {
+    fn to_action_name() -> &'static str {
+        DELETE_USER_POOL_NAME
+    }
+}
+
+impl super::ToResponse for DeleteUserPoolRequest {
+    type E = DeleteUserPoolError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_empty_response(self, delete_user_pool)
+    }
+}
+
+fn delete_user_pool(request: &DeleteUserPoolRequest) -> Result<(), super::ActionError> {
+    super::with_store(|store| {
+        store.remove_user_pool(request.user_pool_id.as_ref().unwrap())?;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DeleteUserPoolRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DeleteUserPoolRequest {
+            user_pool_id: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = DeleteUserPoolError::ResourceNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DeleteUserPoolError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
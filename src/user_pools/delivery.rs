@@ -0,0 +1,222 @@
+use super::{ActionError, CodePurpose, MessageType, TriggerContext, User, UserPool};
+use serde::Serialize;
+use strum_macros::Display;
+
+pub const EMAIL_ATTRIBUTE: &str = "email";
+pub const PHONE_NUMBER_ATTRIBUTE: &str = "phone_number";
+
+/// Delivery medium, same as DeliveryMediumType.
+#[derive(Display, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum DeliveryMedium {
+    EMAIL,
+    SMS,
+}
+
+/// Serializable code delivery, same as CodeDeliveryDetailsType.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CodeDeliveryDetailsType {
+    pub attribute_name: String,
+    pub delivery_medium: DeliveryMedium,
+    pub destination: String,
+}
+
+/// Destination of a message for the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeDelivery {
+    pub attribute_name: String,
+    pub delivery_medium: DeliveryMedium,
+    pub destination: String,
+}
+
+impl CodeDelivery {
+    /// Returns delivery to `email` or `phone_number` attribute of the user.
+    pub fn for_attribute(user: &User, attribute_name: &str) -> Option<Self> {
+        let delivery_medium = match attribute_name {
+            EMAIL_ATTRIBUTE => DeliveryMedium::EMAIL,
+            PHONE_NUMBER_ATTRIBUTE => DeliveryMedium::SMS,
+            _ => return None,
+        };
+        user.attribute(attribute_name)
+            .map(|destination| CodeDelivery {
+                attribute_name: attribute_name.to_string(),
+                delivery_medium,
+                destination: destination.clone(),
+            })
+    }
+
+    pub fn to_code_delivery_details_type(&self) -> CodeDeliveryDetailsType {
+        let destination = match self.delivery_medium {
+            DeliveryMedium::EMAIL => mask_email(&self.destination),
+            DeliveryMedium::SMS => mask_phone_number(&self.destination),
+        };
+        CodeDeliveryDetailsType {
+            attribute_name: self.attribute_name.clone(),
+            delivery_medium: self.delivery_medium,
+            destination,
+        }
+    }
+}
+
+/// Masks email address (e.g. `u***@e***`).
+pub fn mask_email(email: &str) -> String {
+    let mut parts = email.splitn(2, '@');
+    let first = |s: Option<&str>| s.and_then(|s| s.chars().next()).unwrap_or_default();
+    let local = first(parts.next());
+    let domain = first(parts.next());
+    format!("{}***@{}***", local, domain)
+}
+
+/// Masks phone number but the last 4 digits (e.g. `+*******6789`).
+pub fn mask_phone_number(phone_number: &str) -> String {
+    let len = phone_number.chars().count();
+    phone_number
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if (i == 0 && c == '+') || i + 4 >= len {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// Returns delivery of verification codes by `AutoVerifiedAttributes` of the user pool,
+/// phone number has priority over email.
+pub fn verification_delivery(pool: &UserPool, user: &User) -> Option<CodeDelivery> {
+    let auto_verified = pool.config.auto_verified_attributes.as_deref()?;
+    [PHONE_NUMBER_ATTRIBUTE, EMAIL_ATTRIBUTE]
+        .iter()
+        .filter(|name| auto_verified.iter().any(|a| a == *name))
+        .find_map(|name| CodeDelivery::for_attribute(user, name))
+}
+
+/// Returns delivery of password reset codes by `AccountRecoverySetting` of the user pool,
+/// only verified email or phone number can receive the code.
+pub fn recovery_delivery(pool: &UserPool, user: &User) -> Result<CodeDelivery, ActionError> {
+    let mut mechanisms = pool
+        .config
+        .account_recovery_setting
+        .as_ref()
+        .and_then(|s| s.recovery_mechanism.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|m| Some((m.priority.unwrap_or_default(), m.name?)))
+        .collect::<Vec<_>>();
+    mechanisms.sort();
+    let names = if mechanisms.is_empty() {
+        vec!["verified_phone_number", "verified_email"]
+    } else {
+        mechanisms.iter().map(|(_, name)| name.as_str()).collect()
+    };
+    if names == ["admin_only"] {
+        return Err(ActionError::new(
+            "NotAuthorizedException",
+            "User password cannot be reset in the current state.",
+        ));
+    }
+    names
+        .into_iter()
+        .filter_map(|name| name.strip_prefix("verified_"))
+        .filter(|attr| {
+            user.attribute(&format!("{}_verified", attr))
+                .map(String::as_str)
+                == Some("true")
+        })
+        .find_map(|attr| CodeDelivery::for_attribute(user, attr))
+        .ok_or_else(|| {
+            ActionError::invalid_parameter(
+                "Cannot reset password for the user as there is no registered/verified email or phone_number",
+            )
+        })
+}
+
+/// Issues a code for the message type and sends it to the user.
+pub fn send_code(
+    user_pool_id: &str,
+    username: &str,
+    message_type: MessageType,
+    delivery: &CodeDelivery,
+    context: &TriggerContext,
+) -> Result<CodeDeliveryDetailsType, ActionError> {
+    let purpose = match message_type {
+        MessageType::SignUp | MessageType::ResendCode => CodePurpose::SignUp,
+        MessageType::ForgotPassword => CodePurpose::ForgotPassword,
+        MessageType::Authentication => CodePurpose::SmsMfa,
+        _ => CodePurpose::VerifyAttribute(delivery.attribute_name.clone()),
+    };
+    let (username, code) = super::with_store(|store| {
+        let user = store.user_pool_mut(user_pool_id)?.user_mut(username)?;
+        let code = user.issue_code(purpose, Some(&delivery.attribute_name));
+        Ok::<_, ActionError>((user.username.clone(), code))
+    })?;
+    super::send_message(
+        user_pool_id,
+        &username,
+        message_type,
+        delivery,
+        &code,
+        context,
+    )?;
+    Ok(delivery.to_code_delivery_details_type())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn mask_destinations() {
+        assert_eq!("u***@e***", mask_email("user@example.com"));
+        assert_eq!("+*******6789", mask_phone_number("+12345556789"));
+    }
+
+    #[test]
+    fn recovery_delivery_needs_verified_attribute() {
+        let pool = UserPool::new("user_pool_id", Default::default());
+        let mut user = User::new("username");
+        user.set_attributes(vec![("email".to_string(), "user@example.com".to_string())])
+            .unwrap();
+        assert!(recovery_delivery(&pool, &user).is_err());
+        user.set_attributes(vec![("email_verified".to_string(), "true".to_string())])
+            .unwrap();
+        assert_eq!(
+            DeliveryMedium::EMAIL,
+            recovery_delivery(&pool, &user).unwrap().delivery_medium
+        );
+    }
+
+    #[test]
+    fn phone_number_has_priority() {
+        let pool = UserPool::new(
+            "user_pool_id",
+            crate::user_pools::CreateUserPoolRequest {
+                auto_verified_attributes: Some(vec![
+                    "email".to_string(),
+                    "phone_number".to_string(),
+                ]),
+                ..Default::default()
+            },
+        );
+        let mut user = User::new("username");
+        user.set_attributes(vec![("email".to_string(), "user@example.com".to_string())])
+            .unwrap();
+        assert_eq!(
+            DeliveryMedium::EMAIL,
+            verification_delivery(&pool, &user).unwrap().delivery_medium
+        );
+        user.set_attributes(vec![(
+            "phone_number".to_string(),
+            "+12345556789".to_string(),
+        )])
+        .unwrap();
+        assert_eq!(
+            DeliveryMedium::SMS,
+            verification_delivery(&pool, &user).unwrap().delivery_medium
+        );
+    }
+}
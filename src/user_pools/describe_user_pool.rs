@@ -0,0 +1,83 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DESCRIBE_USER_POOL_NAME: &str = "DescribeUserPool";
+pub const DESCRIBE_USER_POOL_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.DescribeUserPool";
+
+super::gen_response_err!(
+    DescribeUserPoolError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserPoolTaggingException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DescribeUserPoolRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for DescribeUserPoolRequest {
+    fn to_action_name() -> &'static str {
+        DESCRIBE_USER_POOL_NAME
+    }
+}
+
+impl super::ToResponse for DescribeUserPoolRequest {
+    type E = DescribeUserPoolError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, DESCRIBE_USER_POOL_NAME, describe_user_pool)
+    }
+}
+
+fn describe_user_pool(
+    request: &DescribeUserPoolRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| {
+        let pool = store.user_pool(request.user_pool_id.as_ref().unwrap())?;
+        Ok(serde_json::json!({ "UserPool": pool.to_user_pool_type() }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DescribeUserPoolRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DescribeUserPoolRequest {
+            user_pool_id: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = DescribeUserPoolError::ResourceNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DescribeUserPoolError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::common::{CLIENT_ID_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const DESCRIBE_USER_POOL_CLIENT_NAME: &str = "DescribeUserPoolClient";
+pub const DESCRIBE_USER_POOL_CLIENT_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.DescribeUserPoolClient";
+
+super::gen_response_err!(
+    DescribeUserPoolClientError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct DescribeUserPoolClientRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "CLIENT_ID_REGEX")]
+    pub client_id: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for DescribeUserPoolClientRequest {
+    fn to_action_name() -> &'static str {
+        DESCRIBE_USER_POOL_CLIENT_NAME
+    }
+}
+
+impl super::ToResponse for DescribeUserPoolClientRequest {
+    type E = DescribeUserPoolClientError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(
+            self,
+            DESCRIBE_USER_POOL_CLIENT_NAME,
+            describe_user_pool_client,
+        )
+    }
+}
+
+fn describe_user_pool_client(
+    request: &DescribeUserPoolClientRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| {
+        let client = store
+            .user_pool(request.user_pool_id.as_ref().unwrap())?
+            .client(request.client_id.as_ref().unwrap())?;
+        Ok(serde_json::json!({ "UserPoolClient": client.to_user_pool_client_type() }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = DescribeUserPoolClientRequest {
+            client_id: Some("client_id".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = DescribeUserPoolClientRequest {
+            client_id: Some("".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = DescribeUserPoolClientError::ResourceNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = DescribeUserPoolClientError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -97,3 +97,61 @@ where
             .or_else(|_| CommonError::from_str(s).map(ResponseError::CommonError))
     }
 }
+
+/// Error raised while processing an action against the stored state.
+///
+/// `error` is an exception name (e.g. `UserNotFoundException`) and is converted
+/// into the action specific error type when responding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionError {
+    pub error: String,
+    pub message: String,
+}
+
+impl ActionError {
+    pub fn new(error: &str, message: &str) -> Self {
+        ActionError {
+            error: error.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn user_not_found() -> Self {
+        Self::new("UserNotFoundException", "User does not exist.")
+    }
+
+    pub fn username_exists() -> Self {
+        Self::new("UsernameExistsException", "User account already exists")
+    }
+
+    pub fn resource_not_found(message: &str) -> Self {
+        Self::new("ResourceNotFoundException", message)
+    }
+
+    pub fn invalid_parameter(message: &str) -> Self {
+        Self::new("InvalidParameterException", message)
+    }
+
+    /// Generates error response, falls back to `InternalFailure` for an unknown error name.
+    pub fn to_response<T>(&self) -> super::Response
+    where
+        T: std::fmt::Display + ToStatusCode + std::str::FromStr,
+    {
+        use std::str::FromStr;
+        match ResponseError::<T>::from_str(&self.error) {
+            Ok(e) => super::error_response(e, Some(&self.message)),
+            Err(_) => {
+                log::warn!("unknown error type for action: {}", self.error);
+                super::error_response(CommonError::InternalFailure, Some(&self.message))
+            }
+        }
+    }
+}
+
+impl Display for ActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.error, self.message)
+    }
+}
+
+impl std::error::Error for ActionError {}
@@ -0,0 +1,135 @@
+use crate::common::{CLIENT_ID_REGEX, HASH_REGEX, NAME_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const FORGOT_PASSWORD_NAME: &str = "ForgotPassword";
+pub const FORGOT_PASSWORD_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.ForgotPassword";
+
+super::gen_response_err!(
+    ForgotPasswordError,
+    CodeDeliveryFailureException
+    | ForbiddenException
+    | InvalidEmailRoleAccessPolicyException
+    | InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | LimitExceededException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ForgotPasswordRequest {
+    pub analytics_metadata: Option<super::data_types::AnalyticsMetadataType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "CLIENT_ID_REGEX")]
+    pub client_id: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "HASH_REGEX")]
+    pub secret_hash: Option<String>,
+    pub user_context_data: Option<super::data_types::UserContextDataType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "NAME_REGEX")]
+    pub username: Option<String>,
+}
+
+impl super::ToActionName for ForgotPasswordRequest {
+    fn to_action_name() -> &'static str {
+        FORGOT_PASSWORD_NAME
+    }
+}
+
+impl super::ToResponse for ForgotPasswordRequest {
+    type E = ForgotPasswordError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, FORGOT_PASSWORD_NAME, forgot_password)
+    }
+}
+
+fn forgot_password(
+    request: &ForgotPasswordRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let client_id = request.client_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let user_pool_id = super::with_store(|store| {
+        let client = store.find_client(client_id)?;
+        super::verify_secret_hash(client, username, request.secret_hash.as_ref())?;
+        Ok::<_, super::ActionError>(client.user_pool_id().to_string())
+    })?;
+    super::migrate_user(
+        &user_pool_id,
+        client_id,
+        "UserMigration_ForgotPassword",
+        username,
+        None,
+        &request.client_metadata.clone().unwrap_or_default(),
+    )?;
+    let delivery = super::with_store(|store| {
+        let pool = store.user_pool(&user_pool_id)?;
+        let user = pool.user(username)?;
+        if !user.enabled {
+            return Err(super::ActionError::new(
+                "NotAuthorizedException",
+                "User is disabled.",
+            ));
+        }
+        super::recovery_delivery(pool, user)
+    })?;
+    let code_delivery_details = super::send_code(
+        &user_pool_id,
+        username,
+        super::MessageType::ForgotPassword,
+        &delivery,
+        &super::TriggerContext::new(Some(client_id), request.client_metadata.as_ref()),
+    )?;
+    Ok(serde_json::json!({ "CodeDeliveryDetails": code_delivery_details }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ForgotPasswordRequest {
+            client_id: Some("client_id".to_string()),
+            username: Some("username".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ForgotPasswordRequest {
+            client_id: Some("client_id".to_string()),
+            username: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = ForgotPasswordError::UserNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ForgotPasswordError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
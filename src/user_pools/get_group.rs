@@ -0,0 +1,88 @@
+use crate::common::{NAME_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_GROUP_NAME: &str = "GetGroup";
+pub const GET_GROUP_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.GetGroup";
+
+super::gen_response_err!(
+    GetGroupError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetGroupRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "NAME_REGEX")]
+    pub group_name: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for GetGroupRequest {
+    fn to_action_name() -> &'static str {
+        GET_GROUP_NAME
+    }
+}
+
+impl super::ToResponse for GetGroupRequest {
+    type E = GetGroupError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, GET_GROUP_NAME, get_group)
+    }
+}
+
+fn get_group(request: &GetGroupRequest) -> Result<serde_json::Value, super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    super::with_store(|store| {
+        let group = store
+            .user_pool(user_pool_id)?
+            .group(request.group_name.as_ref().unwrap())?;
+        Ok(serde_json::json!({ "Group": group.to_group_type(user_pool_id) }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetGroupRequest {
+            group_name: Some("group_name".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetGroupRequest {
+            group_name: Some("group_name".to_string()),
+            user_pool_id: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = GetGroupError::ResourceNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetGroupError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,128 @@
+use crate::common::{NAME_REGEX, TOKEN_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GET_USER_ATTRIBUTE_VERIFICATION_CODE_NAME: &str = "GetUserAttributeVerificationCode";
+pub const GET_USER_ATTRIBUTE_VERIFICATION_CODE_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode";
+
+super::gen_response_err!(
+    GetUserAttributeVerificationCodeError,
+    CodeDeliveryFailureException
+    | ForbiddenException
+    | InvalidEmailRoleAccessPolicyException
+    | InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | LimitExceededException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetUserAttributeVerificationCodeRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex = "TOKEN_REGEX")]
+    pub access_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 32))]
+    #[validate(regex = "NAME_REGEX")]
+    pub attribute_name: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+}
+
+impl super::ToActionName for GetUserAttributeVerificationCodeRequest {
+    fn to_action_name() -> &'static str {
+        GET_USER_ATTRIBUTE_VERIFICATION_CODE_NAME
+    }
+}
+
+impl super::ToResponse for GetUserAttributeVerificationCodeRequest {
+    type E = GetUserAttributeVerificationCodeError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(
+            self,
+            GET_USER_ATTRIBUTE_VERIFICATION_CODE_NAME,
+            get_user_attribute_verification_code,
+        )
+    }
+}
+
+fn get_user_attribute_verification_code(
+    request: &GetUserAttributeVerificationCodeRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let access_token = request.access_token.as_ref().unwrap();
+    let attribute_name = request.attribute_name.as_ref().unwrap();
+    let (user_pool_id, username, delivery) = super::with_store(|store| {
+        let (user_pool_id, username) = super::verify_access_token(store, access_token)?;
+        let user = store.user_pool(&user_pool_id)?.user(&username)?;
+        let delivery =
+            super::CodeDelivery::for_attribute(user, attribute_name).ok_or_else(|| {
+                super::ActionError::invalid_parameter(&format!(
+                    "Cannot send a verification code to the attribute {}.",
+                    attribute_name
+                ))
+            })?;
+        Ok((user_pool_id, username, delivery))
+    })?;
+    let code_delivery_details = super::send_code(
+        &user_pool_id,
+        &username,
+        super::MessageType::VerifyUserAttribute,
+        &delivery,
+        &super::TriggerContext::new(
+            super::access_token_client_id(access_token).as_deref(),
+            request.client_metadata.as_ref(),
+        ),
+    )?;
+    Ok(serde_json::json!({ "CodeDeliveryDetails": code_delivery_details }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GetUserAttributeVerificationCodeRequest {
+            access_token: Some("access_token".to_string()),
+            attribute_name: Some("email".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GetUserAttributeVerificationCodeRequest {
+            access_token: Some("access_token".to_string()),
+            attribute_name: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = GetUserAttributeVerificationCodeError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GetUserAttributeVerificationCodeError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::common::TOKEN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const GLOBAL_SIGN_OUT_NAME: &str = "GlobalSignOut";
+pub const GLOBAL_SIGN_OUT_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.GlobalSignOut";
+
+super::gen_response_err!(
+    GlobalSignOutError,
+    ForbiddenException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct GlobalSignOutRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex = "TOKEN_REGEX")]
+    pub access_token: Option<String>,
+}
+
+impl super::ToActionName for GlobalSignOutRequest {
+    fn to_action_name() -> &'static str {
+        GLOBAL_SIGN_OUT_NAME
+    }
+}
+
+impl super::ToResponse for GlobalSignOutRequest {
+    type E = GlobalSignOutError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_empty_response(self, global_sign_out)
+    }
+}
+
+fn global_sign_out(request: &GlobalSignOutRequest) -> Result<(), super::ActionError> {
+    super::with_store(|store| {
+        let (user_pool_id, username) =
+            super::verify_access_token(store, request.access_token.as_ref().unwrap())?;
+        let user = store.user_pool_mut(&user_pool_id)?.user_mut(&username)?;
+        user.origin_jtis.clear();
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = GlobalSignOutRequest {
+            access_token: Some("access_token".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = GlobalSignOutRequest {
+            access_token: Some("".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = GlobalSignOutError::NotAuthorizedException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = GlobalSignOutError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::common::CLIENT_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const INITIATE_AUTH_NAME: &str = "InitiateAuth";
+pub const INITIATE_AUTH_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.InitiateAuth";
+
+super::gen_response_err!(
+    InitiateAuthError,
+    ForbiddenException
+    | InvalidParameterException
+    | InvalidLambdaResponseException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | InvalidUserPoolConfigurationException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct InitiateAuthRequest {
+    #[validate]
+    pub analytics_metadata: Option<super::data_types::AnalyticsMetadataType>,
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(custom(function = "super::validate_auth_flow"))]
+    pub auth_flow: Option<String>,
+    pub auth_parameters: Option<std::collections::HashMap<String, String>>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "CLIENT_ID_REGEX")]
+    pub client_id: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    pub user_context_data: Option<super::data_types::UserContextDataType>,
+}
+
+impl super::ToActionName for InitiateAuthRequest {
+    fn to_action_name() -> &'static str {
+        INITIATE_AUTH_NAME
+    }
+}
+
+impl super::ToResponse for InitiateAuthRequest {
+    type E = InitiateAuthError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, INITIATE_AUTH_NAME, initiate_auth)
+    }
+}
+
+fn initiate_auth(request: &InitiateAuthRequest) -> Result<super::AuthResponse, super::ActionError> {
+    let client_id = request.client_id.as_ref().unwrap();
+    let auth_flow = request.auth_flow.as_ref().unwrap();
+    super::check_initiate_auth_method(auth_flow, false)?;
+    let user_pool_id = super::with_store(|store| {
+        Ok::<_, super::ActionError>(store.find_client(client_id)?.user_pool_id().to_string())
+    })?;
+    super::initiate_auth_flow(
+        &user_pool_id,
+        client_id,
+        auth_flow,
+        &request.auth_parameters.clone().unwrap_or_default(),
+        &request.client_metadata.clone().unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = InitiateAuthRequest {
+            auth_flow: Some("USER_PASSWORD_AUTH".to_string()),
+            client_id: Some("client_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = InitiateAuthRequest {
+            auth_flow: Some("UNKNOWN_AUTH".to_string()),
+            client_id: Some("client_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = InitiateAuthError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = InitiateAuthError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,181 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header};
+use once_cell::sync::OnceCell;
+use rsa::pkcs1::EncodeRsaPrivateKey;
+use rsa::pkcs8::{DecodePrivateKey, EncodePublicKey};
+use rsa::traits::PublicKeyParts;
+use rsa::RsaPrivateKey;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+const RSA_KEY_BITS: usize = 2048;
+const DEFAULT_PORT: u16 = 8080;
+
+static SIGNING_KEY: OnceCell<SigningKey> = OnceCell::new();
+
+/// RSA key for signing tokens.
+pub struct SigningKey {
+    pub kid: String,
+    /// Base64url encoded modulus.
+    pub n: String,
+    /// Base64url encoded exponent.
+    pub e: String,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+}
+
+impl SigningKey {
+    fn new(private_key: RsaPrivateKey) -> anyhow::Result<Self> {
+        let public_key = private_key.to_public_key();
+        let n = URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be());
+        let e = URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be());
+        let public_der = public_key.to_public_key_der()?;
+        let kid = URL_SAFE_NO_PAD.encode(Sha256::digest(public_der.as_bytes()));
+        let encoding_key = EncodingKey::from_rsa_der(private_key.to_pkcs1_der()?.as_bytes());
+        let decoding_key = DecodingKey::from_rsa_components(&n, &e)?;
+        Ok(SigningKey {
+            kid,
+            n,
+            e,
+            encoding_key,
+            decoding_key,
+        })
+    }
+
+    fn generate() -> Self {
+        log::info!("generate RSA key for signing tokens");
+        let private_key = RsaPrivateKey::new(&mut rand::thread_rng(), RSA_KEY_BITS).unwrap();
+        Self::new(private_key).unwrap()
+    }
+
+    fn read(path: &PathBuf) -> anyhow::Result<Self> {
+        use rsa::pkcs1::DecodeRsaPrivateKey;
+        let pem = std::fs::read_to_string(path)?;
+        let private_key =
+            RsaPrivateKey::from_pkcs8_pem(&pem).or_else(|_| RsaPrivateKey::from_pkcs1_pem(&pem))?;
+        log::info!("read signing key: {}", path.display());
+        Self::new(private_key)
+    }
+}
+
+/// Initializes signing key from PEM file, or generates a new one when no file is given.
+/// A given file which cannot be read is an error, tokens would not verify with its key.
+pub async fn init_signing_key(path: Option<&PathBuf>) -> anyhow::Result<()> {
+    let key = match path {
+        Some(path) => SigningKey::read(path)
+            .map_err(|e| anyhow::anyhow!("failed to read signing key {}: {}", path.display(), e))?,
+        None => SigningKey::generate(),
+    };
+    SIGNING_KEY.get_or_init(|| key);
+    Ok(())
+}
+
+/// Returns signing key (generated when not initialized).
+pub fn signing_key() -> &'static SigningKey {
+    SIGNING_KEY.get_or_init(SigningKey::generate)
+}
+
+/// Returns base url of this server.
+pub fn base_url() -> String {
+    let base_url = crate::opts::get_opt_base_url().unwrap_or_else(|| {
+        let port = crate::opts::OPT
+            .get()
+            .and_then(|o| o.port)
+            .unwrap_or(DEFAULT_PORT);
+        format!("http://localhost:{}", port)
+    });
+    base_url.trim_end_matches('/').to_string()
+}
+
+/// Returns token issuer url of the user pool.
+pub fn issuer(user_pool_id: &str) -> String {
+    format!("{}/{}", base_url(), user_pool_id)
+}
+
+/// Returns JSON Web Key Set for verifying tokens.
+pub fn jwks() -> serde_json::Value {
+    let key = signing_key();
+    serde_json::json!({
+        "keys": [{
+            "alg": "RS256",
+            "e": key.e,
+            "kid": key.kid,
+            "kty": "RSA",
+            "n": key.n,
+            "use": "sig",
+        }]
+    })
+}
+
+/// Returns OpenID Connect discovery document of the user pool.
+pub fn openid_configuration(user_pool_id: &str) -> serde_json::Value {
+    let issuer = issuer(user_pool_id);
+    let base_url = base_url();
+    serde_json::json!({
+        "authorization_endpoint": format!("{}/oauth2/authorize", base_url),
+        "id_token_signing_alg_values_supported": ["RS256"],
+        "issuer": issuer,
+        "jwks_uri": format!("{}/.well-known/jwks.json", issuer),
+        "response_types_supported": ["code", "token"],
+        "scopes_supported": ["openid", "email", "phone", "profile"],
+        "subject_types_supported": ["public"],
+        "token_endpoint": format!("{}/oauth2/token", base_url),
+        "token_endpoint_auth_methods_supported": ["client_secret_basic", "client_secret_post"],
+        "userinfo_endpoint": format!("{}/oauth2/userInfo", base_url),
+    })
+}
+
+/// Returns RS256 signed token.
+pub fn sign(claims: &serde_json::Value) -> String {
+    let key = signing_key();
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(key.kid.clone());
+    jsonwebtoken::encode(&header, claims, &key.encoding_key).unwrap()
+}
+
+/// Verifies token signature and expiration, and returns claims.
+pub fn verify(token: &str) -> Result<serde_json::Value, jsonwebtoken::errors::Error> {
+    let mut validation = jsonwebtoken::Validation::new(Algorithm::RS256);
+    validation.validate_aud = false;
+    validation.set_required_spec_claims(&["exp"]);
+    jsonwebtoken::decode::<serde_json::Value>(token, &signing_key().decoding_key, &validation)
+        .map(|data| data.claims)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn sign_and_verify_token() {
+        let exp = chrono::Utc::now().timestamp() + 60;
+        let token = sign(&serde_json::json!({"sub": "sub", "exp": exp}));
+        let claims = verify(&token).unwrap();
+        assert_eq!("sub", claims["sub"]);
+
+        let header = jsonwebtoken::decode_header(&token).unwrap();
+        assert_eq!(Some(signing_key().kid.clone()), header.kid);
+    }
+
+    #[test]
+    fn jwks_has_signing_key() {
+        let jwks = jwks();
+        assert_eq!(signing_key().kid, jwks["keys"][0]["kid"]);
+        assert_eq!("AQAB", jwks["keys"][0]["e"]);
+    }
+
+    #[test]
+    fn expired_token_is_invalid() {
+        let exp = chrono::Utc::now().timestamp() - 3600;
+        let token = sign(&serde_json::json!({"sub": "sub", "exp": exp}));
+        assert!(verify(&token).is_err());
+    }
+
+    #[tokio::test]
+    async fn unreadable_signing_key_is_error() {
+        let path = PathBuf::from("resources/test/not_found.pem");
+        assert!(init_signing_key(Some(&path)).await.is_err());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_GROUPS_NAME: &str = "ListGroups";
+pub const LIST_GROUPS_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.ListGroups";
+
+static NEXT_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    ListGroupsError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListGroupsRequest {
+    #[validate(range(min = 0, max = 60))]
+    pub limit: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex = "NEXT_TOKEN_REGEX")]
+    pub next_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for ListGroupsRequest {
+    fn to_action_name() -> &'static str {
+        LIST_GROUPS_NAME
+    }
+}
+
+impl super::ToResponse for ListGroupsRequest {
+    type E = ListGroupsError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, LIST_GROUPS_NAME, list_groups)
+    }
+}
+
+fn list_groups(request: &ListGroupsRequest) -> Result<serde_json::Value, super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    super::with_store(|store| {
+        let page = super::paginate(
+            store.user_pool(user_pool_id)?.groups.values().collect(),
+            |group| group.name.clone(),
+            super::page_size(request.limit),
+            request.next_token.as_ref(),
+        )?;
+        let groups = page
+            .items
+            .iter()
+            .map(|group| group.to_group_type(user_pool_id))
+            .collect::<Vec<_>>();
+        Ok(serde_json::json!({
+            "Groups": groups,
+            "NextToken": page.next_token,
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListGroupsRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListGroupsRequest {
+            limit: Some(61),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = ListGroupsError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListGroupsError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_IDENTITY_PROVIDERS_NAME: &str = "ListIdentityProviders";
+pub const LIST_IDENTITY_PROVIDERS_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.ListIdentityProviders";
+
+static NEXT_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    ListIdentityProvidersError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListIdentityProvidersRequest {
+    #[validate(range(min = 0, max = 60))]
+    pub max_results: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex = "NEXT_TOKEN_REGEX")]
+    pub next_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for ListIdentityProvidersRequest {
+    fn to_action_name() -> &'static str {
+        LIST_IDENTITY_PROVIDERS_NAME
+    }
+}
+
+impl super::ToResponse for ListIdentityProvidersRequest {
+    type E = ListIdentityProvidersError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(
+            self,
+            LIST_IDENTITY_PROVIDERS_NAME,
+            list_identity_providers,
+        )
+    }
+}
+
+fn list_identity_providers(
+    request: &ListIdentityProvidersRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| {
+        let page = super::paginate(
+            store
+                .user_pool(request.user_pool_id.as_ref().unwrap())?
+                .identity_providers
+                .values()
+                .collect(),
+            |provider| provider.provider_name().to_string(),
+            super::page_size(request.max_results),
+            request.next_token.as_ref(),
+        )?;
+        let providers = page
+            .items
+            .iter()
+            .map(|provider| provider.to_provider_description())
+            .collect::<Vec<_>>();
+        Ok(serde_json::json!({
+            "NextToken": page.next_token,
+            "Providers": providers,
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListIdentityProvidersRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListIdentityProvidersRequest {
+            max_results: Some(61),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = ListIdentityProvidersError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListIdentityProvidersError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
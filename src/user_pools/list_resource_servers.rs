@@ -0,0 +1,111 @@
+use crate::common::{self, USER_POOL_ID_REGEX};
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_RESOURCE_SERVERS_NAME: &str = "ListResourceServers";
+pub const LIST_RESOURCE_SERVERS_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.ListResourceServers";
+
+static NEXT_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    ListResourceServersError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListResourceServersRequest {
+    #[validate(range(min = 1, max = 50))]
+    pub max_results: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex = "NEXT_TOKEN_REGEX")]
+    pub next_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for ListResourceServersRequest {
+    fn to_action_name() -> &'static str {
+        LIST_RESOURCE_SERVERS_NAME
+    }
+}
+
+impl super::ToResponse for ListResourceServersRequest {
+    type E = ListResourceServersError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, LIST_RESOURCE_SERVERS_NAME, list_resource_servers)
+    }
+}
+
+fn list_resource_servers(
+    request: &ListResourceServersRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| {
+        let page = super::paginate(
+            store
+                .user_pool(request.user_pool_id.as_ref().unwrap())?
+                .resource_servers
+                .values()
+                .collect(),
+            |server| server.identifier.clone().unwrap_or_default(),
+            super::page_size(request.max_results),
+            request.next_token.as_ref(),
+        )?;
+        let resource_servers = page
+            .items
+            .iter()
+            .map(|server| common::without_nulls(serde_json::json!(server)))
+            .collect::<Vec<_>>();
+        Ok(serde_json::json!({
+            "NextToken": page.next_token,
+            "ResourceServers": resource_servers,
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListResourceServersRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListResourceServersRequest {
+            max_results: Some(61),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = ListResourceServersError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListResourceServersError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
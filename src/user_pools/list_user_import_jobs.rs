@@ -0,0 +1,110 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_USER_IMPORT_JOBS_NAME: &str = "ListUserImportJobs";
+pub const LIST_USER_IMPORT_JOBS_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.ListUserImportJobs";
+
+static PAGINATION_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    ListUserImportJobsError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListUserImportJobsRequest {
+    #[validate(required)]
+    #[validate(range(min = 1, max = 60))]
+    pub max_results: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex = "PAGINATION_TOKEN_REGEX")]
+    pub pagination_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for ListUserImportJobsRequest {
+    fn to_action_name() -> &'static str {
+        LIST_USER_IMPORT_JOBS_NAME
+    }
+}
+
+impl super::ToResponse for ListUserImportJobsRequest {
+    type E = ListUserImportJobsError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, LIST_USER_IMPORT_JOBS_NAME, list_user_import_jobs)
+    }
+}
+
+fn list_user_import_jobs(
+    request: &ListUserImportJobsRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| {
+        let pool = store.user_pool(request.user_pool_id.as_ref().unwrap())?;
+        let page = super::paginate(
+            pool.user_import_jobs.values().collect(),
+            |job| job.job_id.clone(),
+            super::page_size(request.max_results),
+            request.pagination_token.as_ref(),
+        )?;
+        let jobs = page
+            .items
+            .iter()
+            .map(|job| job.to_user_import_job_type(pool.region()))
+            .collect::<Vec<_>>();
+        Ok(serde_json::json!({
+            "PaginationToken": page.next_token,
+            "UserImportJobs": jobs,
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListUserImportJobsRequest {
+            max_results: Some(10),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListUserImportJobsRequest {
+            max_results: Some(61),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = ListUserImportJobsError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListUserImportJobsError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_USER_POOLS_NAME: &str = "ListUserPools";
+pub const LIST_USER_POOLS_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.ListUserPools";
+
+static NEXT_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    ListUserPoolsError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListUserPoolsRequest {
+    #[validate(required)]
+    #[validate(range(min = 1, max = 60))]
+    pub max_results: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex = "NEXT_TOKEN_REGEX")]
+    pub next_token: Option<String>,
+}
+
+impl super::ToActionName for ListUserPoolsRequest {
+    fn to_action_name() -> &'static str {
+        LIST_USER_POOLS_NAME
+    }
+}
+
+impl super::ToResponse for ListUserPoolsRequest {
+    type E = ListUserPoolsError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, LIST_USER_POOLS_NAME, list_user_pools)
+    }
+}
+
+fn list_user_pools(
+    request: &ListUserPoolsRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| {
+        let page = super::paginate(
+            store.user_pools.values().collect(),
+            |pool| pool.id.clone(),
+            super::page_size(request.max_results),
+            request.next_token.as_ref(),
+        )?;
+        let user_pools = page
+            .items
+            .iter()
+            .map(|pool| pool.to_user_pool_description_type())
+            .collect::<Vec<_>>();
+        Ok(serde_json::json!({
+            "NextToken": page.next_token,
+            "UserPools": user_pools,
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListUserPoolsRequest {
+            max_results: Some(10),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListUserPoolsRequest {
+            max_results: Some(61),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = ListUserPoolsError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListUserPoolsError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::common::{NAME_REGEX, USER_POOL_ID_REGEX};
+use crate::{http, validator::regex_in_array};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const LIST_USERS_NAME: &str = "ListUsers";
+pub const LIST_USERS_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.ListUsers";
+
+static PAGINATION_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+fn validate_attributes_to_get_regex(value: &[String]) -> Result<(), ValidationError> {
+    regex_in_array(value, &NAME_REGEX)
+}
+
+super::gen_response_err!(
+    ListUsersError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListUsersRequest {
+    #[validate(custom(function = "validate_attributes_to_get_regex"))]
+    pub attributes_to_get: Option<Vec<String>>,
+    #[validate(length(max = 256))]
+    pub filter: Option<String>,
+    #[validate(range(min = 0, max = 60))]
+    pub limit: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex = "PAGINATION_TOKEN_REGEX")]
+    pub pagination_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for ListUsersRequest {
+    fn to_action_name() -> &'static str {
+        LIST_USERS_NAME
+    }
+}
+
+impl super::ToResponse for ListUsersRequest {
+    type E = ListUsersError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, LIST_USERS_NAME, list_users)
+    }
+}
+
+/// Lists users matching `Filter` ordered by username, `AttributesToGet` selects
+/// the returned attributes (all attributes when omitted).
+fn list_users(request: &ListUsersRequest) -> Result<serde_json::Value, super::ActionError> {
+    let filter = request
+        .filter
+        .as_deref()
+        .filter(|filter| !filter.trim().is_empty())
+        .map(super::UserFilter::parse)
+        .transpose()?;
+    super::with_store(|store| {
+        let users = store
+            .user_pool(request.user_pool_id.as_ref().unwrap())?
+            .users
+            .values()
+            .filter(|user| filter.as_ref().is_none_or(|filter| filter.matches(user)))
+            .collect::<Vec<_>>();
+        let page = super::paginate(
+            users,
+            |user| user.username.clone(),
+            super::page_size(request.limit),
+            request.pagination_token.as_ref(),
+        )?;
+        let users = page
+            .items
+            .into_iter()
+            .map(|user| {
+                let mut user_type = user.to_user_type();
+                if let Some(names) = request.attributes_to_get.as_ref() {
+                    user_type
+                        .attributes
+                        .retain(|attr| names.contains(&attr.name));
+                }
+                user_type
+            })
+            .collect::<Vec<_>>();
+        Ok(serde_json::json!({
+            "Users": users,
+            "PaginationToken": page.next_token,
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListUsersRequest {
+            filter: Some(r#"email ^= "bob""#.to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListUsersRequest {
+            limit: Some(61),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = ListUsersError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListUsersError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
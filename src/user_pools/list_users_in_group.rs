@@ -0,0 +1,117 @@
+use crate::common::{NAME_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const LIST_USERS_IN_GROUP_NAME: &str = "ListUsersInGroup";
+pub const LIST_USERS_IN_GROUP_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.ListUsersInGroup";
+
+static NEXT_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\S]+").unwrap());
+
+super::gen_response_err!(
+    ListUsersInGroupError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListUsersInGroupRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "NAME_REGEX")]
+    pub group_name: Option<String>,
+    #[validate(range(min = 0, max = 60))]
+    pub limit: Option<u8>,
+    #[validate(length(min = 1))]
+    #[validate(regex = "NEXT_TOKEN_REGEX")]
+    pub next_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for ListUsersInGroupRequest {
+    fn to_action_name() -> &'static str {
+        LIST_USERS_IN_GROUP_NAME
+    }
+}
+
+impl super::ToResponse for ListUsersInGroupRequest {
+    type E = ListUsersInGroupError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, LIST_USERS_IN_GROUP_NAME, list_users_in_group)
+    }
+}
+
+fn list_users_in_group(
+    request: &ListUsersInGroupRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let group_name = request.group_name.as_ref().unwrap();
+    super::with_store(|store| {
+        let pool = store.user_pool(request.user_pool_id.as_ref().unwrap())?;
+        pool.group(group_name)?;
+        let page = super::paginate(
+            pool.users
+                .values()
+                .filter(|user| user.groups.contains(group_name))
+                .collect(),
+            |user| user.username.clone(),
+            super::page_size(request.limit),
+            request.next_token.as_ref(),
+        )?;
+        let users = page
+            .items
+            .iter()
+            .map(|user| user.to_user_type())
+            .collect::<Vec<_>>();
+        Ok(serde_json::json!({
+            "NextToken": page.next_token,
+            "Users": users,
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ListUsersInGroupRequest {
+            group_name: Some("group_name".to_string()),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ListUsersInGroupRequest {
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = ListUsersInGroupError::ResourceNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ListUsersInGroupError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
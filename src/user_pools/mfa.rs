@@ -0,0 +1,447 @@
+use super::{
+    ActionError, AuthParameters, AuthSession, CodeDelivery, CodePurpose, MFAOptionType,
+    MessageType, SMSMfaSettingsType, SoftwareToken, SoftwareTokenMfaSettingsType, Store,
+    TriggerContext, User, UserPool, PHONE_NUMBER_ATTRIBUTE,
+};
+use std::collections::HashMap;
+
+pub const SMS_MFA: &str = "SMS_MFA";
+pub const SOFTWARE_TOKEN_MFA: &str = "SOFTWARE_TOKEN_MFA";
+pub const MFA_SETUP: &str = "MFA_SETUP";
+pub const SELECT_MFA_TYPE: &str = "SELECT_MFA_TYPE";
+const MFA_CONFIGURATION_OFF: &str = "OFF";
+const MFA_CONFIGURATION_ON: &str = "ON";
+
+/// Returns `MfaConfiguration` of the user pool, `OFF` when not configured.
+pub fn mfa_configuration(pool: &UserPool) -> &str {
+    pool.config
+        .mfa_configuration
+        .as_deref()
+        .unwrap_or(MFA_CONFIGURATION_OFF)
+}
+
+/// Enables or disables the MFA type of the user, and sets or unsets it as preferred.
+fn apply_mfa_setting(user: &mut User, name: &str, enabled: Option<bool>, preferred: Option<bool>) {
+    match enabled {
+        Some(true) if !user.mfa_settings.iter().any(|s| s == name) => {
+            user.mfa_settings.push(name.to_string())
+        }
+        Some(false) => user.mfa_settings.retain(|s| s != name),
+        _ => {}
+    }
+    let is_enabled = user.mfa_settings.iter().any(|s| s == name);
+    let is_preferred = user.preferred_mfa.as_deref() == Some(name);
+    if preferred == Some(true) && is_enabled {
+        user.preferred_mfa = Some(name.to_string());
+    } else if is_preferred && (preferred == Some(false) || !is_enabled) {
+        user.preferred_mfa = None;
+    }
+}
+
+/// Sets MFA preference of the user, shared by SetUserMFAPreference and AdminSetUserMFAPreference.
+pub fn set_mfa_preference(
+    user: &mut User,
+    sms: Option<&SMSMfaSettingsType>,
+    software_token: Option<&SoftwareTokenMfaSettingsType>,
+) -> Result<(), ActionError> {
+    if software_token.and_then(|s| s.enabled) == Some(true)
+        && !user.software_token.as_ref().is_some_and(|t| t.verified)
+    {
+        return Err(ActionError::invalid_parameter(
+            "User has not verified software token mfa",
+        ));
+    }
+    if sms.and_then(|s| s.enabled) == Some(true) && user.attribute(PHONE_NUMBER_ATTRIBUTE).is_none()
+    {
+        return Err(ActionError::invalid_parameter(
+            "User does not have delivery config set to turn on SMS_MFA",
+        ));
+    }
+    if let Some(settings) = sms {
+        apply_mfa_setting(user, SMS_MFA, settings.enabled, settings.preferred_mfa);
+    }
+    if let Some(settings) = software_token {
+        apply_mfa_setting(
+            user,
+            SOFTWARE_TOKEN_MFA,
+            settings.enabled,
+            settings.preferred_mfa,
+        );
+    }
+    user.touch();
+    Ok(())
+}
+
+/// Returns the MFA challenge of the user whose password is verified,
+/// or `None` when the user can sign in without MFA.
+pub fn mfa_challenge(
+    pool: &UserPool,
+    client_id: &str,
+    user: &User,
+) -> Option<(AuthSession, HashMap<String, String>)> {
+    let configuration = mfa_configuration(pool);
+    if configuration == MFA_CONFIGURATION_OFF {
+        return None;
+    }
+    let enabled = |name: &str| user.mfa_settings.iter().any(|s| s == name);
+    let challenge_name = match user.preferred_mfa.as_deref() {
+        Some(preferred) if enabled(preferred) => preferred,
+        _ if enabled(SMS_MFA) && enabled(SOFTWARE_TOKEN_MFA) => SELECT_MFA_TYPE,
+        _ if enabled(SOFTWARE_TOKEN_MFA) => SOFTWARE_TOKEN_MFA,
+        _ if enabled(SMS_MFA) => SMS_MFA,
+        _ if configuration != MFA_CONFIGURATION_ON => return None,
+        // MFA is required, users with a phone number receive codes by SMS
+        _ if user.attribute(PHONE_NUMBER_ATTRIBUTE).is_some() => SMS_MFA,
+        _ => MFA_SETUP,
+    };
+    Some(challenge(pool, client_id, user, challenge_name))
+}
+
+/// Returns the MFA challenge chosen by `ANSWER` to `SELECT_MFA_TYPE` challenge.
+pub fn select_mfa_type(
+    pool: &UserPool,
+    client_id: &str,
+    user: &User,
+    responses: &AuthParameters,
+) -> Result<(AuthSession, HashMap<String, String>), ActionError> {
+    let answer = super::auth_parameter(responses, "ANSWER")?;
+    if ![SMS_MFA, SOFTWARE_TOKEN_MFA].contains(&answer)
+        || !user.mfa_settings.iter().any(|s| s == answer)
+    {
+        return Err(ActionError::invalid_parameter(&format!(
+            "MFA type {} is not enabled for the user.",
+            answer
+        )));
+    }
+    Ok(challenge(pool, client_id, user, answer))
+}
+
+fn challenge(
+    pool: &UserPool,
+    client_id: &str,
+    user: &User,
+    challenge_name: &str,
+) -> (AuthSession, HashMap<String, String>) {
+    let mut challenge_parameters = HashMap::new();
+    challenge_parameters.insert("USER_ID_FOR_SRP".to_string(), user.username.clone());
+    match challenge_name {
+        SOFTWARE_TOKEN_MFA => {
+            let device_name = user
+                .software_token
+                .as_ref()
+                .and_then(|t| t.friendly_device_name.clone());
+            if let Some(device_name) = device_name {
+                challenge_parameters.insert("FRIENDLY_DEVICE_NAME".to_string(), device_name);
+            }
+        }
+        SELECT_MFA_TYPE => {
+            challenge_parameters.insert(
+                "MFAS_CAN_CHOOSE".to_string(),
+                serde_json::to_string(&[SMS_MFA, SOFTWARE_TOKEN_MFA]).unwrap(),
+            );
+        }
+        MFA_SETUP => {
+            challenge_parameters.insert(
+                "MFAS_CAN_SETUP".to_string(),
+                serde_json::to_string(&[SOFTWARE_TOKEN_MFA]).unwrap(),
+            );
+        }
+        _ => {}
+    }
+    let session = AuthSession::new(&pool.id, client_id, &user.username, challenge_name);
+    (session, challenge_parameters)
+}
+
+/// Sends a code of `SMS_MFA` challenge to the phone number of the user,
+/// and adds its masked destination to the challenge parameters.
+pub fn send_sms_mfa_code(
+    user_pool_id: &str,
+    username: &str,
+    challenge_parameters: &mut HashMap<String, String>,
+    context: &TriggerContext,
+) -> Result<(), ActionError> {
+    let delivery = super::with_store(|store| {
+        let user = store.user_pool(user_pool_id)?.user(username)?;
+        CodeDelivery::for_attribute(user, PHONE_NUMBER_ATTRIBUTE).ok_or_else(|| {
+            ActionError::new(
+                "MFAMethodNotFoundException",
+                "The user does not have a phone number to receive SMS MFA codes.",
+            )
+        })
+    })?;
+    let details = super::send_code(
+        user_pool_id,
+        username,
+        MessageType::Authentication,
+        &delivery,
+        context,
+    )?;
+    challenge_parameters.insert(
+        "CODE_DELIVERY_DELIVERY_MEDIUM".to_string(),
+        details.delivery_medium.to_string(),
+    );
+    challenge_parameters.insert("CODE_DELIVERY_DESTINATION".to_string(), details.destination);
+    Ok(())
+}
+
+/// Verifies the response to the MFA challenge of the user.
+pub fn verify_mfa_response(
+    user: &mut User,
+    challenge_name: &str,
+    responses: &AuthParameters,
+) -> Result<(), ActionError> {
+    match challenge_name {
+        SMS_MFA => {
+            let code = super::auth_parameter(responses, "SMS_MFA_CODE")?;
+            user.verify_code(&CodePurpose::SmsMfa, code).map(|_| ())
+        }
+        SOFTWARE_TOKEN_MFA => {
+            let code = super::auth_parameter(responses, "SOFTWARE_TOKEN_MFA_CODE")?;
+            let token = user
+                .software_token
+                .as_ref()
+                .filter(|t| t.verified)
+                .ok_or_else(software_token_not_found)?;
+            if super::verify_totp(&token.secret, code) {
+                Ok(())
+            } else {
+                Err(ActionError::new(
+                    "CodeMismatchException",
+                    "Invalid code received for user",
+                ))
+            }
+        }
+        MFA_SETUP if user.mfa_settings.iter().any(|s| s == SOFTWARE_TOKEN_MFA) => Ok(()),
+        MFA_SETUP => Err(ActionError::new(
+            "MFAMethodNotFoundException",
+            "No MFA method has been set up for the user.",
+        )),
+        _ => Err(ActionError::invalid_parameter(&format!(
+            "Unsupported challenge {}",
+            challenge_name
+        ))),
+    }
+}
+
+/// Sets legacy `MFAOptions` of the user, only SMS to `phone_number` can be set.
+pub fn set_mfa_options(user: &mut User, options: &[MFAOptionType]) -> Result<(), ActionError> {
+    if options.iter().any(|o| {
+        o.delivery_medium.as_deref() != Some("SMS")
+            || o.attribute_name.as_deref() != Some(PHONE_NUMBER_ATTRIBUTE)
+    }) {
+        return Err(ActionError::invalid_parameter(
+            "Only phone_number attribute with SMS delivery medium is supported for MFAOptions",
+        ));
+    }
+    let settings = SMSMfaSettingsType {
+        enabled: Some(!options.is_empty()),
+        preferred_mfa: None,
+    };
+    set_mfa_preference(user, Some(&settings), None)
+}
+
+/// Returns legacy `MFAOptions` of the user.
+pub fn mfa_options(user: &User) -> Vec<MFAOptionType> {
+    user.mfa_settings
+        .iter()
+        .filter(|s| *s == SMS_MFA)
+        .map(|_| MFAOptionType {
+            attribute_name: Some(PHONE_NUMBER_ATTRIBUTE.to_string()),
+            delivery_medium: Some("SMS".to_string()),
+        })
+        .collect()
+}
+
+fn software_token_not_found() -> ActionError {
+    ActionError::new(
+        "SoftwareTokenMFANotFoundException",
+        "Software Token MFA has not been enabled by the user.",
+    )
+}
+
+/// Associates a new software token with the user and returns its secret,
+/// TOTP MFA of the user is disabled until the new token is verified.
+pub fn associate_software_token(user: &mut User) -> String {
+    let secret = super::generate_totp_secret();
+    user.software_token = Some(SoftwareToken {
+        secret: secret.clone(),
+        verified: false,
+        friendly_device_name: None,
+    });
+    apply_mfa_setting(user, SOFTWARE_TOKEN_MFA, Some(false), None);
+    user.touch();
+    secret
+}
+
+/// Verifies the code of the software token associated with the user.
+pub fn verify_software_token(
+    user: &mut User,
+    code: &str,
+    friendly_device_name: Option<&String>,
+) -> Result<(), ActionError> {
+    let token = user.software_token.as_mut().ok_or_else(|| {
+        ActionError::new(
+            "SoftwareTokenMFANotFoundException",
+            "Software Token MFA has not been associated with the user.",
+        )
+    })?;
+    if !super::verify_totp(&token.secret, code) {
+        return Err(ActionError::new(
+            "EnableSoftwareTokenMFAException",
+            "Code mismatch and fail enable Software Token MFA",
+        ));
+    }
+    token.verified = true;
+    token.friendly_device_name = friendly_device_name.cloned();
+    user.touch();
+    Ok(())
+}
+
+/// Enables the verified software token of the user as the preferred MFA.
+pub fn enable_software_token_mfa(user: &mut User) {
+    apply_mfa_setting(user, SOFTWARE_TOKEN_MFA, Some(true), Some(true));
+}
+
+/// Removes and returns the `MFA_SETUP` session.
+pub fn take_mfa_setup_session(
+    store: &mut Store,
+    session: &str,
+) -> Result<AuthSession, ActionError> {
+    let session = store.take_session(session)?;
+    if session.challenge_name != MFA_SETUP {
+        return Err(ActionError::new(
+            "NotAuthorizedException",
+            "Invalid session for the user.",
+        ));
+    }
+    Ok(session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::CreateUserPoolRequest;
+    use pretty_assertions::assert_eq;
+
+    fn user_pool(mfa_configuration: &str) -> UserPool {
+        UserPool::new(
+            "user_pool_id",
+            CreateUserPoolRequest {
+                mfa_configuration: Some(mfa_configuration.to_string()),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn user_with_software_token() -> User {
+        let mut user = User::new("username");
+        let secret = associate_software_token(&mut user);
+        let code = super::super::totp_code(&secret, chrono::Utc::now().timestamp()).unwrap();
+        verify_software_token(&mut user, &code, None).unwrap();
+        user
+    }
+
+    #[test]
+    fn software_token_must_be_verified_to_enable() {
+        let mut user = User::new("username");
+        let enabled = SoftwareTokenMfaSettingsType {
+            enabled: Some(true),
+            preferred_mfa: Some(true),
+        };
+        assert!(set_mfa_preference(&mut user, None, Some(&enabled)).is_err());
+        associate_software_token(&mut user);
+        assert!(verify_software_token(&mut user, "000000x", None).is_err());
+
+        let mut user = user_with_software_token();
+        set_mfa_preference(&mut user, None, Some(&enabled)).unwrap();
+        assert_eq!(vec![SOFTWARE_TOKEN_MFA.to_string()], user.mfa_settings);
+        assert_eq!(Some(SOFTWARE_TOKEN_MFA), user.preferred_mfa.as_deref());
+
+        let disabled = SoftwareTokenMfaSettingsType {
+            enabled: Some(false),
+            preferred_mfa: None,
+        };
+        set_mfa_preference(&mut user, None, Some(&disabled)).unwrap();
+        assert!(user.mfa_settings.is_empty());
+        assert_eq!(None, user.preferred_mfa);
+    }
+
+    #[test]
+    fn sms_mfa_needs_phone_number() {
+        let mut user = User::new("username");
+        let enabled = SMSMfaSettingsType {
+            enabled: Some(true),
+            preferred_mfa: None,
+        };
+        assert!(set_mfa_preference(&mut user, Some(&enabled), None).is_err());
+    }
+
+    #[test]
+    fn mfa_challenge_by_configuration() {
+        let mut user = User::new("username");
+        assert!(mfa_challenge(&user_pool("OFF"), "client_id", &user).is_none());
+        assert!(mfa_challenge(&user_pool("OPTIONAL"), "client_id", &user).is_none());
+        let (session, parameters) = mfa_challenge(&user_pool("ON"), "client_id", &user).unwrap();
+        assert_eq!(MFA_SETUP, session.challenge_name);
+        assert_eq!(r#"["SOFTWARE_TOKEN_MFA"]"#, parameters["MFAS_CAN_SETUP"]);
+
+        user = user_with_software_token();
+        enable_software_token_mfa(&mut user);
+        let (session, _) = mfa_challenge(&user_pool("OPTIONAL"), "client_id", &user).unwrap();
+        assert_eq!(SOFTWARE_TOKEN_MFA, session.challenge_name);
+        assert!(mfa_challenge(&user_pool("OFF"), "client_id", &user).is_none());
+    }
+
+    #[test]
+    fn sms_mfa_challenges() {
+        let mut user = User::new("username");
+        user.set_attributes(vec![(
+            PHONE_NUMBER_ATTRIBUTE.to_string(),
+            "+12345556789".to_string(),
+        )])
+        .unwrap();
+        let (session, _) = mfa_challenge(&user_pool("ON"), "client_id", &user).unwrap();
+        assert_eq!(SMS_MFA, session.challenge_name);
+
+        let sms_option = MFAOptionType {
+            attribute_name: Some(PHONE_NUMBER_ATTRIBUTE.to_string()),
+            delivery_medium: Some("SMS".to_string()),
+        };
+        set_mfa_options(&mut user, &[sms_option]).unwrap();
+        assert_eq!(1, mfa_options(&user).len());
+        let (session, _) = mfa_challenge(&user_pool("OPTIONAL"), "client_id", &user).unwrap();
+        assert_eq!(SMS_MFA, session.challenge_name);
+        set_mfa_options(&mut user, &[]).unwrap();
+        assert!(mfa_options(&user).is_empty());
+    }
+
+    #[test]
+    fn select_mfa_type_without_preference() {
+        let mut user = user_with_software_token();
+        user.set_attributes(vec![(
+            PHONE_NUMBER_ATTRIBUTE.to_string(),
+            "+12345556789".to_string(),
+        )])
+        .unwrap();
+        apply_mfa_setting(&mut user, SMS_MFA, Some(true), None);
+        apply_mfa_setting(&mut user, SOFTWARE_TOKEN_MFA, Some(true), None);
+        let pool = user_pool("OPTIONAL");
+        let (session, parameters) = mfa_challenge(&pool, "client_id", &user).unwrap();
+        assert_eq!(SELECT_MFA_TYPE, session.challenge_name);
+        assert_eq!(
+            r#"["SMS_MFA","SOFTWARE_TOKEN_MFA"]"#,
+            parameters["MFAS_CAN_CHOOSE"]
+        );
+
+        let mut responses = AuthParameters::new();
+        responses.insert("ANSWER".to_string(), SOFTWARE_TOKEN_MFA.to_string());
+        let (session, _) = select_mfa_type(&pool, "client_id", &user, &responses).unwrap();
+        assert_eq!(SOFTWARE_TOKEN_MFA, session.challenge_name);
+        responses.insert("ANSWER".to_string(), MFA_SETUP.to_string());
+        assert!(select_mfa_type(&pool, "client_id", &user, &responses).is_err());
+
+        apply_mfa_setting(&mut user, SMS_MFA, None, Some(true));
+        let (session, _) = mfa_challenge(&pool, "client_id", &user).unwrap();
+        assert_eq!(SMS_MFA, session.challenge_name);
+    }
+}
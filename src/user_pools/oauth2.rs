@@ -0,0 +1,629 @@
+use super::{
+    ActionError, AuthenticationResultType, AuthorizationCode, ClientMetadata, Store,
+    UserPoolClient, UserStatus,
+};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+const OPENID_SCOPE: &str = "openid";
+const ADMIN_SCOPE: &str = "aws.cognito.signin.user.admin";
+const USER_SCOPES: [&str; 5] = ["openid", "email", "phone", "profile", ADMIN_SCOPE];
+
+/// Parameters of `/oauth2/authorize`, carried to `/login` and its form.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct AuthorizeRequest {
+    pub client_id: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+    pub nonce: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub response_type: Option<String>,
+    pub scope: Option<String>,
+    pub state: Option<String>,
+}
+
+/// Form of `/login`, the credentials with the authorization request.
+#[derive(Deserialize, Debug, Default, Clone)]
+pub struct LoginForm {
+    pub username: String,
+    pub password: String,
+    #[serde(flatten)]
+    pub request: AuthorizeRequest,
+}
+
+/// Parameters of `/oauth2/token`.
+#[derive(Deserialize, Debug, Default, Clone)]
+pub struct TokenRequest {
+    pub grant_type: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+    pub code: Option<String>,
+    pub code_verifier: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+}
+
+/// Parameters of `/oauth2/revoke`.
+#[derive(Deserialize, Debug, Default, Clone)]
+pub struct RevokeRequest {
+    pub token: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
+
+/// Parameters of `/logout`, either `logout_uri` or the parameters of the next sign-in.
+#[derive(Deserialize, Debug, Default, Clone)]
+pub struct LogoutRequest {
+    pub logout_uri: Option<String>,
+    #[serde(flatten)]
+    pub request: AuthorizeRequest,
+}
+
+/// Error of the OAuth 2.0 endpoints, `error` is an error code of RFC 6749.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuthError {
+    pub error: &'static str,
+    pub description: String,
+    /// Location of the client receiving the error, errors without it are shown to the user.
+    pub redirect_uri: Option<String>,
+}
+
+impl OAuthError {
+    pub fn new(error: &'static str, description: &str) -> Self {
+        OAuthError {
+            error,
+            description: description.to_string(),
+            redirect_uri: None,
+        }
+    }
+
+    /// Sends the error to the redirect URI of the client with the state of the request.
+    fn redirected(mut self, redirect_uri: &str, state: Option<&str>) -> Self {
+        self.redirect_uri = Some(with_parameters(
+            redirect_uri,
+            '?',
+            &[
+                ("error", Some(self.error)),
+                ("error_description", Some(&self.description)),
+                ("state", state),
+            ],
+        ));
+        self
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "error": self.error,
+            "error_description": self.description,
+        })
+    }
+}
+
+impl From<ActionError> for OAuthError {
+    fn from(e: ActionError) -> Self {
+        OAuthError::new("invalid_grant", &e.message)
+    }
+}
+
+/// Error of the login form, sign-in errors are shown in the form again.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoginError {
+    OAuth(OAuthError),
+    SignIn(ActionError),
+}
+
+impl From<OAuthError> for LoginError {
+    fn from(e: OAuthError) -> Self {
+        LoginError::OAuth(e)
+    }
+}
+
+impl From<ActionError> for LoginError {
+    fn from(e: ActionError) -> Self {
+        LoginError::SignIn(e)
+    }
+}
+
+/// Appends the parameters to the query or the fragment of the URI, `None` values are omitted.
+fn with_parameters(uri: &str, separator: char, params: &[(&str, Option<&str>)]) -> String {
+    let params = params
+        .iter()
+        .filter_map(|(name, value)| value.map(|v| (*name, v)))
+        .collect::<Vec<_>>();
+    let encoded = serde_urlencoded::to_string(params).unwrap_or_default();
+    if separator == '?' && uri.contains('?') {
+        format!("{}&{}", uri, encoded)
+    } else {
+        format!("{}{}{}", uri, separator, encoded)
+    }
+}
+
+fn contains(values: &Option<Vec<String>>, value: &str) -> bool {
+    values.iter().flatten().any(|v| v == value)
+}
+
+fn invalid_client() -> OAuthError {
+    OAuthError::new("invalid_client", "Client authentication failed.")
+}
+
+/// Returns the client allowed to use OAuth flows.
+fn oauth_client<'a>(store: &'a Store, client_id: &str) -> Result<&'a UserPoolClient, OAuthError> {
+    let client = store
+        .find_client(client_id)
+        .map_err(|e| OAuthError::new("invalid_client", &e.message))?;
+    if client.config.allowed_oauth_flows_user_pool_client != Some(true) {
+        return Err(OAuthError::new(
+            "unauthorized_client",
+            "Client is not enabled for OAuth2.0 flows.",
+        ));
+    }
+    Ok(client)
+}
+
+/// Returns the requested scopes allowed for the client, all allowed scopes without `scope`.
+fn granted_scopes(
+    client: &UserPoolClient,
+    scope: Option<&String>,
+) -> Result<Vec<String>, OAuthError> {
+    let allowed = client
+        .config
+        .allowed_oauth_scopes
+        .clone()
+        .unwrap_or_default();
+    let scopes = match scope {
+        Some(scope) => scope.split_whitespace().map(str::to_string).collect(),
+        None => allowed.clone(),
+    };
+    match scopes.iter().find(|s| !allowed.contains(s)) {
+        Some(s) => Err(OAuthError::new(
+            "invalid_scope",
+            &format!("Scope {} is not allowed for the client.", s),
+        )),
+        None => Ok(scopes),
+    }
+}
+
+/// Validates the authorization request and returns the granted scopes, errors found after
+/// the redirect URI is validated are sent to the client.
+fn validate_authorize_request(
+    store: &Store,
+    request: &AuthorizeRequest,
+) -> Result<Vec<String>, OAuthError> {
+    let client_id = request
+        .client_id
+        .as_ref()
+        .ok_or_else(|| OAuthError::new("invalid_request", "client_id is required."))?;
+    let client = oauth_client(store, client_id)?;
+    let redirect_uri = request
+        .redirect_uri
+        .as_deref()
+        .filter(|uri| contains(&client.config.callback_urls, uri))
+        .ok_or_else(|| {
+            OAuthError::new(
+                "redirect_mismatch",
+                "redirect_uri is not registered for the client.",
+            )
+        })?;
+    let redirect = |e: OAuthError| e.redirected(redirect_uri, request.state.as_deref());
+
+    let flow = match request.response_type.as_deref() {
+        Some("code") => "code",
+        Some("token") => "implicit",
+        _ => {
+            return Err(redirect(OAuthError::new(
+                "unsupported_response_type",
+                "response_type must be code or token.",
+            )))
+        }
+    };
+    if !contains(&client.config.allowed_oauth_flows, flow) {
+        return Err(redirect(OAuthError::new(
+            "unauthorized_client",
+            &format!("Flow {} is not allowed for the client.", flow),
+        )));
+    }
+    if request.code_challenge.is_some()
+        && !matches!(
+            request.code_challenge_method.as_deref(),
+            None | Some("S256") | Some("plain")
+        )
+    {
+        return Err(redirect(OAuthError::new(
+            "invalid_request",
+            "code_challenge_method must be S256 or plain.",
+        )));
+    }
+    granted_scopes(client, request.scope.as_ref()).map_err(redirect)
+}
+
+/// Validates the authorization request of `/oauth2/authorize` and `/login`.
+pub fn check_authorize_request(request: &AuthorizeRequest) -> Result<(), OAuthError> {
+    super::with_store(|store| validate_authorize_request(store, request).map(|_| ()))
+}
+
+/// Returns the sign-in page URL with the parameters of the authorization request.
+pub fn login_url(request: &AuthorizeRequest) -> String {
+    format!(
+        "/login?{}",
+        serde_urlencoded::to_string(request).unwrap_or_default()
+    )
+}
+
+/// Signs in the user of the login form, and returns the redirect URI of the client with
+/// the authorization code, or with the tokens of the implicit grant in the fragment.
+/// Users who would be challenged (new password or MFA) cannot sign in.
+pub fn hosted_ui_sign_in(form: &LoginForm) -> Result<String, LoginError> {
+    let request = &form.request;
+    let client_metadata = ClientMetadata::new();
+    let client_id = request.client_id.clone().unwrap_or_default();
+    let redirect_uri = request.redirect_uri.clone().unwrap_or_default();
+    let (scopes, user_pool_id) = super::with_store(|store| {
+        let scopes = validate_authorize_request(store, request)?;
+        let user_pool_id = store.find_client(&client_id)?.user_pool_id().to_string();
+        Ok::<_, LoginError>((scopes, user_pool_id))
+    })?;
+
+    super::migrate_user(
+        &user_pool_id,
+        &client_id,
+        "UserMigration_Authentication",
+        &form.username,
+        Some(&form.password),
+        &client_metadata,
+    )?;
+    super::pre_authentication(&user_pool_id, &client_id, &form.username, &client_metadata)?;
+    let username = super::with_store(|store| {
+        let pool = store.user_pool(&user_pool_id)?;
+        let user = super::authenticate_password(pool, &form.username, &form.password)?;
+        if user.status == UserStatus::FORCE_CHANGE_PASSWORD
+            || super::mfa_challenge(pool, &client_id, user).is_some()
+        {
+            return Err(ActionError::new(
+                "NotAuthorizedException",
+                "Sign-in with a challenge is not supported by the hosted UI.",
+            ));
+        }
+        Ok(user.username.clone())
+    })?;
+    super::post_authentication(&user_pool_id, &client_id, &username, &client_metadata)?;
+
+    if request.response_type.as_deref() == Some("code") {
+        let mut code = AuthorizationCode::new(&user_pool_id, &client_id, &username, &redirect_uri);
+        code.scopes = scopes;
+        code.code_challenge = request.code_challenge.clone().map(|challenge| {
+            let method = request.code_challenge_method.as_deref();
+            (challenge, method.unwrap_or("plain").to_string())
+        });
+        code.nonce = request.nonce.clone();
+        let code = super::with_store(|store| store.add_authorization_code(code));
+        return Ok(with_parameters(
+            &redirect_uri,
+            '?',
+            &[("code", Some(&code)), ("state", request.state.as_deref())],
+        ));
+    }
+
+    let result = super::issue_hosted_ui_tokens(
+        &user_pool_id,
+        &client_id,
+        &username,
+        &scopes,
+        request.nonce.as_deref(),
+    )?;
+    let expires_in = result.expires_in.to_string();
+    let id_token = has_openid_scope(&scopes.join(" ")).then_some(result.id_token.as_str());
+    Ok(with_parameters(
+        &redirect_uri,
+        '#',
+        &[
+            ("access_token", Some(&result.access_token)),
+            ("id_token", id_token),
+            ("token_type", Some(&result.token_type)),
+            ("expires_in", Some(&expires_in)),
+            ("state", request.state.as_deref()),
+        ],
+    ))
+}
+
+/// Returns the client id and secret of HTTP Basic authorization, or of the parameters.
+fn client_credentials(
+    client_id: Option<&String>,
+    client_secret: Option<&String>,
+    authorization: Option<&str>,
+) -> Result<(String, Option<String>), OAuthError> {
+    if let Some(credentials) = authorization.and_then(|a| a.strip_prefix("Basic ")) {
+        let decoded = STANDARD
+            .decode(credentials.trim())
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid_client)?;
+        let (client_id, client_secret) = decoded.split_once(':').ok_or_else(invalid_client)?;
+        return Ok((client_id.to_string(), Some(client_secret.to_string())));
+    }
+    let client_id =
+        client_id.ok_or_else(|| OAuthError::new("invalid_request", "client_id is required."))?;
+    Ok((client_id.clone(), client_secret.cloned()))
+}
+
+fn has_openid_scope(scope: &str) -> bool {
+    scope.split(' ').any(|s| s == OPENID_SCOPE)
+}
+
+/// Returns the response of the token endpoint, the ID token is returned with `openid` scope.
+fn token_response(result: AuthenticationResultType, scope: &str) -> serde_json::Value {
+    let mut response = serde_json::json!({
+        "access_token": result.access_token,
+        "expires_in": result.expires_in,
+        "token_type": result.token_type,
+    });
+    if has_openid_scope(scope) {
+        response["id_token"] = serde_json::json!(result.id_token);
+    }
+    if let Some(refresh_token) = result.refresh_token {
+        response["refresh_token"] = serde_json::json!(refresh_token);
+    }
+    response
+}
+
+/// Issues tokens for the grant of `/oauth2/token`, the client authenticates with HTTP Basic
+/// authorization or `client_id` and `client_secret` parameters.
+pub fn grant_tokens(
+    request: &TokenRequest,
+    authorization: Option<&str>,
+) -> Result<serde_json::Value, OAuthError> {
+    let (client_id, client_secret) = client_credentials(
+        request.client_id.as_ref(),
+        request.client_secret.as_ref(),
+        authorization,
+    )?;
+    super::with_store(|store| {
+        let client = oauth_client(store, &client_id)?;
+        if client.client_secret.is_some() && client.client_secret != client_secret {
+            return Err(invalid_client());
+        }
+        Ok(())
+    })?;
+    match request.grant_type.as_deref() {
+        Some("authorization_code") => authorization_code_grant(&client_id, request),
+        Some("refresh_token") => refresh_token_grant(&client_id, request),
+        Some("client_credentials") => {
+            super::with_store(|store| client_credentials_grant(store, &client_id, request))
+        }
+        _ => Err(OAuthError::new(
+            "unsupported_grant_type",
+            "grant_type must be authorization_code, refresh_token or client_credentials.",
+        )),
+    }
+}
+
+/// Exchanges the authorization code for tokens, the code is verified with the redirect URI
+/// and the PKCE code verifier.
+fn authorization_code_grant(
+    client_id: &str,
+    request: &TokenRequest,
+) -> Result<serde_json::Value, OAuthError> {
+    let invalid_grant = || OAuthError::new("invalid_grant", "Invalid authorization code.");
+    let code = request
+        .code
+        .as_ref()
+        .ok_or_else(|| OAuthError::new("invalid_request", "code is required."))?;
+    let code = super::with_store(|store| {
+        let code = store
+            .take_authorization_code(code)
+            .filter(|code| code.client_id == client_id)
+            .filter(|code| request.redirect_uri.as_ref() == Some(&code.redirect_uri))
+            .filter(|code| code.verify_code_verifier(request.code_verifier.as_deref()))
+            .ok_or_else(invalid_grant)?;
+        let user = store.user_pool(&code.user_pool_id)?.user(&code.username)?;
+        super::check_user_status(user)?;
+        Ok::<_, OAuthError>(code)
+    })?;
+    let result = super::issue_hosted_ui_tokens(
+        &code.user_pool_id,
+        client_id,
+        &code.username,
+        &code.scopes,
+        code.nonce.as_deref(),
+    )?;
+    Ok(token_response(result, &code.scopes.join(" ")))
+}
+
+/// Issues new ID and access tokens from the refresh token.
+fn refresh_token_grant(
+    client_id: &str,
+    request: &TokenRequest,
+) -> Result<serde_json::Value, OAuthError> {
+    let refresh_token = request
+        .refresh_token
+        .as_ref()
+        .ok_or_else(|| OAuthError::new("invalid_request", "refresh_token is required."))?;
+    let (user_pool_id, claims) = super::with_store(|store| {
+        let client = store.find_client(client_id)?;
+        let pool = store.user_pool(client.user_pool_id())?;
+        let claims = super::verify_refresh_token(pool, client, refresh_token)?;
+        Ok::<_, ActionError>((pool.id.clone(), claims))
+    })?;
+    let result = super::reissue_tokens(&user_pool_id, client_id, &claims, &ClientMetadata::new())?;
+    Ok(token_response(
+        result,
+        claims["scope"].as_str().unwrap_or_default(),
+    ))
+}
+
+/// Issues an access token of the client itself with custom scopes of resource servers.
+fn client_credentials_grant(
+    store: &Store,
+    client_id: &str,
+    request: &TokenRequest,
+) -> Result<serde_json::Value, OAuthError> {
+    let client = store.find_client(client_id)?;
+    if client.client_secret.is_none()
+        || !contains(&client.config.allowed_oauth_flows, "client_credentials")
+    {
+        return Err(OAuthError::new(
+            "unauthorized_client",
+            "Flow client_credentials is not allowed for the client.",
+        ));
+    }
+    let scopes = granted_scopes(client, request.scope.as_ref())?;
+    if let Some(scope) = scopes.iter().find(|s| USER_SCOPES.contains(&s.as_str())) {
+        return Err(OAuthError::new(
+            "invalid_scope",
+            &format!("Scope {} is not allowed for client credentials.", scope),
+        ));
+    }
+    let now = Utc::now().timestamp();
+    let expires_in = client.access_token_validity().num_seconds();
+    let access_token = super::sign(&serde_json::json!({
+        "sub": client_id,
+        "token_use": "access",
+        "scope": scopes.join(" "),
+        "auth_time": now,
+        "iss": super::issuer(client.user_pool_id()),
+        "exp": now + expires_in,
+        "iat": now,
+        "version": 2,
+        "jti": uuid::Uuid::new_v4().to_string(),
+        "client_id": client_id,
+    }));
+    Ok(serde_json::json!({
+        "access_token": access_token,
+        "expires_in": expires_in,
+        "token_type": "Bearer",
+    }))
+}
+
+/// Returns the attributes of the user of the access token for `/oauth2/userInfo`,
+/// the token must have `openid` or `aws.cognito.signin.user.admin` scope.
+pub fn user_info(authorization: Option<&str>) -> Result<serde_json::Value, OAuthError> {
+    let access_token = authorization
+        .and_then(|a| a.strip_prefix("Bearer "))
+        .ok_or_else(|| OAuthError::new("invalid_request", "Access token is required."))?;
+    let invalid_token = |message: &str| OAuthError::new("invalid_token", message);
+    super::with_store(|store| {
+        let (user_pool_id, username, claims) =
+            super::verify_access_token_claims(store, access_token)
+                .map_err(|e| invalid_token(&e.message))?;
+        let scope = claims["scope"].as_str().unwrap_or_default();
+        if !scope
+            .split(' ')
+            .any(|s| s == OPENID_SCOPE || s == ADMIN_SCOPE)
+        {
+            return Err(invalid_token("Access token does not contain openid scope."));
+        }
+        let user = store.user_pool(&user_pool_id)?.user(&username)?;
+        let mut info = user
+            .attributes
+            .iter()
+            .map(|(name, value)| (name.clone(), serde_json::json!(value)))
+            .collect::<serde_json::Map<_, _>>();
+        info.insert("sub".to_string(), serde_json::json!(user.sub()));
+        info.insert("username".to_string(), serde_json::json!(user.username));
+        Ok(serde_json::Value::Object(info))
+    })
+}
+
+/// Revokes the refresh token for `/oauth2/revoke`, same as RevokeToken.
+pub fn revoke_oauth_token(
+    request: &RevokeRequest,
+    authorization: Option<&str>,
+) -> Result<(), OAuthError> {
+    let (client_id, client_secret) = client_credentials(
+        request.client_id.as_ref(),
+        request.client_secret.as_ref(),
+        authorization,
+    )?;
+    let token = request
+        .token
+        .as_ref()
+        .ok_or_else(|| OAuthError::new("invalid_request", "token is required."))?;
+    super::with_store(|store| {
+        super::revoke_token(store, token, &client_id, client_secret.as_ref()).map_err(|e| {
+            match e.error.as_str() {
+                "UnsupportedTokenTypeException" => {
+                    OAuthError::new("unsupported_token_type", &e.message)
+                }
+                "UnsupportedOperationException" => OAuthError::new("invalid_request", &e.message),
+                _ => OAuthError::new("invalid_client", &e.message),
+            }
+        })
+    })
+}
+
+/// Returns the location redirected to by `/logout`, the logout URI of the client or the
+/// sign-in page. The hosted UI keeps no sign-in session, so there is nothing to clear.
+pub fn logout_location(request: &LogoutRequest) -> Result<String, OAuthError> {
+    let client_id = request
+        .request
+        .client_id
+        .as_ref()
+        .ok_or_else(|| OAuthError::new("invalid_request", "client_id is required."))?;
+    super::with_store(|store| {
+        let client = oauth_client(store, client_id)?;
+        match request.logout_uri.as_ref() {
+            Some(uri) if contains(&client.config.logout_urls, uri) => Ok(uri.clone()),
+            Some(_) => Err(OAuthError::new(
+                "redirect_mismatch",
+                "logout_uri is not registered for the client.",
+            )),
+            None => {
+                validate_authorize_request(store, &request.request)?;
+                Ok(login_url(&request.request))
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parameters_are_appended_to_uri() {
+        assert_eq!(
+            "https://example.com/cb?code=a+b&state=x%26y",
+            with_parameters(
+                "https://example.com/cb",
+                '?',
+                &[("code", Some("a b")), ("state", Some("x&y"))]
+            )
+        );
+        assert_eq!(
+            "https://example.com/cb?a=1&code=c",
+            with_parameters(
+                "https://example.com/cb?a=1",
+                '?',
+                &[("code", Some("c")), ("state", None)]
+            )
+        );
+        assert_eq!(
+            "https://example.com/cb#access_token=t",
+            with_parameters(
+                "https://example.com/cb",
+                '#',
+                &[("access_token", Some("t"))]
+            )
+        );
+    }
+
+    #[test]
+    fn client_credentials_of_basic_authorization() {
+        let authorization = format!("Basic {}", STANDARD.encode("client_id:secret"));
+        assert_eq!(
+            ("client_id".to_string(), Some("secret".to_string())),
+            client_credentials(None, None, Some(&authorization)).unwrap()
+        );
+
+        let client_id = "client_id".to_string();
+        assert_eq!(
+            ("client_id".to_string(), None),
+            client_credentials(Some(&client_id), None, None).unwrap()
+        );
+        assert!(client_credentials(None, None, Some("Basic invalid")).is_err());
+        assert!(client_credentials(None, None, None).is_err());
+    }
+}
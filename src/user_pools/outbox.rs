@@ -0,0 +1,442 @@
+use super::{
+    ActionError, CodeDelivery, CreateUserPoolRequest, DeliveryMedium, Store, Trigger,
+    TriggerContext, TriggerEvent, UserPool,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::Display;
+
+const CODE_PLACEHOLDER: &str = "{####}";
+const USERNAME_PLACEHOLDER: &str = "{username}";
+const DEFAULT_VERIFICATION_SUBJECT: &str = "Your verification code";
+const DEFAULT_VERIFICATION_MESSAGE: &str = "Your verification code is {####}. ";
+const DEFAULT_INVITATION_SUBJECT: &str = "Your temporary password";
+const DEFAULT_INVITATION_MESSAGE: &str =
+    "Your username is {username} and temporary password is {####}. ";
+const DEFAULT_AUTHENTICATION_MESSAGE: &str = "Your authentication code is {####}. ";
+const DEFAULT_WELCOME_SUBJECT: &str = "Your account";
+const DEFAULT_WELCOME_MESSAGE: &str = "Your username is {username}. ";
+
+/// What a message is sent for, same as the trigger sources of CustomMessage
+/// except `Welcome` sent to users created by UserMigration trigger.
+#[derive(Display, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageType {
+    SignUp,
+    AdminCreateUser,
+    ResendCode,
+    ForgotPassword,
+    UpdateUserAttribute,
+    VerifyUserAttribute,
+    Authentication,
+    Welcome,
+}
+
+/// Email or SMS message captured instead of being delivered.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Message {
+    pub message_id: String,
+    pub user_pool_id: String,
+    pub username: String,
+    pub message_type: MessageType,
+    pub delivery_medium: DeliveryMedium,
+    pub destination: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    pub body: String,
+    /// Code or temporary password replacing `{####}` of the message.
+    pub code: String,
+    pub sent_date: i64,
+}
+
+/// Conditions to find messages in the outbox, all given conditions must match.
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct OutboxFilter {
+    pub user_pool_id: Option<String>,
+    pub username: Option<String>,
+    pub destination: Option<String>,
+}
+
+impl OutboxFilter {
+    fn matches(&self, message: &Message) -> bool {
+        let matches = |condition: &Option<String>, value: &str| {
+            condition.as_deref().is_none_or(|c| c == value)
+        };
+        matches(&self.user_pool_id, &message.user_pool_id)
+            && matches(&self.username, &message.username)
+            && matches(&self.destination, &message.destination)
+    }
+}
+
+/// Messages sent to users, in sending order.
+#[derive(Debug, Default)]
+pub struct Outbox {
+    messages: Vec<Message>,
+}
+
+impl Outbox {
+    pub fn push(&mut self, message: Message) {
+        log::info!(
+            "{} message sent to {} by {} (user pool: {}, username: {}): {}",
+            message.message_type,
+            message.destination,
+            message.delivery_medium,
+            message.user_pool_id,
+            message.username,
+            message.body
+        );
+        self.messages.push(message);
+    }
+
+    pub fn find(&self, filter: &OutboxFilter) -> Vec<&Message> {
+        self.messages.iter().filter(|m| filter.matches(m)).collect()
+    }
+
+    /// Removes the matched messages and returns the number of them.
+    pub fn remove(&mut self, filter: &OutboxFilter) -> usize {
+        let len = self.messages.len();
+        self.messages.retain(|m| !filter.matches(m));
+        len - self.messages.len()
+    }
+}
+
+/// Returns subject (only for email) and body templates of the message by the user pool settings.
+fn message_templates(
+    config: &CreateUserPoolRequest,
+    message_type: MessageType,
+    delivery_medium: DeliveryMedium,
+) -> (Option<String>, String) {
+    let verification = config.verification_message_template.as_ref();
+    let invitation = config
+        .admin_create_user_config
+        .as_ref()
+        .and_then(|c| c.invite_message_template.as_ref());
+    let or_default = |value: Option<&String>, default: &str| {
+        value.cloned().unwrap_or_else(|| default.to_string())
+    };
+    match (message_type, delivery_medium) {
+        (MessageType::AdminCreateUser, DeliveryMedium::EMAIL) => (
+            Some(or_default(
+                invitation.and_then(|t| t.email_subject.as_ref()),
+                DEFAULT_INVITATION_SUBJECT,
+            )),
+            or_default(
+                invitation.and_then(|t| t.email_message.as_ref()),
+                DEFAULT_INVITATION_MESSAGE,
+            ),
+        ),
+        (MessageType::AdminCreateUser, DeliveryMedium::SMS) => (
+            None,
+            or_default(
+                invitation.and_then(|t| t.sms_message.as_ref()),
+                DEFAULT_INVITATION_MESSAGE,
+            ),
+        ),
+        (MessageType::Welcome, DeliveryMedium::EMAIL) => (
+            Some(DEFAULT_WELCOME_SUBJECT.to_string()),
+            DEFAULT_WELCOME_MESSAGE.to_string(),
+        ),
+        (MessageType::Welcome, DeliveryMedium::SMS) => (None, DEFAULT_WELCOME_MESSAGE.to_string()),
+        (MessageType::Authentication, _) => (
+            None,
+            or_default(
+                config.sms_authentication_message.as_ref(),
+                DEFAULT_AUTHENTICATION_MESSAGE,
+            ),
+        ),
+        (_, DeliveryMedium::EMAIL) => (
+            Some(or_default(
+                verification
+                    .and_then(|t| t.email_subject.as_ref())
+                    .or(config.email_verification_subject.as_ref()),
+                DEFAULT_VERIFICATION_SUBJECT,
+            )),
+            or_default(
+                verification
+                    .and_then(|t| t.email_message.as_ref())
+                    .or(config.email_verification_message.as_ref()),
+                DEFAULT_VERIFICATION_MESSAGE,
+            ),
+        ),
+        (_, DeliveryMedium::SMS) => (
+            None,
+            or_default(
+                verification
+                    .and_then(|t| t.sms_message.as_ref())
+                    .or(config.sms_verification_message.as_ref()),
+                DEFAULT_VERIFICATION_MESSAGE,
+            ),
+        ),
+    }
+}
+
+/// Returns the event of CustomMessage trigger for the message to the user,
+/// or `None` when the trigger is not configured.
+fn custom_message_event(
+    pool: &UserPool,
+    username: &str,
+    message_type: MessageType,
+    context: &TriggerContext,
+) -> Result<Option<TriggerEvent>, ActionError> {
+    // CustomMessage has no trigger source for the welcome message.
+    if message_type == MessageType::Welcome || !super::has_trigger(pool, Trigger::CustomMessage) {
+        return Ok(None);
+    }
+    let user = pool.user(username)?;
+    let username_parameter = match message_type {
+        MessageType::AdminCreateUser => Some(USERNAME_PLACEHOLDER),
+        _ => None,
+    };
+    Ok(super::trigger_event(
+        pool,
+        Trigger::CustomMessage,
+        &format!("CustomMessage_{}", message_type),
+        context.client_id.as_deref(),
+        &user.username,
+        serde_json::json!({
+            "userAttributes": super::event_user_attributes(user),
+            "codeParameter": CODE_PLACEHOLDER,
+            "usernameParameter": username_parameter,
+            "clientMetadata": context.client_metadata,
+        }),
+        serde_json::json!({
+            "smsMessage": null,
+            "emailMessage": null,
+            "emailSubject": null,
+        }),
+    ))
+}
+
+/// Replaces the templates with `emailSubject`, `emailMessage` or `smsMessage` returned by
+/// CustomMessage trigger, templates not returned are left as is.
+fn custom_message_templates(
+    response: Option<&Value>,
+    delivery_medium: DeliveryMedium,
+    (subject, body): (Option<String>, String),
+) -> (Option<String>, String) {
+    let response = match response {
+        Some(response) => response,
+        None => return (subject, body),
+    };
+    let template = |name: &str| response[name].as_str().map(str::to_string);
+    match delivery_medium {
+        DeliveryMedium::EMAIL => (
+            template("emailSubject").or(subject),
+            template("emailMessage").unwrap_or(body),
+        ),
+        DeliveryMedium::SMS => (subject, template("smsMessage").unwrap_or(body)),
+    }
+}
+
+/// Renders the message by the user pool settings and CustomMessage trigger,
+/// and puts it into the outbox.
+pub fn send_message(
+    user_pool_id: &str,
+    username: &str,
+    message_type: MessageType,
+    delivery: &CodeDelivery,
+    code: &str,
+    context: &TriggerContext,
+) -> Result<(), ActionError> {
+    let event = super::with_store(|store| {
+        custom_message_event(
+            store.user_pool(user_pool_id)?,
+            username,
+            message_type,
+            context,
+        )
+    })?;
+    let response = super::invoke_trigger(event)?;
+    super::with_store(|store| {
+        push_message(
+            store,
+            user_pool_id,
+            username,
+            message_type,
+            delivery,
+            code,
+            response.as_ref(),
+        )
+    })
+}
+
+/// Renders the message with the response of CustomMessage trigger and puts it into the outbox.
+fn push_message(
+    store: &mut Store,
+    user_pool_id: &str,
+    username: &str,
+    message_type: MessageType,
+    delivery: &CodeDelivery,
+    code: &str,
+    custom_message: Option<&Value>,
+) -> Result<(), ActionError> {
+    let pool = store.user_pool(user_pool_id)?;
+    let (subject, body) = custom_message_templates(
+        custom_message,
+        delivery.delivery_medium,
+        message_templates(&pool.config, message_type, delivery.delivery_medium),
+    );
+    let body = body
+        .replace(CODE_PLACEHOLDER, code)
+        .replace(USERNAME_PLACEHOLDER, username);
+    store.outbox.push(Message {
+        message_id: uuid::Uuid::new_v4().to_string(),
+        user_pool_id: user_pool_id.to_string(),
+        username: username.to_string(),
+        message_type,
+        delivery_medium: delivery.delivery_medium,
+        destination: delivery.destination.clone(),
+        subject,
+        body,
+        code: code.to_string(),
+        sent_date: Utc::now().timestamp(),
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::{AdminCreateUserConfigType, MessageTemplateType};
+    use pretty_assertions::assert_eq;
+
+    fn delivery(delivery_medium: DeliveryMedium) -> CodeDelivery {
+        CodeDelivery {
+            attribute_name: "email".to_string(),
+            delivery_medium,
+            destination: "user@example.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn render_default_messages() {
+        let mut store = Store::default();
+        let user_pool_id = store.add_user_pool(Default::default()).id.clone();
+        let email = delivery(DeliveryMedium::EMAIL);
+        push_message(
+            &mut store,
+            &user_pool_id,
+            "username",
+            MessageType::SignUp,
+            &email,
+            "123456",
+            None,
+        )
+        .unwrap();
+        push_message(
+            &mut store,
+            &user_pool_id,
+            "username",
+            MessageType::AdminCreateUser,
+            &email,
+            "Passw0rd!",
+            None,
+        )
+        .unwrap();
+
+        let messages = store.outbox.find(&Default::default());
+        assert_eq!(
+            Some("Your verification code"),
+            messages[0].subject.as_deref()
+        );
+        assert_eq!("Your verification code is 123456. ", messages[0].body);
+        assert_eq!(
+            "Your username is username and temporary password is Passw0rd!. ",
+            messages[1].body
+        );
+    }
+
+    #[test]
+    fn render_welcome_message_without_code() {
+        let mut store = Store::default();
+        let user_pool_id = store.add_user_pool(Default::default()).id.clone();
+        push_message(
+            &mut store,
+            &user_pool_id,
+            "username",
+            MessageType::Welcome,
+            &delivery(DeliveryMedium::EMAIL),
+            "",
+            None,
+        )
+        .unwrap();
+
+        let messages = store.outbox.find(&Default::default());
+        assert_eq!(Some("Your account"), messages[0].subject.as_deref());
+        assert_eq!("Your username is username. ", messages[0].body);
+    }
+
+    #[test]
+    fn render_configured_messages() {
+        let mut store = Store::default();
+        let user_pool_id = store
+            .add_user_pool(CreateUserPoolRequest {
+                sms_verification_message: Some("code: {####}".to_string()),
+                admin_create_user_config: Some(AdminCreateUserConfigType {
+                    invite_message_template: Some(MessageTemplateType {
+                        sms_message: Some("{username}/{####}".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .id
+            .clone();
+        let sms = delivery(DeliveryMedium::SMS);
+        push_message(
+            &mut store,
+            &user_pool_id,
+            "username",
+            MessageType::ForgotPassword,
+            &sms,
+            "123456",
+            None,
+        )
+        .unwrap();
+        push_message(
+            &mut store,
+            &user_pool_id,
+            "username",
+            MessageType::AdminCreateUser,
+            &sms,
+            "Passw0rd!",
+            None,
+        )
+        .unwrap();
+
+        let messages = store.outbox.find(&Default::default());
+        assert_eq!(None, messages[0].subject);
+        assert_eq!("code: 123456", messages[0].body);
+        assert_eq!("username/Passw0rd!", messages[1].body);
+    }
+
+    #[test]
+    fn find_and_remove_messages() {
+        let mut store = Store::default();
+        let user_pool_id = store.add_user_pool(Default::default()).id.clone();
+        for username in ["user1", "user2"] {
+            push_message(
+                &mut store,
+                &user_pool_id,
+                username,
+                MessageType::SignUp,
+                &delivery(DeliveryMedium::EMAIL),
+                "123456",
+                None,
+            )
+            .unwrap();
+        }
+
+        let filter = OutboxFilter {
+            username: Some("user1".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(1, store.outbox.find(&filter).len());
+        assert_eq!(1, store.outbox.remove(&filter));
+        assert_eq!(0, store.outbox.find(&filter).len());
+        assert_eq!(1, store.outbox.find(&Default::default()).len());
+    }
+}
@@ -0,0 +1,120 @@
+use super::ActionError;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use rand::Rng;
+use sha2::Sha256;
+
+/// Maximum and default number of items of a page.
+pub const MAX_PAGE_SIZE: usize = 60;
+
+/// Key signing pagination tokens, tokens are valid until the server restarts.
+static PAGINATION_KEY: Lazy<[u8; 32]> = Lazy::new(|| rand::thread_rng().gen());
+
+/// Items of a page and the token of the next page (`None` on the last page).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_token: Option<String>,
+}
+
+fn mac(key: &str) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(&*PAGINATION_KEY).unwrap();
+    mac.update(key.as_bytes());
+    mac
+}
+
+/// Encodes the sort key of the last item of a page into an opaque token.
+fn encode_token(key: &str) -> String {
+    format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(key),
+        URL_SAFE_NO_PAD.encode(mac(key).finalize().into_bytes())
+    )
+}
+
+/// Decodes the sort key of the token, tampered tokens are rejected.
+fn decode_token(token: &str) -> Result<String, ActionError> {
+    let invalid_token = || ActionError::invalid_parameter("Invalid pagination token.");
+    let (key, signature) = token.split_once('.').ok_or_else(invalid_token)?;
+    let key = URL_SAFE_NO_PAD
+        .decode(key)
+        .ok()
+        .and_then(|key| String::from_utf8(key).ok())
+        .ok_or_else(invalid_token)?;
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| invalid_token())?;
+    mac(&key)
+        .verify_slice(&signature)
+        .map_err(|_| invalid_token())?;
+    Ok(key)
+}
+
+/// Returns the page size of `Limit` or `MaxResults`, omitted or zero is the maximum.
+pub fn page_size(limit: Option<u8>) -> usize {
+    limit
+        .map(usize::from)
+        .filter(|limit| *limit > 0)
+        .unwrap_or(MAX_PAGE_SIZE)
+        .min(MAX_PAGE_SIZE)
+}
+
+/// Returns a page of the items ordered by the unique sort key, starting after the item of
+/// the token. Pages are stable while items are added or removed since the token holds
+/// the key of the last returned item instead of an offset.
+pub fn paginate<T, F>(
+    mut items: Vec<T>,
+    sort_key: F,
+    limit: usize,
+    token: Option<&String>,
+) -> Result<Page<T>, ActionError>
+where
+    F: Fn(&T) -> String,
+{
+    items.sort_by_key(|item| sort_key(item));
+    if let Some(token) = token {
+        let after = decode_token(token)?;
+        items.retain(|item| sort_key(item) > after);
+    }
+    let next_token = if items.len() > limit && limit > 0 {
+        Some(encode_token(&sort_key(&items[limit - 1])))
+    } else {
+        None
+    };
+    items.truncate(limit);
+    Ok(Page { items, next_token })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn paginate_items() {
+        let items = vec!["c", "a", "e", "b", "d"];
+        let key = |item: &&str| item.to_string();
+        let page = paginate(items.clone(), key, 2, None).unwrap();
+        assert_eq!(vec!["a", "b"], page.items);
+        let page = paginate(items.clone(), key, 2, page.next_token.as_ref()).unwrap();
+        assert_eq!(vec!["c", "d"], page.items);
+        let page = paginate(items, key, 2, page.next_token.as_ref()).unwrap();
+        assert_eq!(vec!["e"], page.items);
+        assert_eq!(None, page.next_token);
+    }
+
+    #[test]
+    fn reject_tampered_token() {
+        let token = encode_token("b");
+        assert_eq!("b", decode_token(&token).unwrap());
+        let (_, signature) = token.split_once('.').unwrap();
+        let tampered = format!("{}.{}", URL_SAFE_NO_PAD.encode("a"), signature);
+        assert_eq!(
+            "InvalidParameterException",
+            decode_token(&tampered).unwrap_err().error
+        );
+        assert!(decode_token("token").is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use super::{ActionError, PasswordPolicyType, User, UserPool, UserStatus};
+use chrono::{Duration, Utc};
+
+const DEFAULT_MINIMUM_LENGTH: i64 = 8;
+const DEFAULT_TEMPORARY_PASSWORD_VALIDITY_DAYS: i64 = 7;
+const SYMBOLS: &str = "^$*.[]{}()?\"!@#%&/\\,><':;|_~`=+-";
+
+/// Whether the characters are required, matcher of the characters and the reason of failure.
+type CharacterCheck = (bool, fn(char) -> bool, &'static str);
+
+/// Returns password policy of the user pool, Cognito's default when not configured.
+pub fn password_policy(pool: &UserPool) -> PasswordPolicyType {
+    pool.config
+        .policies
+        .as_ref()
+        .and_then(|p| p.password_policy.clone())
+        .unwrap_or(PasswordPolicyType {
+            minimum_length: Some(DEFAULT_MINIMUM_LENGTH),
+            require_lowercase: Some(true),
+            require_numbers: Some(true),
+            require_symbols: Some(true),
+            require_uppercase: Some(true),
+            temporary_password_validity_days: Some(DEFAULT_TEMPORARY_PASSWORD_VALIDITY_DAYS),
+        })
+}
+
+fn invalid_password(reason: &str) -> ActionError {
+    ActionError::new(
+        "InvalidPasswordException",
+        &format!("Password did not conform with policy: {}", reason),
+    )
+}
+
+/// Checks the password conforms to the password policy of the user pool.
+pub fn check_password_policy(pool: &UserPool, password: &str) -> Result<(), ActionError> {
+    let policy = password_policy(pool);
+    let minimum_length = policy.minimum_length.unwrap_or(DEFAULT_MINIMUM_LENGTH);
+    let checks: [CharacterCheck; 4] = [
+        (
+            policy.require_lowercase.unwrap_or_default(),
+            |c| c.is_ascii_lowercase(),
+            "Password must have lowercase characters",
+        ),
+        (
+            policy.require_uppercase.unwrap_or_default(),
+            |c| c.is_ascii_uppercase(),
+            "Password must have uppercase characters",
+        ),
+        (
+            policy.require_numbers.unwrap_or_default(),
+            |c| c.is_ascii_digit(),
+            "Password must have numeric characters",
+        ),
+        (
+            policy.require_symbols.unwrap_or_default(),
+            |c| SYMBOLS.contains(c),
+            "Password must have symbol characters",
+        ),
+    ];
+
+    if (password.chars().count() as i64) < minimum_length {
+        return Err(invalid_password("Password not long enough"));
+    }
+    for (required, is_match, reason) in checks.iter() {
+        if *required && !password.chars().any(is_match) {
+            return Err(invalid_password(reason));
+        }
+    }
+    Ok(())
+}
+
+/// Checks the temporary password of the user is not expired by `TemporaryPasswordValidityDays`
+/// (or deprecated `UnusedAccountValidityDays`) of the user pool.
+pub fn check_temporary_password(pool: &UserPool, user: &User) -> Result<(), ActionError> {
+    if user.status != UserStatus::FORCE_CHANGE_PASSWORD {
+        return Ok(());
+    }
+    let validity_days = password_policy(pool)
+        .temporary_password_validity_days
+        .or_else(|| {
+            pool.config
+                .admin_create_user_config
+                .as_ref()
+                .and_then(|c| c.unused_account_validity_days)
+        })
+        .unwrap_or(DEFAULT_TEMPORARY_PASSWORD_VALIDITY_DAYS);
+    if user.password_date + Duration::days(validity_days) < Utc::now() {
+        return Err(ActionError::new(
+            "NotAuthorizedException",
+            "Temporary password has expired and must be reset by an administrator.",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::{CreateUserPoolRequest, UserPoolPolicyType};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn default_password_policy() {
+        let pool = UserPool::new("user_pool_id", Default::default());
+        assert!(check_password_policy(&pool, "Passw0rd!").is_ok());
+        assert_eq!(
+            "Password did not conform with policy: Password not long enough",
+            check_password_policy(&pool, "Pa0!").unwrap_err().message
+        );
+        assert!(check_password_policy(&pool, "password0!").is_err());
+        assert!(check_password_policy(&pool, "PASSWORD0!").is_err());
+        assert_eq!(
+            "Password did not conform with policy: Password must have numeric characters",
+            check_password_policy(&pool, "Password!")
+                .unwrap_err()
+                .message
+        );
+        assert!(check_password_policy(&pool, "Password0").is_err());
+    }
+
+    #[test]
+    fn configured_password_policy() {
+        let pool = UserPool::new(
+            "user_pool_id",
+            CreateUserPoolRequest {
+                policies: Some(UserPoolPolicyType {
+                    password_policy: Some(PasswordPolicyType {
+                        minimum_length: Some(6),
+                        ..Default::default()
+                    }),
+                }),
+                ..Default::default()
+            },
+        );
+        assert!(check_password_policy(&pool, "secret").is_ok());
+        assert!(check_password_policy(&pool, "short").is_err());
+    }
+
+    #[test]
+    fn expired_temporary_password() {
+        let pool = UserPool::new(
+            "user_pool_id",
+            CreateUserPoolRequest {
+                policies: Some(UserPoolPolicyType {
+                    password_policy: Some(PasswordPolicyType {
+                        temporary_password_validity_days: Some(1),
+                        ..Default::default()
+                    }),
+                }),
+                ..Default::default()
+            },
+        );
+        let mut user = User::new("username");
+        user.status = UserStatus::FORCE_CHANGE_PASSWORD;
+        assert!(check_temporary_password(&pool, &user).is_ok());
+        user.password_date = Utc::now() - Duration::days(2);
+        assert!(check_temporary_password(&pool, &user).is_err());
+        user.status = UserStatus::CONFIRMED;
+        assert!(check_temporary_password(&pool, &user).is_ok());
+    }
+}
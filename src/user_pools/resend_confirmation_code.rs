@@ -0,0 +1,134 @@
+use crate::common::{CLIENT_ID_REGEX, HASH_REGEX, NAME_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const RESEND_CONFIRMATION_CODE_NAME: &str = "ResendConfirmationCode";
+pub const RESEND_CONFIRMATION_CODE_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.ResendConfirmationCode";
+
+super::gen_response_err!(
+    ResendConfirmationCodeError,
+    CodeDeliveryFailureException
+    | ForbiddenException
+    | InvalidEmailRoleAccessPolicyException
+    | InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | LimitExceededException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResendConfirmationCodeRequest {
+    pub analytics_metadata: Option<super::data_types::AnalyticsMetadataType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "CLIENT_ID_REGEX")]
+    pub client_id: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "HASH_REGEX")]
+    pub secret_hash: Option<String>,
+    pub user_context_data: Option<super::data_types::UserContextDataType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "NAME_REGEX")]
+    pub username: Option<String>,
+}
+
+impl super::ToActionName for ResendConfirmationCodeRequest {
+    fn to_action_name() -> &'static str {
+        RESEND_CONFIRMATION_CODE_NAME
+    }
+}
+
+impl super::ToResponse for ResendConfirmationCodeRequest {
+    type E = ResendConfirmationCodeError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(
+            self,
+            RESEND_CONFIRMATION_CODE_NAME,
+            resend_confirmation_code,
+        )
+    }
+}
+
+fn resend_confirmation_code(
+    request: &ResendConfirmationCodeRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let client_id = request.client_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let (user_pool_id, delivery) = super::with_store(|store| {
+        let client = store.find_client(client_id)?;
+        super::verify_secret_hash(client, username, request.secret_hash.as_ref())?;
+        let user_pool_id = client.user_pool_id().to_string();
+        let pool = store.user_pool(&user_pool_id)?;
+        let user = pool.user(username)?;
+        if user.status != super::UserStatus::UNCONFIRMED {
+            return Err(super::ActionError::invalid_parameter(
+                "User is already confirmed.",
+            ));
+        }
+        let delivery = super::verification_delivery(pool, user).ok_or_else(|| {
+            super::ActionError::invalid_parameter(
+                "Cannot resend codes. Auto verification not turned on.",
+            )
+        })?;
+        Ok((user_pool_id, delivery))
+    })?;
+    let code_delivery_details = super::send_code(
+        &user_pool_id,
+        username,
+        super::MessageType::ResendCode,
+        &delivery,
+        &super::TriggerContext::new(Some(client_id), request.client_metadata.as_ref()),
+    )?;
+    Ok(serde_json::json!({ "CodeDeliveryDetails": code_delivery_details }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = ResendConfirmationCodeRequest {
+            client_id: Some("client_id".to_string()),
+            username: Some("username".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = ResendConfirmationCodeRequest {
+            client_id: Some("client_id".to_string()),
+            username: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = ResendConfirmationCodeError::UserNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = ResendConfirmationCodeError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
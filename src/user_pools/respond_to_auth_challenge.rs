@@ -0,0 +1,124 @@
+use crate::common::CLIENT_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const RESPOND_TO_AUTH_CHALLENGE_NAME: &str = "RespondToAuthChallenge";
+pub const RESPOND_TO_AUTH_CHALLENGE_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.RespondToAuthChallenge";
+
+super::gen_response_err!(
+    RespondToAuthChallengeError,
+    AliasExistsException
+    | CodeMismatchException
+    | ExpiredCodeException
+    | ForbiddenException
+    | InvalidParameterException
+    | InvalidPasswordException
+    | InvalidLambdaResponseException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | InvalidUserPoolConfigurationException
+    | MFAMethodNotFoundException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | SoftwareTokenMFANotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct RespondToAuthChallengeRequest {
+    pub analytics_metadata: Option<super::data_types::AnalyticsMetadataType>,
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(custom(function = "super::validate_challenge_name"))]
+    pub challenge_name: Option<String>,
+    pub challenge_responses: Option<std::collections::HashMap<String, String>>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "CLIENT_ID_REGEX")]
+    pub client_id: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    #[validate(length(min = 20, max = 2048))]
+    pub session: Option<String>,
+    pub user_context_data: Option<super::data_types::UserContextDataType>,
+}
+
+impl super::ToActionName for RespondToAuthChallengeRequest {
+    fn to_action_name() -> &'static str {
+        RESPOND_TO_AUTH_CHALLENGE_NAME
+    }
+}
+
+impl super::ToResponse for RespondToAuthChallengeRequest {
+    type E = RespondToAuthChallengeError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(
+            self,
+            RESPOND_TO_AUTH_CHALLENGE_NAME,
+            respond_to_auth_challenge,
+        )
+    }
+}
+
+fn respond_to_auth_challenge(
+    request: &RespondToAuthChallengeRequest,
+) -> Result<super::AuthResponse, super::ActionError> {
+    let client_id = request.client_id.as_ref().unwrap();
+    let user_pool_id = super::with_store(|store| {
+        Ok::<_, super::ActionError>(store.find_client(client_id)?.user_pool_id().to_string())
+    })?;
+    super::respond_to_challenge(
+        &user_pool_id,
+        client_id,
+        request.challenge_name.as_ref().unwrap(),
+        &request.challenge_responses.clone().unwrap_or_default(),
+        request.session.as_deref(),
+        &request.client_metadata.clone().unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = RespondToAuthChallengeRequest {
+            challenge_name: Some("NEW_PASSWORD_REQUIRED".to_string()),
+            client_id: Some("client_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = RespondToAuthChallengeRequest {
+            challenge_name: Some("NEW_PASSWORD_REQUIRED".to_string()),
+            client_id: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = RespondToAuthChallengeError::NotAuthorizedException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = RespondToAuthChallengeError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
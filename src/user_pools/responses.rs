@@ -59,11 +59,13 @@ where
         .header(AWS_ERROR_TYPE_HEADER, format!("{}", error))
         .header(AWS_ERROR_MESSAGE_HEADER, "DUMMY ERROR MESSAGE")
         .header("Content-Type", AWS_CONTENT_TYPE_HEADER_VALUE)
-        .body(json_body(&format!(
-            "{{\"__type\": \"{}\",\"message\":\"{}\"}}",
-            error,
-            message.unwrap_or("DUMMY ERROR MESSAGE")
-        )))
+        .body(json_body(
+            &serde_json::json!({
+                "__type": format!("{}", error),
+                "message": message.unwrap_or("DUMMY ERROR MESSAGE"),
+            })
+            .to_string(),
+        ))
         .unwrap()
 }
 
@@ -83,19 +85,57 @@ where
     }
 }
 
-pub fn to_json_response<R>(request: &R, template_name: &str) -> Response
+/// Checks that the user pool (`UserPoolId`) and the client (`ClientId`) in the request exist.
+fn check_request_resources<R>(request: &R) -> Result<(), super::ActionError>
+where
+    R: serde::Serialize,
+{
+    let value = serde_json::to_value(request).unwrap_or_default();
+    let user_pool_id = value.get("UserPoolId").and_then(|v| v.as_str());
+    let client_id = value.get("ClientId").and_then(|v| v.as_str());
+    super::with_store(|store| store.check_resources(user_pool_id, client_id))
+}
+
+/// Returns a response when forced by config, the request is invalid
+/// or the requested resources do not exist.
+fn precondition_response<R>(request: &R) -> Option<Response>
 where
     R: super::ToActionName + ToResponse + serde::Serialize + Validate,
 {
     if let Some(response) = super::config_response::<R>() {
-        return response;
+        return Some(response);
     };
     if request.validate().is_err() {
         let error =
             super::ResponseError::<R::E>::CommonError(super::CommonError::InvalidParameterValue);
-        return super::error_response(error, Some("Parameters validation error."));
+        return Some(super::error_response(
+            error,
+            Some("Parameters validation error."),
+        ));
     }
+    if let Err(e) = check_request_resources(request) {
+        return Some(e.to_response::<R::E>());
+    }
+    None
+}
 
+/// Merges hook result json object into stored values (hook result has priority).
+fn merge_hook_result(values: serde_json::Value, hook_result: String) -> String {
+    let mut values = match values {
+        serde_json::Value::Object(map) => map,
+        _ => serde_json::Map::new(),
+    };
+    match serde_json::from_str::<serde_json::Value>(&hook_result) {
+        Ok(serde_json::Value::Object(map)) => values.extend(map),
+        _ => log::warn!("hook script result is not a json object: {}", hook_result),
+    }
+    serde_json::Value::Object(values).to_string()
+}
+
+fn render_json_response<R>(request: &R, template_name: &str, values: serde_json::Value) -> Response
+where
+    R: serde::Serialize,
+{
     let hook_result = crate::hooks::call_request_hook(
         template_name,
         &request,
@@ -104,10 +144,13 @@ where
     let opt_json = templates::render_template(
         template_name,
         &request,
-        hook_result.unwrap_or_else(|e| {
-            log::warn!("hook script error: {}", e);
-            "{}".to_string()
-        }),
+        merge_hook_result(
+            values,
+            hook_result.unwrap_or_else(|e| {
+                log::warn!("hook script error: {}", e);
+                "{}".to_string()
+            }),
+        ),
     );
     match opt_json {
         Some(json) => warp::http::Response::builder()
@@ -121,23 +164,68 @@ where
     }
 }
 
-pub fn to_empty_response<R>(request: &R) -> Response
+fn empty_ok_response() -> Response {
+    warp::http::Response::builder()
+        .status(crate::http::status_code(200))
+        .body(super::responses::empty_body())
+        .unwrap()
+}
+
+pub fn to_json_response<R>(request: &R, template_name: &str) -> Response
 where
     R: super::ToActionName + ToResponse + serde::Serialize + Validate,
 {
-    if let Some(response) = super::config_response::<R>() {
+    if let Some(response) = precondition_response(request) {
         return response;
     };
-    if request.validate().is_err() {
-        let error =
-            super::ResponseError::<R::E>::CommonError(super::CommonError::InvalidParameterValue);
-        return super::error_response(error, Some("Parameters validation error."));
+    render_json_response(request, template_name, serde_json::json!({}))
+}
+
+/// Returns rendered template response with values returned by the stateful action.
+///
+/// Values are available in the template in addition to request parameters.
+pub fn to_stateful_json_response<R, T, F>(request: &R, template_name: &str, action: F) -> Response
+where
+    R: super::ToActionName + ToResponse + serde::Serialize + Validate,
+    T: serde::Serialize,
+    F: FnOnce(&R) -> Result<T, super::ActionError>,
+{
+    if let Some(response) = precondition_response(request) {
+        return response;
+    };
+    match action(request).map(|v| serde_json::to_value(v)) {
+        Ok(Ok(values)) => render_json_response(request, template_name, values),
+        Ok(Err(e)) => super::error_response(
+            super::CommonError::InternalFailure,
+            Some(&format!("Error: {:?}", e)),
+        ),
+        Err(e) => e.to_response::<R::E>(),
     }
+}
 
-    warp::http::Response::builder()
-        .status(crate::http::status_code(200))
-        .body(super::responses::empty_body())
-        .unwrap()
+pub fn to_empty_response<R>(request: &R) -> Response
+where
+    R: super::ToActionName + ToResponse + serde::Serialize + Validate,
+{
+    if let Some(response) = precondition_response(request) {
+        return response;
+    };
+    empty_ok_response()
+}
+
+/// Returns empty response after the stateful action succeeded.
+pub fn to_stateful_empty_response<R, F>(request: &R, action: F) -> Response
+where
+    R: super::ToActionName + ToResponse + serde::Serialize + Validate,
+    F: FnOnce(&R) -> Result<(), super::ActionError>,
+{
+    if let Some(response) = precondition_response(request) {
+        return response;
+    };
+    match action(request) {
+        Ok(_) => empty_ok_response(),
+        Err(e) => e.to_response::<R::E>(),
+    }
 }
 
 #[cfg(test)]
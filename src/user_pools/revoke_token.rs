@@ -0,0 +1,95 @@
+use crate::common::{CLIENT_ID_REGEX, TOKEN_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const REVOKE_TOKEN_NAME: &str = "RevokeToken";
+pub const REVOKE_TOKEN_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.RevokeToken";
+
+super::gen_response_err!(
+    RevokeTokenError,
+    ForbiddenException
+    | InvalidParameterException
+    | TooManyRequestsException
+    | UnauthorizedException
+    | UnsupportedOperationException
+    | UnsupportedTokenTypeException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct RevokeTokenRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "CLIENT_ID_REGEX")]
+    pub client_id: Option<String>,
+    #[validate(length(min = 1, max = 64))]
+    #[validate(regex = "CLIENT_ID_REGEX")]
+    pub client_secret: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex = "TOKEN_REGEX")]
+    pub token: Option<String>,
+}
+
+impl super::ToActionName for RevokeTokenRequest {
+    fn to_action_name() -> &'static str {
+        REVOKE_TOKEN_NAME
+    }
+}
+
+impl super::ToResponse for RevokeTokenRequest {
+    type E = RevokeTokenError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_empty_response(self, revoke_token)
+    }
+}
+
+fn revoke_token(request: &RevokeTokenRequest) -> Result<(), super::ActionError> {
+    super::with_store(|store| {
+        super::revoke_token(
+            store,
+            request.token.as_ref().unwrap(),
+            request.client_id.as_ref().unwrap(),
+            request.client_secret.as_ref(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = RevokeTokenRequest {
+            client_id: Some("client_id".to_string()),
+            token: Some("token".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = RevokeTokenRequest {
+            client_id: Some("client_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = RevokeTokenError::UnsupportedTokenTypeException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = RevokeTokenError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::common::TOKEN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const SET_USER_MFA_PREFERENCE_NAME: &str = "SetUserMFAPreference";
+pub const SET_USER_MFA_PREFERENCE_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.SetUserMFAPreference";
+
+super::gen_response_err!(
+    SetUserMFAPreferenceError,
+    ForbiddenException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetUserMFAPreferenceRequest {
+    #[validate(required)]
+    #[validate(regex = "TOKEN_REGEX")]
+    pub access_token: Option<String>,
+    #[serde(rename = "SMSMfaSettings")]
+    pub sms_mfa_settings: Option<super::data_types::SMSMfaSettingsType>,
+    pub software_token_mfa_settings: Option<super::data_types::SoftwareTokenMfaSettingsType>,
+}
+
+impl super::ToActionName for SetUserMFAPreferenceRequest {
+    fn to_action_name() -> &'static str {
+        SET_USER_MFA_PREFERENCE_NAME
+    }
+}
+
+impl super::ToResponse for SetUserMFAPreferenceRequest {
+    type E = SetUserMFAPreferenceError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_empty_response(self, set_user_mfa_preference)
+    }
+}
+
+fn set_user_mfa_preference(
+    request: &SetUserMFAPreferenceRequest,
+) -> Result<(), super::ActionError> {
+    super::with_store(|store| {
+        let (user_pool_id, username) =
+            super::verify_access_token(store, request.access_token.as_ref().unwrap())?;
+        let user = store.user_pool_mut(&user_pool_id)?.user_mut(&username)?;
+        super::set_mfa_preference(
+            user,
+            request.sms_mfa_settings.as_ref(),
+            request.software_token_mfa_settings.as_ref(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = SetUserMFAPreferenceRequest {
+            access_token: Some("access_token".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = SetUserMFAPreferenceRequest {
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = SetUserMFAPreferenceError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = SetUserMFAPreferenceError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,258 @@
+use crate::common::{CLIENT_ID_REGEX, HASH_REGEX, NAME_REGEX, PASSWORD_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const SIGN_UP_NAME: &str = "SignUp";
+pub const SIGN_UP_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.SignUp";
+
+super::gen_response_err!(
+    SignUpError,
+    CodeDeliveryFailureException
+    | ForbiddenException
+    | InvalidEmailRoleAccessPolicyException
+    | InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidPasswordException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | LimitExceededException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UsernameExistsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignUpRequest {
+    pub analytics_metadata: Option<super::data_types::AnalyticsMetadataType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "CLIENT_ID_REGEX")]
+    pub client_id: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    #[validate(required)]
+    #[validate(length(min = 6, max = 256))]
+    #[validate(regex = "PASSWORD_REGEX")]
+    pub password: Option<String>,
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "HASH_REGEX")]
+    pub secret_hash: Option<String>,
+    #[validate]
+    pub user_attributes: Option<Vec<super::data_types::AttributeType>>,
+    pub user_context_data: Option<super::data_types::UserContextDataType>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "NAME_REGEX")]
+    pub username: Option<String>,
+    #[validate]
+    pub validation_data: Option<Vec<super::data_types::AttributeType>>,
+}
+
+impl super::ToActionName for SignUpRequest {
+    fn to_action_name() -> &'static str {
+        SIGN_UP_NAME
+    }
+}
+
+impl super::ToResponse for SignUpRequest {
+    type E = SignUpError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, SIGN_UP_NAME, sign_up)
+    }
+}
+
+/// Returns the attribute used as username when the user pool has `UsernameAttributes`.
+fn username_attribute(
+    username_attributes: &[String],
+    username: &str,
+) -> Result<&'static str, super::ActionError> {
+    let email = username_attributes
+        .iter()
+        .any(|a| a == super::EMAIL_ATTRIBUTE);
+    let phone_number = username_attributes
+        .iter()
+        .any(|a| a == super::PHONE_NUMBER_ATTRIBUTE);
+    match (email, phone_number) {
+        (true, _) if username.contains('@') => Ok(super::EMAIL_ATTRIBUTE),
+        (_, true) if username.starts_with('+') => Ok(super::PHONE_NUMBER_ATTRIBUTE),
+        (true, true) => Err(super::ActionError::invalid_parameter(
+            "Username should be either an email or a phone number.",
+        )),
+        (true, false) => Err(super::ActionError::invalid_parameter(
+            "Username should be an email.",
+        )),
+        _ => Err(super::ActionError::invalid_parameter(
+            "Username should be a phone number.",
+        )),
+    }
+}
+
+/// Sets the email or phone number given as username to the user when the user pool has
+/// `UsernameAttributes`, the user is then stored with `sub`. Other users are left as is.
+pub(crate) fn set_username_attribute(
+    pool: &super::UserPool,
+    user: &mut super::User,
+    username: &str,
+) -> Result<(), super::ActionError> {
+    let username_attributes = match pool.config.username_attributes.as_deref() {
+        Some(username_attributes) if !username_attributes.is_empty() => username_attributes,
+        _ => return Ok(()),
+    };
+    let attribute = username_attribute(username_attributes, username)?;
+    if pool.resolve_username(username).is_some() {
+        return Err(super::ActionError::new(
+            "UsernameExistsException",
+            &format!("An account with the given {} already exists.", attribute),
+        ));
+    }
+    user.username = user.sub().to_string();
+    user.set_attributes(vec![(attribute.to_string(), username.to_string())])
+}
+
+fn sign_up(request: &SignUpRequest) -> Result<serde_json::Value, super::ActionError> {
+    let client_id = request.client_id.as_ref().unwrap();
+    let username = request.username.as_ref().unwrap();
+    let attributes = request
+        .user_attributes
+        .iter()
+        .flatten()
+        .map(|attr| {
+            (
+                attr.name.clone().unwrap_or_default(),
+                attr.value.clone().unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let validation_data = request
+        .validation_data
+        .iter()
+        .flatten()
+        .filter_map(|attr| attr.name.clone().zip(attr.value.clone()))
+        .collect();
+    let client_metadata = request.client_metadata.clone().unwrap_or_default();
+    let (user_pool_id, mut user) = super::with_store(|store| {
+        let client = store.find_client(client_id)?;
+        super::verify_secret_hash(client, username, request.secret_hash.as_ref())?;
+        let user_pool_id = client.user_pool_id().to_string();
+        let pool = store.user_pool_mut(&user_pool_id)?;
+        let password = request.password.as_ref().unwrap();
+        super::check_password_policy(pool, password)?;
+
+        let mut user = super::User::new(username);
+        user.status = super::UserStatus::UNCONFIRMED;
+        user.set_password(password);
+        user.set_attributes(attributes)?;
+        set_username_attribute(pool, &mut user, username)?;
+        if pool.users.contains_key(&user.username) {
+            return Err(super::ActionError::new(
+                "UsernameExistsException",
+                "User already exists",
+            ));
+        }
+        Ok((user_pool_id, user))
+    })?;
+
+    let pre_sign_up = super::pre_sign_up(
+        &user_pool_id,
+        Some(client_id),
+        "PreSignUp_SignUp",
+        &user,
+        &validation_data,
+        &client_metadata,
+    )?;
+    pre_sign_up.auto_verify(&mut user)?;
+    let confirmed = pre_sign_up.auto_confirm_user();
+    let (username, sub, delivery) = super::with_store(|store| {
+        let pool = store.user_pool_mut(&user_pool_id)?;
+        let delivery = if confirmed {
+            user.status = super::UserStatus::CONFIRMED;
+            None
+        } else {
+            super::verification_delivery(pool, &user)
+        };
+        let user = pool.add_user(user)?;
+        Ok::<_, super::ActionError>((user.username.clone(), user.sub().to_string(), delivery))
+    })?;
+    let code_delivery_details = delivery
+        .map(|delivery| {
+            super::send_code(
+                &user_pool_id,
+                &username,
+                super::MessageType::SignUp,
+                &delivery,
+                &super::TriggerContext::new(Some(client_id), Some(&client_metadata)),
+            )
+        })
+        .transpose()?;
+    if confirmed {
+        super::post_confirmation(
+            &user_pool_id,
+            Some(client_id),
+            "PostConfirmation_ConfirmSignUp",
+            &username,
+            &client_metadata,
+        )?;
+    }
+    Ok(serde_json::json!({
+        "CodeDeliveryDetails": code_delivery_details,
+        "UserConfirmed": confirmed,
+        "UserSub": sub,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = SignUpRequest {
+            client_id: Some("client_id".to_string()),
+            password: Some("Passw0rd!".to_string()),
+            username: Some("username".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = SignUpRequest {
+            client_id: Some("client_id".to_string()),
+            password: Some("Passw0rd!".to_string()),
+            username: Some("".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = SignUpError::UsernameExistsException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = SignUpError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+
+    #[test]
+    fn username_attribute_by_username_attributes() {
+        let both = vec!["email".to_string(), "phone_number".to_string()];
+        assert_eq!(Ok("email"), username_attribute(&both, "user@example.com"));
+        assert_eq!(
+            Ok("phone_number"),
+            username_attribute(&both, "+12345556789")
+        );
+        assert!(username_attribute(&both, "username").is_err());
+        assert!(username_attribute(&["email".to_string()], "+12345556789").is_err());
+    }
+}
@@ -0,0 +1,409 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use num_bigint::BigUint;
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// 3072-bit group of RFC 5054, same as Cognito.
+const N_HEX: &str = "\
+FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B139B22514A08798E3404DD\
+EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F\
+83655D23DCA3AD961C62F356208552BB9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF6955817183995497CEA956AE515D2261898FA0510\
+15728E5A8AAAC42DAD33170D04507A33A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7\
+ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864D87602733EC86A64521F2B18177B200C\
+BBE117577A615D6C770988C0BAD946E208E24FA074E5AB3143DB5BFCE0FD108E4B82D120A93AD2CAFFFFFFFFFFFFFFFF";
+const G: u32 = 2;
+const DERIVED_KEY_INFO: &[u8] = b"Caldera Derived Key";
+const SALT_BYTES: usize = 16;
+const PRIVATE_KEY_BYTES: usize = 128;
+const SECRET_BLOCK_BYTES: usize = 512;
+
+/// Format of `TIMESTAMP` challenge response (e.g. `Tue Sep 5 08:02:03 UTC 2023`).
+pub const SRP_TIMESTAMP_FORMAT: &str = "%a %b %-d %H:%M:%S UTC %Y";
+
+static N: Lazy<BigUint> = Lazy::new(|| BigUint::parse_bytes(N_HEX.as_bytes(), 16).unwrap());
+static K: Lazy<BigUint> = Lazy::new(|| hash_to_int(&[&pad(&N), &pad(&BigUint::from(G))]));
+
+/// Returns big-endian bytes with a leading zero byte when the highest bit is set,
+/// same as `padHex` of amazon-cognito-identity-js.
+fn pad(n: &BigUint) -> Vec<u8> {
+    let bytes = n.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        [&[0u8][..], &bytes].concat()
+    } else {
+        bytes
+    }
+}
+
+fn hash(parts: &[&[u8]]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().to_vec()
+}
+
+fn hash_to_int(parts: &[&[u8]]) -> BigUint {
+    BigUint::from_bytes_be(&hash(parts))
+}
+
+fn hmac(key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn random_int(len: usize) -> BigUint {
+    let mut bytes = vec![0u8; len];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    BigUint::from_bytes_be(&bytes)
+}
+
+fn to_hex(n: &BigUint) -> String {
+    n.to_str_radix(16)
+}
+
+fn from_hex(value: &str) -> Option<BigUint> {
+    BigUint::parse_bytes(value.as_bytes(), 16)
+}
+
+/// Returns pool name used by SRP, the user pool id without the region.
+pub fn srp_pool_name(user_pool_id: &str) -> &str {
+    user_pool_id.split('_').nth(1).unwrap_or(user_pool_id)
+}
+
+/// Returns a random `SECRET_BLOCK`.
+pub fn generate_secret_block() -> String {
+    let mut bytes = vec![0u8; SECRET_BLOCK_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    STANDARD.encode(bytes)
+}
+
+/// x = H(salt | H(pool name | username | ":" | password))
+fn private_key(pool_name: &str, username: &str, password: &str, salt: &BigUint) -> BigUint {
+    let identity = hash(&[format!("{}{}:{}", pool_name, username, password).as_bytes()]);
+    hash_to_int(&[&pad(salt), &identity])
+}
+
+/// Returns the 128-bit key derived by HKDF from the premaster secret.
+fn derived_key(premaster_secret: &BigUint, u: &BigUint) -> Vec<u8> {
+    let prk = hmac(&pad(u), &[&pad(premaster_secret)]);
+    hmac(&prk, &[DERIVED_KEY_INFO, &[1u8]])[..16].to_vec()
+}
+
+/// Returns `PASSWORD_CLAIM_SIGNATURE` signed by the derived key.
+fn password_claim_signature(
+    key: &[u8],
+    pool_name: &str,
+    username: &str,
+    secret_block: &str,
+    timestamp: &str,
+) -> Option<String> {
+    let secret_block = STANDARD.decode(secret_block).ok()?;
+    Some(STANDARD.encode(hmac(
+        key,
+        &[
+            pool_name.as_bytes(),
+            username.as_bytes(),
+            &secret_block,
+            timestamp.as_bytes(),
+        ],
+    )))
+}
+
+/// Server side state of the SRP-6a exchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SrpSession {
+    pub srp_a: BigUint,
+    pub srp_b: BigUint,
+    private_b: BigUint,
+    pub salt: BigUint,
+    verifier: BigUint,
+}
+
+impl SrpSession {
+    /// Starts the exchange with client public value `SRP_A` (hex),
+    /// returns `None` when `SRP_A` is invalid.
+    pub fn new(pool_name: &str, username: &str, password: &str, srp_a: &str) -> Option<Self> {
+        Self::with_keys(
+            pool_name,
+            username,
+            password,
+            srp_a,
+            random_int(SALT_BYTES),
+            random_int(PRIVATE_KEY_BYTES),
+        )
+    }
+
+    fn with_keys(
+        pool_name: &str,
+        username: &str,
+        password: &str,
+        srp_a: &str,
+        salt: BigUint,
+        private_b: BigUint,
+    ) -> Option<Self> {
+        let srp_a = from_hex(srp_a).filter(|a| a % &*N != BigUint::default())?;
+        let verifier =
+            BigUint::from(G).modpow(&private_key(pool_name, username, password, &salt), &N);
+        let srp_b = (&*K * &verifier + BigUint::from(G).modpow(&private_b, &N)) % &*N;
+        Some(SrpSession {
+            srp_a,
+            srp_b,
+            private_b,
+            salt,
+            verifier,
+        })
+    }
+
+    /// Returns `SALT` and `SRP_B` challenge parameters.
+    pub fn challenge_parameters(&self) -> Vec<(String, String)> {
+        vec![
+            ("SALT".to_string(), to_hex(&self.salt)),
+            ("SRP_B".to_string(), to_hex(&self.srp_b)),
+        ]
+    }
+
+    /// Returns the state to be kept in the auth session.
+    pub fn to_parameters(&self) -> HashMap<String, String> {
+        vec![
+            ("SRP_A", &self.srp_a),
+            ("SRP_B", &self.srp_b),
+            ("SRP_PRIVATE_B", &self.private_b),
+            ("SALT", &self.salt),
+            ("VERIFIER", &self.verifier),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), to_hex(v)))
+        .collect()
+    }
+
+    /// Restores the state kept in the auth session.
+    pub fn from_parameters(parameters: &HashMap<String, String>) -> Option<Self> {
+        let value = |name: &str| parameters.get(name).and_then(|v| from_hex(v));
+        Some(SrpSession {
+            srp_a: value("SRP_A")?,
+            srp_b: value("SRP_B")?,
+            private_b: value("SRP_PRIVATE_B")?,
+            salt: value("SALT")?,
+            verifier: value("VERIFIER")?,
+        })
+    }
+
+    /// u = H(A | B)
+    fn scrambling_parameter(&self) -> BigUint {
+        hash_to_int(&[&pad(&self.srp_a), &pad(&self.srp_b)])
+    }
+
+    /// S = (A * v^u) ^ b
+    fn premaster_secret(&self, u: &BigUint) -> BigUint {
+        (&self.srp_a * self.verifier.modpow(u, &N) % &*N).modpow(&self.private_b, &N)
+    }
+
+    /// Verifies `PASSWORD_CLAIM_SIGNATURE` sent by the client.
+    pub fn verify_password_claim(
+        &self,
+        pool_name: &str,
+        username: &str,
+        secret_block: &str,
+        timestamp: &str,
+        signature: &str,
+    ) -> bool {
+        let u = self.scrambling_parameter();
+        let key = derived_key(&self.premaster_secret(&u), &u);
+        password_claim_signature(&key, pool_name, username, secret_block, timestamp).as_deref()
+            == Some(signature)
+    }
+}
+
+/// Checks `TIMESTAMP` is formatted as Cognito does.
+pub fn is_valid_srp_timestamp(timestamp: &str) -> bool {
+    chrono::NaiveDateTime::parse_from_str(timestamp, SRP_TIMESTAMP_FORMAT).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Client side of the exchange, same as amazon-cognito-identity-js.
+    fn client_signature(
+        pool_name: &str,
+        username: &str,
+        password: &str,
+        private_a: &BigUint,
+        session: &SrpSession,
+        secret_block: &str,
+        timestamp: &str,
+    ) -> String {
+        let srp_a = BigUint::from(G).modpow(private_a, &N);
+        let u = hash_to_int(&[&pad(&srp_a), &pad(&session.srp_b)]);
+        let x = private_key(pool_name, username, password, &session.salt);
+        let kgx = &*K * BigUint::from(G).modpow(&x, &N) % &*N;
+        // S = (B - k * g^x) ^ (a + u * x)
+        let base = (&session.srp_b + &*N - kgx) % &*N;
+        let premaster_secret = base.modpow(&(private_a + &u * &x), &N);
+        let key = derived_key(&premaster_secret, &u);
+        password_claim_signature(&key, pool_name, username, secret_block, timestamp).unwrap()
+    }
+
+    #[test]
+    fn n_is_3072_bit() {
+        assert_eq!(3072, N.bits());
+    }
+
+    #[test]
+    fn pad_adds_leading_zero_for_high_bit() {
+        assert_eq!(vec![0x00, 0x80], pad(&BigUint::from(0x80u32)));
+        assert_eq!(vec![0x7f], pad(&BigUint::from(0x7fu32)));
+    }
+
+    #[test]
+    fn verify_password_claim_of_client() {
+        let private_a = random_int(PRIVATE_KEY_BYTES);
+        let srp_a = to_hex(&BigUint::from(G).modpow(&private_a, &N));
+        let session = SrpSession::new("abcdefghi", "username", "Passw0rd!", &srp_a).unwrap();
+        let secret_block = generate_secret_block();
+        let timestamp = "Tue Sep 5 08:02:03 UTC 2023";
+
+        let signature = client_signature(
+            "abcdefghi",
+            "username",
+            "Passw0rd!",
+            &private_a,
+            &session,
+            &secret_block,
+            timestamp,
+        );
+        let session = SrpSession::from_parameters(&session.to_parameters()).unwrap();
+        assert!(session.verify_password_claim(
+            "abcdefghi",
+            "username",
+            &secret_block,
+            timestamp,
+            &signature
+        ));
+
+        let signature = client_signature(
+            "abcdefghi",
+            "username",
+            "wrong",
+            &private_a,
+            &session,
+            &secret_block,
+            timestamp,
+        );
+        assert!(!session.verify_password_claim(
+            "abcdefghi",
+            "username",
+            &secret_block,
+            timestamp,
+            &signature
+        ));
+    }
+
+    /// Values computed by the client algorithm of amazon-cognito-identity-js
+    /// (`AuthenticationHelper`) with the fixed a, b and salt below.
+    #[test]
+    fn known_answer_of_js_client() {
+        let private_a = from_hex(&"0123456789abcdef".repeat(16)).unwrap();
+        let private_b = from_hex(&"fedcba9876543210".repeat(16)).unwrap();
+        let salt = from_hex("abcdef0123456789abcdef0123456789").unwrap();
+        let secret_block = "c2VjcmV0IGJsb2Nr";
+        let timestamp = "Tue Sep 5 08:02:03 UTC 2023";
+        let signature = "Traa1TaDg4OK5BoPkW3G5W76rVw+MI/EDMkDU7JGjJA=";
+        let srp_a = "\
+54b8e4da8991abf64544e5e11b2bd8c7a17bf13cdc3b757a276c8d475af798afd3666395ae0a291861ac9f3181e26318\
+5ab8006e236972ff0ac89ff60a824bf8c4e19c07106f6f62b7a129f56e73477cd23ce582c6a23d19c5cb2fbf29f32978\
+fcf0743650a1572c349444cdc3229d5b29c60fdb9f3dfc0ecbd532302115b7aab5016382590f811a5b7caa46ec650538\
+e1377de9c35a4203edc9b004658d1147caa08516730d40c80409b0bab60fd885ae184434764cc759d8b7905a7c1deff6\
+46e62540614d40378604b21e170159c1931f362f8003e66b5d81cd0b5996850c0735512ed5c8913864e819e7520b25d6\
+df825b2372b56398ccb46a2f29e31760d68e468d841518a783046b56c28cfc12d789a725469640ef0db5b557cbe9117f\
+97d808df3f32c9610e5ef7822593e5ee1289b7f1993ba4406d84926ca459cbcb00d77369492e9363a7ac6191652c8d18\
+d1c94422fd0e579bcfff161b945072743a4934e650373ecc40acebb84ff209b7c8ba5d5b7cd4a786ba1174323284223";
+        let srp_b = "\
+447c526a67af73b8325f844ab4352c37c5fb0203456e0b0524e97e27e58e5c778f20186bf95196e212132ebc6326522d\
+5a5107fc5ff751a1c35cad35aebfd2dbc9ed1baf59ab07da66560d42d0e739db603ce0fc85b68170c1fbe4bd4d4808e9\
+d85247814e9e3a96952be7bc28d2ee2f45b3a6c97e0d6d3582d21ff0c7c53a8ec44b3c8ffc7b2267143e6711fe0c4c70\
+31fff53cb9545c6c1d3340683e3284fd8f1b53ebb990780af53c53c4dc09cda93ee032cc2834ee8fbd8eedd2eff90a8f\
+c1cd48d88b6d60b57744578a5a292d925bf589857f3260cb0b42471171af232147987f091d2d4c10724ba0b51319c6b4\
+2a9e04d21b6dde2d0eb1633e9642581d0d0e2e6a8bc8460e1800487d610f091f812b687f9b1d34643f9668fb9c9ddb26\
+49ec2433507419df5a8793f3f25067c4bc5dd6da3be9b40a1ba528d07764300fb7a36c1667217da03b3dbefc59c77450\
+0bbef1137e530ee6f2e3c1aa8c019c396b2a494820b59e2c14eb4210ae92bc37e4a3fccf2b5ce9c90ee3981dcfb28195";
+        let premaster_secret = "\
+76e7171729173bfaf4433b33e67c9118a01d268547684a51bbf3ade5384731e9cfedb5846cffcf52b7a8bec1cd780dc9\
+27b061eb3967e7d5c929b25f61dfde229312d11531d3cb801727ec3252199d987aefead2aaac2b10a709a80dee65d7a0\
+c86c257d6c31343816f9e752b654082ab5a76faa4d4eda1d60271134ee788b27399474855e4c6b1e69468f8f3fbd1edd\
+092110ab6ccd8449590e5fe04e5611b6b64f692c87dff4577e9e5141bfb5da344654753185daec9d4b08623cf1f04469\
+9e4287b274526e5ae4fecefd02960513d46f1ba58c8eadee82222c0f63af808596f4b4d6b1f3c23c64221661875132e8\
+e6a9a4b259c11af31a38e0d91b330cf57d2f110ad7a371115fab0269e4d439de5315cfd52242fae14003347aeaf266af\
+7d2f33854a6f3d59a009e952d73f827ff19e1ebed1af17898a8909529abc2b931648bba4b1e2b26f4424ce793860319d\
+389b6d7f21c95e864af35ac9b32c8f1f4cd76eaf3bfd2188e76ac286fcd2f401607940f3de39ed0378a28a482f99849e";
+
+        assert!(is_valid_srp_timestamp(timestamp));
+        assert_eq!(srp_a, to_hex(&BigUint::from(G).modpow(&private_a, &N)));
+        let session =
+            SrpSession::with_keys("abcdefghi", "username", "Passw0rd!", srp_a, salt, private_b)
+                .unwrap();
+        assert_eq!(srp_b, to_hex(&session.srp_b));
+        let u = session.scrambling_parameter();
+        assert_eq!(
+            "8174f142b23fca3299a4e071e4f40ace5546b9706da723dc697f56af8eeb423e",
+            to_hex(&u)
+        );
+        let s = session.premaster_secret(&u);
+        assert_eq!(premaster_secret, to_hex(&s));
+        assert_eq!(
+            "92ff6a88030096f699d5ee49a7e6c1b1",
+            derived_key(&s, &u)
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        );
+        assert_eq!(
+            signature,
+            client_signature(
+                "abcdefghi",
+                "username",
+                "Passw0rd!",
+                &private_a,
+                &session,
+                secret_block,
+                timestamp,
+            )
+        );
+        assert!(session.verify_password_claim(
+            "abcdefghi",
+            "username",
+            secret_block,
+            timestamp,
+            signature
+        ));
+    }
+
+    #[test]
+    fn invalid_srp_a() {
+        assert!(SrpSession::new("abcdefghi", "username", "Passw0rd!", "0").is_none());
+        assert!(SrpSession::new("abcdefghi", "username", "Passw0rd!", N_HEX).is_none());
+        assert!(SrpSession::new("abcdefghi", "username", "Passw0rd!", "xyz").is_none());
+    }
+
+    #[test]
+    fn srp_timestamp_format() {
+        assert!(is_valid_srp_timestamp("Tue Sep 5 08:02:03 UTC 2023"));
+        assert!(is_valid_srp_timestamp("Tue Sep 12 08:02:03 UTC 2023"));
+        assert!(!is_valid_srp_timestamp("2023-09-05T08:02:03Z"));
+    }
+
+    #[test]
+    fn srp_pool_name_from_user_pool_id() {
+        assert_eq!("abcdefghi", srp_pool_name("us-east-1_abcdefghi"));
+    }
+}
@@ -0,0 +1,298 @@
+mod authorization_code;
+mod group;
+mod identity_provider;
+mod session;
+mod user;
+mod user_import_job;
+mod user_pool;
+mod user_pool_client;
+
+pub use self::authorization_code::*;
+pub use self::group::*;
+pub use self::identity_provider::*;
+pub use self::session::*;
+pub use self::user::*;
+pub use self::user_import_job::*;
+pub use self::user_pool::*;
+pub use self::user_pool_client::*;
+
+use once_cell::sync::Lazy;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+static STORE: Lazy<Mutex<Store>> = Lazy::new(|| Mutex::new(Store::default()));
+
+thread_local! {
+    static STORE_LOCKED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// In-memory state of all user pools.
+#[derive(Debug, Default)]
+pub struct Store {
+    pub user_pools: HashMap<String, UserPool>,
+    pub sessions: HashMap<String, AuthSession>,
+    pub authorization_codes: HashMap<String, AuthorizationCode>,
+    pub outbox: super::Outbox,
+}
+
+impl Store {
+    pub fn user_pool(&self, user_pool_id: &str) -> Result<&UserPool, super::ActionError> {
+        self.user_pools
+            .get(user_pool_id)
+            .ok_or_else(|| user_pool_not_found(user_pool_id))
+    }
+
+    pub fn user_pool_mut(
+        &mut self,
+        user_pool_id: &str,
+    ) -> Result<&mut UserPool, super::ActionError> {
+        self.user_pools
+            .get_mut(user_pool_id)
+            .ok_or_else(|| user_pool_not_found(user_pool_id))
+    }
+
+    pub fn add_user_pool(&mut self, config: super::CreateUserPoolRequest) -> &UserPool {
+        let id = generate_user_pool_id();
+        self.user_pools
+            .insert(id.clone(), UserPool::new(&id, config));
+        &self.user_pools[&id]
+    }
+
+    pub fn remove_user_pool(&mut self, user_pool_id: &str) -> Result<UserPool, super::ActionError> {
+        self.user_pools
+            .remove(user_pool_id)
+            .ok_or_else(|| user_pool_not_found(user_pool_id))
+    }
+
+    /// Finds a client from all user pools.
+    pub fn find_client(&self, client_id: &str) -> Result<&UserPoolClient, super::ActionError> {
+        self.user_pools
+            .values()
+            .find_map(|pool| pool.clients.get(client_id))
+            .ok_or_else(|| {
+                super::ActionError::resource_not_found(&format!(
+                    "User pool client {} does not exist.",
+                    client_id
+                ))
+            })
+    }
+
+    /// Stores the auth session and returns its id.
+    pub fn add_session(&mut self, session: AuthSession) -> String {
+        self.sessions.retain(|_, s| !s.is_expired());
+        let id = session.id.clone();
+        self.sessions.insert(id.clone(), session);
+        id
+    }
+
+    /// Removes and returns the auth session, a session can be used only once.
+    pub fn take_session(&mut self, session_id: &str) -> Result<AuthSession, super::ActionError> {
+        match self.sessions.remove(session_id) {
+            Some(session) if session.is_expired() => Err(super::ActionError::new(
+                "NotAuthorizedException",
+                "Invalid session for the user, session is expired.",
+            )),
+            Some(session) => Ok(session),
+            None => Err(super::ActionError::new(
+                "NotAuthorizedException",
+                "Invalid session for the user.",
+            )),
+        }
+    }
+
+    /// Stores the authorization code and returns the code.
+    pub fn add_authorization_code(&mut self, code: AuthorizationCode) -> String {
+        self.authorization_codes.retain(|_, c| !c.is_expired());
+        let value = code.code.clone();
+        self.authorization_codes.insert(value.clone(), code);
+        value
+    }
+
+    /// Removes and returns the unexpired authorization code, a code can be used only once.
+    pub fn take_authorization_code(&mut self, code: &str) -> Option<AuthorizationCode> {
+        self.authorization_codes
+            .remove(code)
+            .filter(|code| !code.is_expired())
+    }
+
+    /// Checks that the user pool and the client exist.
+    pub fn check_resources(
+        &self,
+        user_pool_id: Option<&str>,
+        client_id: Option<&str>,
+    ) -> Result<(), super::ActionError> {
+        match (user_pool_id, client_id) {
+            (Some(pool_id), Some(client_id)) => {
+                self.user_pool(pool_id)?.client(client_id).map(|_| ())
+            }
+            (Some(pool_id), None) => self.user_pool(pool_id).map(|_| ()),
+            (None, Some(client_id)) => self.find_client(client_id).map(|_| ()),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+fn user_pool_not_found(user_pool_id: &str) -> super::ActionError {
+    super::ActionError::resource_not_found(&format!("User pool {} does not exist.", user_pool_id))
+}
+
+/// Marks the store locked by the current thread until dropped.
+struct LockGuard;
+
+impl LockGuard {
+    fn new() -> Self {
+        if STORE_LOCKED.with(|locked| locked.replace(true)) {
+            panic!("the store is already locked by this thread");
+        }
+        LockGuard
+    }
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        STORE_LOCKED.with(|locked| locked.set(false));
+    }
+}
+
+/// Runs a function with the locked global store.
+///
+/// The store must not be locked again by the function, triggers are invoked after it returns
+/// since their handlers may call this server.
+pub fn with_store<T, F>(f: F) -> T
+where
+    F: FnOnce(&mut Store) -> T,
+{
+    let _guard = LockGuard::new();
+    let mut store = STORE.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut store)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::{ActionError, CreateUserPoolClientRequest, CreateUserPoolRequest};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn add_and_remove_user() {
+        let mut pool = UserPool::new("user_pool_id", Default::default());
+        assert!(pool.add_user(User::new("username")).is_ok());
+        assert_eq!(
+            Err(ActionError::username_exists()),
+            pool.add_user(User::new("username")).map(|_| ())
+        );
+        assert!(pool.remove_user("username").is_ok());
+        assert_eq!(
+            Err(ActionError::user_not_found()),
+            pool.user("username").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn find_user_by_alias() {
+        let mut pool = UserPool::new(
+            "user_pool_id",
+            CreateUserPoolRequest {
+                alias_attributes: Some(vec!["email".to_string()]),
+                ..Default::default()
+            },
+        );
+        let mut user = User::new("username");
+        user.set_attributes(vec![("email".to_string(), "user@example.com".to_string())])
+            .unwrap();
+        pool.add_user(user).unwrap();
+        assert!(pool.user("user@example.com").is_err());
+
+        pool.user_mut("username")
+            .unwrap()
+            .set_attributes(vec![("email_verified".to_string(), "true".to_string())])
+            .unwrap();
+        assert_eq!("username", pool.user("user@example.com").unwrap().username);
+        assert!(pool.remove_user("user@example.com").is_ok());
+    }
+
+    #[test]
+    fn check_user_pool_and_client() {
+        let mut store = Store::default();
+        let pool_id = store
+            .add_user_pool(CreateUserPoolRequest::default())
+            .id
+            .clone();
+        let client_id = store
+            .user_pool_mut(&pool_id)
+            .unwrap()
+            .add_client(CreateUserPoolClientRequest::default())
+            .client_id
+            .clone();
+
+        assert!(store
+            .check_resources(Some(&pool_id), Some(&client_id))
+            .is_ok());
+        assert!(store.check_resources(None, Some(&client_id)).is_ok());
+        assert!(store
+            .check_resources(Some("us-east-1_unknown"), None)
+            .is_err());
+        assert!(store
+            .check_resources(Some(&pool_id), Some("unknown"))
+            .is_err());
+    }
+
+    #[test]
+    fn session_can_be_taken_once() {
+        let mut store = Store::default();
+        let session_id = store.add_session(AuthSession::new(
+            "user_pool_id",
+            "client_id",
+            "username",
+            "SMS_MFA",
+        ));
+        assert_eq!(
+            "username",
+            store.take_session(&session_id).unwrap().username
+        );
+        assert!(store.take_session(&session_id).is_err());
+    }
+
+    #[test]
+    fn groups_are_ordered_by_precedence() {
+        let mut pool = UserPool::new("user_pool_id", Default::default());
+        for (name, precedence) in &[("a", None), ("b", Some(2)), ("c", Some(1))] {
+            pool.add_group(Group {
+                precedence: *precedence,
+                ..Group::new(name)
+            })
+            .unwrap();
+        }
+        assert_eq!(
+            "GroupExistsException",
+            pool.add_group(Group::new("a")).unwrap_err().error
+        );
+        let mut user = User::new("username");
+        user.groups
+            .extend(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        pool.add_user(user).unwrap();
+        let names = |pool: &UserPool| {
+            pool.groups_of(pool.user("username").unwrap())
+                .iter()
+                .map(|group| group.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["c", "b", "a"], names(&pool));
+        pool.remove_group("b").unwrap();
+        assert_eq!(vec!["c", "a"], names(&pool));
+        assert!(!pool.user("username").unwrap().groups.contains("b"));
+    }
+
+    #[test]
+    #[should_panic(expected = "the store is already locked by this thread")]
+    fn store_cannot_be_locked_again() {
+        with_store(|_| with_store(|_| ()));
+    }
+
+    #[test]
+    fn store_can_be_locked_after_unlocked() {
+        with_store(|_| ());
+        with_store(|_| ());
+    }
+}
@@ -0,0 +1,81 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use sha2::{Digest, Sha256};
+
+const CODE_VALIDITY_MINUTES: i64 = 5;
+
+/// Authorization code issued by the hosted UI sign-in, exchanged once for tokens.
+#[derive(Debug, Clone)]
+pub struct AuthorizationCode {
+    pub code: String,
+    pub user_pool_id: String,
+    pub client_id: String,
+    pub username: String,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    /// PKCE code challenge and its method (`S256` or `plain`).
+    pub code_challenge: Option<(String, String)>,
+    pub nonce: Option<String>,
+    pub expiration_date: DateTime<Utc>,
+}
+
+impl AuthorizationCode {
+    pub fn new(user_pool_id: &str, client_id: &str, username: &str, redirect_uri: &str) -> Self {
+        AuthorizationCode {
+            code: uuid::Uuid::new_v4().to_string(),
+            user_pool_id: user_pool_id.to_string(),
+            client_id: client_id.to_string(),
+            username: username.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            scopes: vec![],
+            code_challenge: None,
+            nonce: None,
+            expiration_date: Utc::now() + Duration::minutes(CODE_VALIDITY_MINUTES),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expiration_date < Utc::now()
+    }
+
+    /// Checks the PKCE code verifier against the code challenge, any verifier is accepted
+    /// when the authorization request had no code challenge.
+    pub fn verify_code_verifier(&self, code_verifier: Option<&str>) -> bool {
+        match (self.code_challenge.as_ref(), code_verifier) {
+            (None, _) => true,
+            (Some((challenge, method)), Some(verifier)) if method == "S256" => {
+                URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes())) == *challenge
+            }
+            (Some((challenge, _)), Some(verifier)) => verifier == challenge,
+            (Some(_), None) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_s256_code_verifier() {
+        // Example of RFC 7636 Appendix B
+        let mut code = AuthorizationCode::new("user_pool_id", "client_id", "username", "uri");
+        code.code_challenge = Some((
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM".to_string(),
+            "S256".to_string(),
+        ));
+        assert!(code.verify_code_verifier(Some("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk")));
+        assert!(!code.verify_code_verifier(Some("invalid")));
+        assert!(!code.verify_code_verifier(None));
+    }
+
+    #[test]
+    fn verify_plain_code_verifier() {
+        let mut code = AuthorizationCode::new("user_pool_id", "client_id", "username", "uri");
+        assert!(code.verify_code_verifier(None));
+        code.code_challenge = Some(("verifier".to_string(), "plain".to_string()));
+        assert!(code.verify_code_verifier(Some("verifier")));
+        assert!(!code.verify_code_verifier(Some("other")));
+    }
+}
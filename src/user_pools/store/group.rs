@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Stored group.
+#[derive(Debug, Clone)]
+pub struct Group {
+    pub name: String,
+    pub description: Option<String>,
+    /// Lower value has priority for `cognito:preferred_role` claim.
+    pub precedence: Option<i64>,
+    pub role_arn: Option<String>,
+    pub creation_date: DateTime<Utc>,
+    pub last_modified_date: DateTime<Utc>,
+}
+
+/// Serializable group, same as GroupType.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct GroupType {
+    pub creation_date: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub group_name: String,
+    pub last_modified_date: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precedence: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_arn: Option<String>,
+    pub user_pool_id: String,
+}
+
+impl Group {
+    pub fn new(name: &str) -> Self {
+        let now = Utc::now();
+        Group {
+            name: name.to_string(),
+            description: None,
+            precedence: None,
+            role_arn: None,
+            creation_date: now,
+            last_modified_date: now,
+        }
+    }
+
+    /// Updates last modified date.
+    pub fn touch(&mut self) {
+        self.last_modified_date = Utc::now();
+    }
+
+    pub fn to_group_type(&self, user_pool_id: &str) -> GroupType {
+        GroupType {
+            creation_date: self.creation_date.timestamp(),
+            description: self.description.clone(),
+            group_name: self.name.clone(),
+            last_modified_date: self.last_modified_date.timestamp(),
+            precedence: self.precedence,
+            role_arn: self.role_arn.clone(),
+            user_pool_id: user_pool_id.to_string(),
+        }
+    }
+}
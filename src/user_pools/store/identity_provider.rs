@@ -0,0 +1,52 @@
+use crate::common;
+use crate::user_pools::CreateIdentityProviderRequest;
+use chrono::{DateTime, Utc};
+
+/// Stored identity provider.
+#[derive(Debug)]
+pub struct IdentityProvider {
+    pub config: CreateIdentityProviderRequest,
+    pub creation_date: DateTime<Utc>,
+    pub last_modified_date: DateTime<Utc>,
+}
+
+impl IdentityProvider {
+    pub fn new(config: CreateIdentityProviderRequest) -> Self {
+        let now = Utc::now();
+        IdentityProvider {
+            config,
+            creation_date: now,
+            last_modified_date: now,
+        }
+    }
+
+    pub fn provider_name(&self) -> &str {
+        self.config.provider_name.as_deref().unwrap_or_default()
+    }
+
+    /// Returns serializable identity provider, same as IdentityProviderType.
+    pub fn to_identity_provider_type(&self) -> serde_json::Value {
+        let mut value =
+            common::without_nulls(serde_json::to_value(&self.config).unwrap_or_default());
+        let map = value.as_object_mut().unwrap();
+        map.insert(
+            "CreationDate".to_string(),
+            serde_json::json!(self.creation_date.timestamp()),
+        );
+        map.insert(
+            "LastModifiedDate".to_string(),
+            serde_json::json!(self.last_modified_date.timestamp()),
+        );
+        value
+    }
+
+    /// Returns serializable summary, same as ProviderDescription.
+    pub fn to_provider_description(&self) -> serde_json::Value {
+        serde_json::json!({
+            "CreationDate": self.creation_date.timestamp(),
+            "LastModifiedDate": self.last_modified_date.timestamp(),
+            "ProviderName": self.config.provider_name,
+            "ProviderType": self.config.provider_type,
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use crate::common;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+const SESSION_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const SESSION_LENGTH: usize = 256;
+const SESSION_VALIDITY_MINUTES: i64 = 3;
+
+/// Auth session between a challenge and its response.
+#[derive(Debug, Clone)]
+pub struct AuthSession {
+    pub id: String,
+    pub user_pool_id: String,
+    pub client_id: String,
+    pub username: String,
+    pub challenge_name: String,
+    /// Flow specific state carried to the next challenge response.
+    pub parameters: HashMap<String, String>,
+    pub expiration_date: DateTime<Utc>,
+}
+
+impl AuthSession {
+    pub fn new(user_pool_id: &str, client_id: &str, username: &str, challenge_name: &str) -> Self {
+        AuthSession {
+            id: common::random_string(SESSION_CHARS, SESSION_LENGTH),
+            user_pool_id: user_pool_id.to_string(),
+            client_id: client_id.to_string(),
+            username: username.to_string(),
+            challenge_name: challenge_name.to_string(),
+            parameters: HashMap::new(),
+            expiration_date: Utc::now() + Duration::minutes(SESSION_VALIDITY_MINUTES),
+        }
+    }
+
+    /// Renews the id and the expiration of the session to continue it with the next step.
+    pub fn renew(&mut self) {
+        self.id = common::random_string(SESSION_CHARS, SESSION_LENGTH);
+        self.expiration_date = Utc::now() + Duration::minutes(SESSION_VALIDITY_MINUTES);
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expiration_date < Utc::now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new_session_is_not_expired() {
+        let session = AuthSession::new("user_pool_id", "client_id", "username", "SMS_MFA");
+        assert_eq!(SESSION_LENGTH, session.id.len());
+        assert!(!session.is_expired());
+    }
+}
@@ -0,0 +1,301 @@
+use crate::user_pools::ActionError;
+use chrono::{DateTime, Duration, Utc};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use strum_macros::{Display, EnumString};
+
+pub const SUB_ATTRIBUTE: &str = "sub";
+const CODE_VALIDITY_HOURS: i64 = 24;
+
+/// User status, same as UserStatusType.
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+#[derive(Display, EnumString, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatus {
+    UNCONFIRMED,
+    CONFIRMED,
+    ARCHIVED,
+    COMPROMISED,
+    UNKNOWN,
+    RESET_REQUIRED,
+    FORCE_CHANGE_PASSWORD,
+}
+
+/// What a verification code is issued for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CodePurpose {
+    SignUp,
+    ForgotPassword,
+    VerifyAttribute(String),
+    /// Code of `SMS_MFA` challenge.
+    SmsMfa,
+}
+
+/// Issued verification code.
+#[derive(Debug, Clone)]
+pub struct VerificationCode {
+    pub code: String,
+    /// Attribute the code was delivered to.
+    pub attribute_name: Option<String>,
+    pub expiration_date: DateTime<Utc>,
+}
+
+/// Software token (TOTP) associated with the user.
+#[derive(Debug, Clone)]
+pub struct SoftwareToken {
+    /// Base32 encoded secret.
+    pub secret: String,
+    pub verified: bool,
+    pub friendly_device_name: Option<String>,
+}
+
+/// Stored user.
+#[derive(Debug, Clone)]
+pub struct User {
+    pub username: String,
+    pub attributes: BTreeMap<String, String>,
+    pub password: Option<String>,
+    /// When the password was last set.
+    pub password_date: DateTime<Utc>,
+    pub status: UserStatus,
+    pub enabled: bool,
+    pub codes: HashMap<CodePurpose, VerificationCode>,
+    pub software_token: Option<SoftwareToken>,
+    /// Enabled MFA types, same as UserMFASettingList.
+    pub mfa_settings: Vec<String>,
+    pub preferred_mfa: Option<String>,
+    /// Origins (`origin_jti`) of issued tokens which are not revoked.
+    pub origin_jtis: HashSet<String>,
+    /// Names of the groups the user belongs to.
+    pub groups: BTreeSet<String>,
+    pub create_date: DateTime<Utc>,
+    pub last_modified_date: DateTime<Utc>,
+}
+
+/// Serializable user, same as UserType.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserType {
+    pub attributes: Vec<AttributeValue>,
+    pub enabled: bool,
+    pub user_create_date: i64,
+    pub user_last_modified_date: i64,
+    pub user_status: UserStatus,
+    pub username: String,
+}
+
+/// Serializable attribute, same as AttributeType.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AttributeValue {
+    pub name: String,
+    pub value: String,
+}
+
+impl User {
+    /// Returns a new confirmed user with generated `sub` attribute.
+    pub fn new(username: &str) -> Self {
+        let now = Utc::now();
+        let mut attributes = BTreeMap::new();
+        attributes.insert(SUB_ATTRIBUTE.to_string(), uuid::Uuid::new_v4().to_string());
+        User {
+            username: username.to_string(),
+            attributes,
+            password: None,
+            password_date: now,
+            status: UserStatus::CONFIRMED,
+            enabled: true,
+            codes: HashMap::new(),
+            software_token: None,
+            mfa_settings: Vec::new(),
+            preferred_mfa: None,
+            origin_jtis: HashSet::new(),
+            groups: BTreeSet::new(),
+            create_date: now,
+            last_modified_date: now,
+        }
+    }
+
+    pub fn sub(&self) -> &str {
+        &self.attributes[SUB_ATTRIBUTE]
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&String> {
+        self.attributes.get(name)
+    }
+
+    /// Sets attributes, `sub` is never overwritten.
+    pub fn set_attributes<I>(&mut self, attributes: I) -> Result<(), ActionError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        for (name, value) in attributes {
+            if name == SUB_ATTRIBUTE {
+                return Err(ActionError::invalid_parameter(
+                    "Cannot modify the non-mutable attribute sub",
+                ));
+            }
+            self.attributes.insert(name, value);
+        }
+        self.touch();
+        Ok(())
+    }
+
+    pub fn delete_attributes(&mut self, names: &[String]) -> Result<(), ActionError> {
+        if names.iter().any(|n| n == SUB_ATTRIBUTE) {
+            return Err(ActionError::invalid_parameter(
+                "Cannot delete the non-mutable attribute sub",
+            ));
+        }
+        for name in names {
+            self.attributes.remove(name);
+        }
+        self.touch();
+        Ok(())
+    }
+
+    /// Issues a new 6 digit code for the purpose, replacing the previous one.
+    pub fn issue_code(&mut self, purpose: CodePurpose, attribute_name: Option<&str>) -> String {
+        let code = format!("{:06}", rand::thread_rng().gen_range(0..1_000_000));
+        self.codes.insert(
+            purpose,
+            VerificationCode {
+                code: code.clone(),
+                attribute_name: attribute_name.map(str::to_string),
+                expiration_date: Utc::now() + Duration::hours(CODE_VALIDITY_HOURS),
+            },
+        );
+        code
+    }
+
+    /// Verifies the code for the purpose, a verified code is expired.
+    pub fn verify_code(
+        &mut self,
+        purpose: &CodePurpose,
+        code: &str,
+    ) -> Result<VerificationCode, ActionError> {
+        let expired = || {
+            ActionError::new(
+                "ExpiredCodeException",
+                "Invalid code provided, please request a code again.",
+            )
+        };
+        match self.codes.get(purpose) {
+            None => Err(expired()),
+            Some(issued) if issued.expiration_date < Utc::now() => {
+                self.codes.remove(purpose);
+                Err(expired())
+            }
+            Some(issued) if issued.code != code => Err(ActionError::new(
+                "CodeMismatchException",
+                "Invalid verification code provided, please try again.",
+            )),
+            Some(_) => Ok(self.codes.remove(purpose).unwrap()),
+        }
+    }
+
+    pub fn set_password(&mut self, password: &str) {
+        self.password = Some(password.to_string());
+        self.password_date = Utc::now();
+        self.touch();
+    }
+
+    /// Updates last modified date.
+    pub fn touch(&mut self) {
+        self.last_modified_date = Utc::now();
+    }
+
+    pub fn attribute_values(&self) -> Vec<AttributeValue> {
+        self.attributes
+            .iter()
+            .map(|(name, value)| AttributeValue {
+                name: name.clone(),
+                value: value.clone(),
+            })
+            .collect()
+    }
+
+    pub fn to_user_type(&self) -> UserType {
+        UserType {
+            attributes: self.attribute_values(),
+            enabled: self.enabled,
+            user_create_date: self.create_date.timestamp(),
+            user_last_modified_date: self.last_modified_date.timestamp(),
+            user_status: self.status,
+            username: self.username.clone(),
+        }
+    }
+}
+
+/// Generates a random temporary password including lower, upper, number and symbol characters.
+pub fn generate_temporary_password() -> String {
+    const LOWERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+    const UPPERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    const NUMBERS: &[u8] = b"0123456789";
+    const SYMBOLS: &[u8] = b"^$*.[]{}()?-!@#%&/,><:;|_~";
+    let mut rng = rand::thread_rng();
+    let mut chars: Vec<u8> = [LOWERS, UPPERS, NUMBERS, SYMBOLS]
+        .iter()
+        .map(|set| set[rng.gen_range(0..set.len())])
+        .collect();
+    let all = [LOWERS, UPPERS, NUMBERS].concat();
+    chars.extend((0..8).map(|_| all[rng.gen_range(0..all.len())]));
+    chars.shuffle(&mut rng);
+    String::from_utf8(chars).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new_user_has_sub() {
+        let user = User::new("username");
+        assert_eq!(36, user.sub().len());
+    }
+
+    #[test]
+    fn cannot_modify_sub() {
+        let mut user = User::new("username");
+        assert!(user
+            .set_attributes(vec![("sub".to_string(), "value".to_string())])
+            .is_err());
+        assert!(user.delete_attributes(&["sub".to_string()]).is_err());
+    }
+
+    #[test]
+    fn verify_issued_code() {
+        let mut user = User::new("username");
+        let code = user.issue_code(CodePurpose::SignUp, Some("email"));
+        assert_eq!(6, code.len());
+        assert_eq!(
+            "CodeMismatchException",
+            user.verify_code(&CodePurpose::SignUp, "wrong")
+                .unwrap_err()
+                .error
+        );
+        assert_eq!(
+            Some("email".to_string()),
+            user.verify_code(&CodePurpose::SignUp, &code)
+                .unwrap()
+                .attribute_name
+        );
+        assert_eq!(
+            "ExpiredCodeException",
+            user.verify_code(&CodePurpose::SignUp, &code)
+                .unwrap_err()
+                .error
+        );
+    }
+
+    #[test]
+    fn temporary_password_has_all_character_types() {
+        let password = generate_temporary_password();
+        assert_eq!(12, password.len());
+        assert!(password.chars().any(|c| c.is_ascii_lowercase()));
+        assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+        assert!(password.chars().any(|c| c.is_ascii_digit()));
+    }
+}
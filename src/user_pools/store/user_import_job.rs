@@ -0,0 +1,48 @@
+use crate::common;
+use crate::user_pools::CreateUserImportJobRequest;
+use chrono::{DateTime, Utc};
+
+const JOB_ID_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Stored user import job, jobs are never started.
+#[derive(Debug)]
+pub struct UserImportJob {
+    pub job_id: String,
+    pub config: CreateUserImportJobRequest,
+    pub creation_date: DateTime<Utc>,
+}
+
+impl UserImportJob {
+    /// Returns a new job with generated id (e.g. `import-abc123def4`).
+    pub fn new(config: CreateUserImportJobRequest) -> Self {
+        UserImportJob {
+            job_id: format!("import-{}", common::random_string(JOB_ID_CHARS, 10)),
+            config,
+            creation_date: Utc::now(),
+        }
+    }
+
+    /// Returns serializable job, same as UserImportJobType.
+    pub fn to_user_import_job_type(&self, region: &str) -> serde_json::Value {
+        let mut value =
+            common::without_nulls(serde_json::to_value(&self.config).unwrap_or_default());
+        let map = value.as_object_mut().unwrap();
+        map.insert("JobId".to_string(), serde_json::json!(self.job_id));
+        map.insert(
+            "PreSignedUrl".to_string(),
+            serde_json::json!(format!(
+                "https://cognito-idp-import-{}.s3.amazonaws.com/{}",
+                region, self.job_id
+            )),
+        );
+        map.insert(
+            "CreationDate".to_string(),
+            serde_json::json!(self.creation_date.timestamp()),
+        );
+        map.insert("Status".to_string(), serde_json::json!("Created"));
+        for count in &["ImportedUsers", "SkippedUsers", "FailedUsers"] {
+            map.insert(count.to_string(), serde_json::json!(0));
+        }
+        value
+    }
+}
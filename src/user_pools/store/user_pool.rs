@@ -0,0 +1,307 @@
+use super::{Group, IdentityProvider, User, UserImportJob, UserPoolClient};
+use crate::common;
+use crate::user_pools::{
+    ActionError, CreateResourceServerRequest, CreateUserPoolClientRequest, CreateUserPoolRequest,
+};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+
+const DEFAULT_REGION: &str = "us-east-1";
+const ACCOUNT_ID: &str = "123456789012";
+const PREFERRED_USERNAME_ATTRIBUTE: &str = "preferred_username";
+const ID_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const CUSTOM_ATTRIBUTE_PREFIX: &str = "custom:";
+const STANDARD_ATTRIBUTES: [&str; 20] = [
+    "address",
+    "birthdate",
+    "email",
+    "email_verified",
+    "family_name",
+    "gender",
+    "given_name",
+    "locale",
+    "middle_name",
+    "name",
+    "nickname",
+    "phone_number",
+    "phone_number_verified",
+    "picture",
+    "preferred_username",
+    "profile",
+    "sub",
+    "updated_at",
+    "website",
+    "zoneinfo",
+];
+
+/// Stored user pool.
+#[derive(Debug)]
+pub struct UserPool {
+    pub id: String,
+    pub config: CreateUserPoolRequest,
+    pub users: HashMap<String, User>,
+    pub clients: HashMap<String, UserPoolClient>,
+    pub groups: BTreeMap<String, Group>,
+    pub identity_providers: BTreeMap<String, IdentityProvider>,
+    /// Resource servers by identifier, same as ResourceServerType.
+    pub resource_servers: BTreeMap<String, CreateResourceServerRequest>,
+    pub user_import_jobs: BTreeMap<String, UserImportJob>,
+    pub creation_date: DateTime<Utc>,
+    pub last_modified_date: DateTime<Utc>,
+}
+
+/// Generates a user pool id (e.g. `us-east-1_AbCdE1234`).
+pub fn generate_user_pool_id() -> String {
+    let region = crate::opts::get_opt_region().unwrap_or_else(|| DEFAULT_REGION.to_string());
+    format!("{}_{}", region, common::random_string(ID_CHARS, 9))
+}
+
+impl UserPool {
+    pub fn new(id: &str, config: CreateUserPoolRequest) -> Self {
+        let now = Utc::now();
+        UserPool {
+            id: id.to_string(),
+            config,
+            users: HashMap::new(),
+            clients: HashMap::new(),
+            groups: BTreeMap::new(),
+            identity_providers: BTreeMap::new(),
+            resource_servers: BTreeMap::new(),
+            user_import_jobs: BTreeMap::new(),
+            creation_date: now,
+            last_modified_date: now,
+        }
+    }
+
+    /// Updates last modified date.
+    pub fn touch(&mut self) {
+        self.last_modified_date = Utc::now();
+    }
+
+    /// Returns region part of the user pool id.
+    pub fn region(&self) -> &str {
+        self.id.split('_').next().unwrap_or(DEFAULT_REGION)
+    }
+
+    pub fn arn(&self) -> String {
+        format!(
+            "arn:aws:cognito-idp:{}:{}:userpool/{}",
+            self.region(),
+            ACCOUNT_ID,
+            self.id
+        )
+    }
+
+    /// Returns the attribute name of the schema, custom attributes are prefixed with `custom:`.
+    fn schema_attribute_name(name: &str) -> String {
+        if STANDARD_ATTRIBUTES.contains(&name) || name.starts_with(CUSTOM_ATTRIBUTE_PREFIX) {
+            name.to_string()
+        } else {
+            format!("{}{}", CUSTOM_ATTRIBUTE_PREFIX, name)
+        }
+    }
+
+    /// Checks the attribute is a standard attribute or a custom attribute of the schema.
+    pub fn has_attribute(&self, name: &str) -> bool {
+        STANDARD_ATTRIBUTES.contains(&name)
+            || self
+                .config
+                .schema
+                .iter()
+                .flatten()
+                .filter_map(|attr| attr.name.as_deref())
+                .any(|attr| Self::schema_attribute_name(attr) == name)
+    }
+
+    /// Returns names of the required attributes of the schema.
+    pub fn required_attributes(&self) -> Vec<String> {
+        self.config
+            .schema
+            .iter()
+            .flatten()
+            .filter(|attr| attr.required.unwrap_or_default())
+            .filter_map(|attr| attr.name.as_deref())
+            .map(Self::schema_attribute_name)
+            .collect()
+    }
+
+    /// Returns the username of the user signing in with the username or an alias
+    /// (`UsernameAttributes`, or verified `AliasAttributes` of the user pool).
+    pub fn resolve_username(&self, name: &str) -> Option<&str> {
+        if let Some((username, _)) = self.users.get_key_value(name) {
+            return Some(username);
+        }
+        let username_attributes = self.config.username_attributes.iter().flatten();
+        let alias_attributes = self.config.alias_attributes.iter().flatten();
+        self.users
+            .values()
+            .find(|user| {
+                username_attributes
+                    .clone()
+                    .any(|attr| user.attribute(attr).map(String::as_str) == Some(name))
+                    || alias_attributes.clone().any(|attr| {
+                        user.attribute(attr).map(String::as_str) == Some(name)
+                            && (attr == PREFERRED_USERNAME_ATTRIBUTE
+                                || user
+                                    .attribute(&format!("{}_verified", attr))
+                                    .map(String::as_str)
+                                    == Some("true"))
+                    })
+            })
+            .map(|user| user.username.as_str())
+    }
+
+    /// Finds the user by the username or an alias.
+    pub fn user(&self, name: &str) -> Result<&User, ActionError> {
+        self.resolve_username(name)
+            .and_then(|username| self.users.get(username))
+            .ok_or_else(ActionError::user_not_found)
+    }
+
+    /// Finds the user by the username or an alias.
+    pub fn user_mut(&mut self, name: &str) -> Result<&mut User, ActionError> {
+        let username = self
+            .resolve_username(name)
+            .ok_or_else(ActionError::user_not_found)?
+            .to_string();
+        Ok(self.users.get_mut(&username).unwrap())
+    }
+
+    pub fn add_user(&mut self, user: User) -> Result<&User, ActionError> {
+        if self.users.contains_key(&user.username) {
+            return Err(ActionError::username_exists());
+        }
+        let username = user.username.clone();
+        self.users.insert(username.clone(), user);
+        Ok(&self.users[&username])
+    }
+
+    pub fn remove_user(&mut self, name: &str) -> Result<User, ActionError> {
+        let username = self
+            .resolve_username(name)
+            .ok_or_else(ActionError::user_not_found)?
+            .to_string();
+        Ok(self.users.remove(&username).unwrap())
+    }
+
+    pub fn client(&self, client_id: &str) -> Result<&UserPoolClient, ActionError> {
+        self.clients.get(client_id).ok_or_else(|| {
+            ActionError::resource_not_found(&format!(
+                "User pool client {} does not exist.",
+                client_id
+            ))
+        })
+    }
+
+    pub fn client_mut(&mut self, client_id: &str) -> Result<&mut UserPoolClient, ActionError> {
+        self.clients.get_mut(client_id).ok_or_else(|| {
+            ActionError::resource_not_found(&format!(
+                "User pool client {} does not exist.",
+                client_id
+            ))
+        })
+    }
+
+    pub fn add_client(&mut self, config: CreateUserPoolClientRequest) -> &UserPoolClient {
+        let client = UserPoolClient::new(config);
+        let client_id = client.client_id.clone();
+        self.clients.insert(client_id.clone(), client);
+        &self.clients[&client_id]
+    }
+
+    pub fn remove_client(&mut self, client_id: &str) -> Result<UserPoolClient, ActionError> {
+        self.clients.remove(client_id).ok_or_else(|| {
+            ActionError::resource_not_found(&format!(
+                "User pool client {} does not exist.",
+                client_id
+            ))
+        })
+    }
+
+    pub fn group(&self, group_name: &str) -> Result<&Group, ActionError> {
+        self.groups
+            .get(group_name)
+            .ok_or_else(|| ActionError::resource_not_found("Group not found."))
+    }
+
+    pub fn group_mut(&mut self, group_name: &str) -> Result<&mut Group, ActionError> {
+        self.groups
+            .get_mut(group_name)
+            .ok_or_else(|| ActionError::resource_not_found("Group not found."))
+    }
+
+    pub fn add_group(&mut self, group: Group) -> Result<&Group, ActionError> {
+        if self.groups.contains_key(&group.name) {
+            return Err(ActionError::new(
+                "GroupExistsException",
+                "A group with the name already exists.",
+            ));
+        }
+        let name = group.name.clone();
+        self.groups.insert(name.clone(), group);
+        Ok(&self.groups[&name])
+    }
+
+    /// Removes the group and its memberships.
+    pub fn remove_group(&mut self, group_name: &str) -> Result<Group, ActionError> {
+        let group = self
+            .groups
+            .remove(group_name)
+            .ok_or_else(|| ActionError::resource_not_found("Group not found."))?;
+        for user in self.users.values_mut() {
+            user.groups.remove(group_name);
+        }
+        Ok(group)
+    }
+
+    /// Returns the groups of the user ordered by precedence, groups without precedence are last.
+    pub fn groups_of(&self, user: &User) -> Vec<&Group> {
+        let mut groups = user
+            .groups
+            .iter()
+            .filter_map(|name| self.groups.get(name))
+            .collect::<Vec<_>>();
+        groups.sort_by_key(|group| group.precedence.unwrap_or(i64::MAX));
+        groups
+    }
+
+    /// Returns serializable user pool, same as UserPoolType.
+    pub fn to_user_pool_type(&self) -> serde_json::Value {
+        let mut value =
+            common::without_nulls(serde_json::to_value(&self.config).unwrap_or_default());
+        let map = value.as_object_mut().unwrap();
+        if let Some(name) = map.remove("PoolName") {
+            map.insert("Name".to_string(), name);
+        }
+        if let Some(schema) = map.remove("Schema") {
+            map.insert("SchemaAttributes".to_string(), schema);
+        }
+        map.entry("MfaConfiguration")
+            .or_insert_with(|| serde_json::json!("OFF"));
+        map.insert("Id".to_string(), serde_json::json!(self.id));
+        map.insert("Arn".to_string(), serde_json::json!(self.arn()));
+        map.insert(
+            "CreationDate".to_string(),
+            serde_json::json!(self.creation_date.timestamp()),
+        );
+        map.insert(
+            "LastModifiedDate".to_string(),
+            serde_json::json!(self.last_modified_date.timestamp()),
+        );
+        map.insert(
+            "EstimatedNumberOfUsers".to_string(),
+            serde_json::json!(self.users.len()),
+        );
+        value
+    }
+    /// Returns serializable user pool summary, same as UserPoolDescriptionType.
+    pub fn to_user_pool_description_type(&self) -> serde_json::Value {
+        common::without_nulls(serde_json::json!({
+            "CreationDate": self.creation_date.timestamp(),
+            "Id": self.id,
+            "LambdaConfig": self.config.lambda_config,
+            "LastModifiedDate": self.last_modified_date.timestamp(),
+            "Name": self.config.pool_name,
+        }))
+    }
+}
@@ -0,0 +1,153 @@
+use crate::common;
+use crate::user_pools::CreateUserPoolClientRequest;
+use chrono::{DateTime, Duration, Utc};
+
+const DEFAULT_TOKEN_VALIDITY_HOURS: i64 = 1;
+const DEFAULT_REFRESH_TOKEN_VALIDITY_DAYS: i64 = 30;
+const CLIENT_ID_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+const CLIENT_ID_LENGTH: usize = 26;
+const CLIENT_SECRET_LENGTH: usize = 51;
+
+/// Stored user pool client.
+#[derive(Debug)]
+pub struct UserPoolClient {
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub config: CreateUserPoolClientRequest,
+    pub creation_date: DateTime<Utc>,
+    pub last_modified_date: DateTime<Utc>,
+}
+
+impl UserPoolClient {
+    /// Returns a new client with generated id (and secret if `GenerateSecret` is set).
+    pub fn new(config: CreateUserPoolClientRequest) -> Self {
+        let now = Utc::now();
+        let client_secret = if config.generate_secret.unwrap_or(false) {
+            Some(common::random_string(CLIENT_ID_CHARS, CLIENT_SECRET_LENGTH))
+        } else {
+            None
+        };
+        UserPoolClient {
+            client_id: common::random_string(CLIENT_ID_CHARS, CLIENT_ID_LENGTH),
+            client_secret,
+            config,
+            creation_date: now,
+            last_modified_date: now,
+        }
+    }
+
+    /// Updates last modified date.
+    pub fn touch(&mut self) {
+        self.last_modified_date = Utc::now();
+    }
+
+    pub fn access_token_validity(&self) -> Duration {
+        let units = self.config.token_validity_units.as_ref();
+        token_validity(
+            self.config.access_token_validity,
+            units.and_then(|u| u.access_token.as_deref()),
+            DEFAULT_TOKEN_VALIDITY_HOURS,
+            "hours",
+        )
+    }
+
+    pub fn id_token_validity(&self) -> Duration {
+        let units = self.config.token_validity_units.as_ref();
+        token_validity(
+            self.config.id_token_validity,
+            units.and_then(|u| u.id_token.as_deref()),
+            DEFAULT_TOKEN_VALIDITY_HOURS,
+            "hours",
+        )
+    }
+
+    pub fn refresh_token_validity(&self) -> Duration {
+        let units = self.config.token_validity_units.as_ref();
+        token_validity(
+            self.config.refresh_token_validity,
+            units.and_then(|u| u.refresh_token.as_deref()),
+            DEFAULT_REFRESH_TOKEN_VALIDITY_DAYS,
+            "days",
+        )
+    }
+
+    pub fn user_pool_id(&self) -> &str {
+        self.config.user_pool_id.as_deref().unwrap_or_default()
+    }
+
+    /// Returns serializable user pool client, same as UserPoolClientType.
+    pub fn to_user_pool_client_type(&self) -> serde_json::Value {
+        let mut value =
+            common::without_nulls(serde_json::to_value(&self.config).unwrap_or_default());
+        let map = value.as_object_mut().unwrap();
+        map.remove("GenerateSecret");
+        map.insert("ClientId".to_string(), serde_json::json!(self.client_id));
+        if let Some(secret) = self.client_secret.as_ref() {
+            map.insert("ClientSecret".to_string(), serde_json::json!(secret));
+        }
+        map.insert(
+            "CreationDate".to_string(),
+            serde_json::json!(self.creation_date.timestamp()),
+        );
+        map.insert(
+            "LastModifiedDate".to_string(),
+            serde_json::json!(self.last_modified_date.timestamp()),
+        );
+        value
+    }
+}
+
+/// Returns token validity duration with a time unit (seconds, minutes, hours or days).
+fn token_validity(
+    value: Option<i64>,
+    unit: Option<&str>,
+    default_value: i64,
+    default_unit: &str,
+) -> Duration {
+    let value = value.unwrap_or(default_value);
+    match unit.unwrap_or(default_unit) {
+        "seconds" => Duration::seconds(value),
+        "minutes" => Duration::minutes(value),
+        "hours" => Duration::hours(value),
+        _ => Duration::days(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn token_validity_with_units() {
+        let client = UserPoolClient::new(CreateUserPoolClientRequest::default());
+        assert_eq!(Duration::hours(1), client.access_token_validity());
+        assert_eq!(Duration::days(30), client.refresh_token_validity());
+
+        let client = UserPoolClient::new(CreateUserPoolClientRequest {
+            access_token_validity: Some(5),
+            token_validity_units: Some(crate::user_pools::TokenValidityUnitsType {
+                access_token: Some("minutes".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        assert_eq!(Duration::minutes(5), client.access_token_validity());
+    }
+
+    #[test]
+    fn generates_secret_only_when_requested() {
+        let client = UserPoolClient::new(CreateUserPoolClientRequest::default());
+        assert_eq!(CLIENT_ID_LENGTH, client.client_id.len());
+        assert!(client.client_secret.is_none());
+
+        let client = UserPoolClient::new(CreateUserPoolClientRequest {
+            generate_secret: Some(true),
+            ..Default::default()
+        });
+        assert_eq!(
+            Some(CLIENT_SECRET_LENGTH),
+            client.client_secret.map(|s| s.len())
+        );
+    }
+}
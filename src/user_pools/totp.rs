@@ -0,0 +1,124 @@
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha1::Sha1;
+
+const BASE32_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const SECRET_BYTES: usize = 20;
+const TIME_STEP_SECONDS: i64 = 30;
+const CODE_DIGITS: u32 = 6;
+/// Accepted time steps before and after the current one.
+const WINDOW: i64 = 1;
+
+/// Encodes bytes in Base32 (RFC 4648) without padding.
+pub fn base32_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(5) {
+        let mut buffer = [0u8; 5];
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        let bits = buffer.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+        let chars = (chunk.len() * 8).div_ceil(5);
+        for i in 0..chars {
+            let index = (bits >> (35 - i * 5)) & 0x1f;
+            encoded.push(BASE32_CHARS[index as usize] as char);
+        }
+    }
+    encoded
+}
+
+/// Decodes Base32 (RFC 4648), padding and case are ignored.
+pub fn base32_decode(value: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut bits = 0u64;
+    let mut bit_count = 0;
+    for c in value.trim_end_matches('=').chars() {
+        let index = BASE32_CHARS
+            .iter()
+            .position(|b| *b as char == c.to_ascii_uppercase())?;
+        bits = (bits << 5) | index as u64;
+        bit_count += 5;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            bytes.push((bits >> bit_count) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Generates a Base32 secret for software token MFA.
+pub fn generate_totp_secret() -> String {
+    let mut bytes = vec![0u8; SECRET_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    base32_encode(&bytes)
+}
+
+/// Returns the current unix time, or the fixed time given by `--totp-time`.
+fn totp_now() -> i64 {
+    crate::opts::get_opt_totp_time().unwrap_or_else(|| chrono::Utc::now().timestamp())
+}
+
+/// Returns the TOTP code (RFC 6238, HMAC-SHA1 and 30 seconds step) of the secret at the time.
+pub fn totp_code(secret: &str, time: i64) -> Option<String> {
+    let key = base32_decode(secret)?;
+    let counter = (time / TIME_STEP_SECONDS) as u64;
+    let mut mac = Hmac::<Sha1>::new_from_slice(&key).ok()?;
+    mac.update(&counter.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    Some(format!(
+        "{:0width$}",
+        binary % 10u32.pow(CODE_DIGITS),
+        width = CODE_DIGITS as usize
+    ))
+}
+
+/// Verifies the TOTP code of the secret, allowing one time step of clock drift.
+pub fn verify_totp(secret: &str, code: &str) -> bool {
+    let now = totp_now();
+    (-WINDOW..=WINDOW)
+        .any(|step| totp_code(secret, now + step * TIME_STEP_SECONDS).as_deref() == Some(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn base32_round_trip() {
+        assert_eq!("MZXW6YTBOI", base32_encode(b"foobar"));
+        assert_eq!(Some(b"foobar".to_vec()), base32_decode("MZXW6YTBOI======"));
+        assert_eq!(Some(b"foobar".to_vec()), base32_decode("mzxw6ytboi"));
+        assert_eq!(None, base32_decode("1"));
+        assert_eq!(32, generate_totp_secret().len());
+    }
+
+    #[test]
+    fn totp_code_of_rfc6238() {
+        // test vectors of RFC 6238 (SHA1), truncated to 6 digits
+        let secret = base32_encode(b"12345678901234567890");
+        assert_eq!(Some("287082".to_string()), totp_code(&secret, 59));
+        assert_eq!(Some("081804".to_string()), totp_code(&secret, 1111111109));
+        assert_eq!(Some("005924".to_string()), totp_code(&secret, 1234567890));
+    }
+
+    #[test]
+    fn verify_current_code() {
+        let secret = generate_totp_secret();
+        let now = chrono::Utc::now().timestamp();
+        assert!(verify_totp(&secret, &totp_code(&secret, now).unwrap()));
+        assert!(verify_totp(
+            &secret,
+            &totp_code(&secret, now - TIME_STEP_SECONDS).unwrap()
+        ));
+        assert!(!verify_totp(
+            &secret,
+            &totp_code(&secret, now - 3 * TIME_STEP_SECONDS).unwrap()
+        ));
+    }
+}
@@ -0,0 +1,720 @@
+use super::{
+    ActionError, CodeDelivery, LambdaConfigType, MessageType, User, UserPool, UserStatus,
+    EMAIL_ATTRIBUTE, PHONE_NUMBER_ATTRIBUTE, SUB_ATTRIBUTE,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use strum_macros::Display;
+
+const AWS_SDK_VERSION: &str = "aws-sdk-unknown-unknown";
+const CLIENT_ID_NOT_APPLICABLE: &str = "CLIENT_ID_NOT_APPLICABLE";
+const USER_STATUS_ATTRIBUTE: &str = "cognito:user_status";
+
+pub type ClientMetadata = HashMap<String, String>;
+
+/// Caller of triggers invoked while sending messages, `callerContext.clientId` and
+/// `clientMetadata` of the events.
+#[derive(Debug, Default, Clone)]
+pub struct TriggerContext {
+    pub client_id: Option<String>,
+    pub client_metadata: ClientMetadata,
+}
+
+impl TriggerContext {
+    pub fn new(client_id: Option<&str>, client_metadata: Option<&ClientMetadata>) -> Self {
+        TriggerContext {
+            client_id: client_id.map(str::to_string),
+            client_metadata: client_metadata.cloned().unwrap_or_default(),
+        }
+    }
+}
+
+/// Lambda trigger of the user pool, same as the fields of LambdaConfigType.
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    PreSignUp,
+    PostConfirmation,
+    PreAuthentication,
+    PostAuthentication,
+    PreTokenGeneration,
+    UserMigration,
+    CustomMessage,
+    DefineAuthChallenge,
+    CreateAuthChallenge,
+    VerifyAuthChallengeResponse,
+}
+
+impl Trigger {
+    fn arn(self, config: &LambdaConfigType) -> Option<&String> {
+        match self {
+            Trigger::PreSignUp => config.pre_sign_up.as_ref(),
+            Trigger::PostConfirmation => config.post_confirmation.as_ref(),
+            Trigger::PreAuthentication => config.pre_authentication.as_ref(),
+            Trigger::PostAuthentication => config.post_authentication.as_ref(),
+            Trigger::PreTokenGeneration => config
+                .pre_token_generation_config
+                .as_ref()
+                .and_then(|c| c.lambda_arn.as_ref())
+                .or(config.pre_token_generation.as_ref()),
+            Trigger::UserMigration => config.user_migration.as_ref(),
+            Trigger::CustomMessage => config.custom_message.as_ref(),
+            Trigger::DefineAuthChallenge => config.define_auth_challenge.as_ref(),
+            Trigger::CreateAuthChallenge => config.create_auth_challenge.as_ref(),
+            Trigger::VerifyAuthChallengeResponse => config.verify_auth_challenge_response.as_ref(),
+        }
+    }
+}
+
+fn trigger_arn(pool: &UserPool, trigger: Trigger) -> Option<&String> {
+    pool.config
+        .lambda_config
+        .as_ref()
+        .and_then(|config| trigger.arn(config))
+}
+
+/// Returns `true` when the trigger is configured for the user pool.
+pub fn has_trigger(pool: &UserPool, trigger: Trigger) -> bool {
+    trigger_arn(pool, trigger).is_some()
+}
+
+/// Returns `userAttributes` of trigger events, including `cognito:user_status`.
+pub fn event_user_attributes(user: &User) -> Value {
+    let mut attributes = user
+        .attributes
+        .iter()
+        .map(|(name, value)| (name.clone(), Value::String(value.clone())))
+        .collect::<serde_json::Map<_, _>>();
+    attributes.insert(
+        USER_STATUS_ATTRIBUTE.to_string(),
+        Value::String(user.status.to_string()),
+    );
+    Value::Object(attributes)
+}
+
+fn invalid_lambda_response() -> ActionError {
+    ActionError::new(
+        "InvalidLambdaResponseException",
+        "Invalid lambda function output : Invalid JSON",
+    )
+}
+
+/// Returns `version` of the trigger event, `2` for V2_0 of PreTokenGeneration trigger.
+fn event_version(pool: &UserPool, trigger: Trigger) -> &'static str {
+    match trigger {
+        Trigger::PreTokenGeneration if pre_token_generation_version(pool) == "V2_0" => "2",
+        _ => "1",
+    }
+}
+
+/// Event of a trigger configured for the user pool.
+///
+/// Events are built with the locked store and invoked after it is unlocked,
+/// since handlers may call this server.
+#[derive(Debug)]
+pub struct TriggerEvent {
+    trigger: Trigger,
+    arn: String,
+    event: Value,
+}
+
+/// Returns Cognito's event of the trigger, or `None` when the trigger is not configured
+/// for the user pool.
+pub fn trigger_event(
+    pool: &UserPool,
+    trigger: Trigger,
+    trigger_source: &str,
+    client_id: Option<&str>,
+    username: &str,
+    request: Value,
+    response: Value,
+) -> Option<TriggerEvent> {
+    let arn = trigger_arn(pool, trigger)?;
+    let event = serde_json::json!({
+        "version": event_version(pool, trigger),
+        "triggerSource": trigger_source,
+        "region": pool.region(),
+        "userPoolId": pool.id,
+        "userName": username,
+        "callerContext": {
+            "awsSdkVersion": AWS_SDK_VERSION,
+            "clientId": client_id.unwrap_or(CLIENT_ID_NOT_APPLICABLE),
+        },
+        "request": request,
+        "response": response,
+    });
+    Some(TriggerEvent {
+        trigger,
+        arn: arn.clone(),
+        event,
+    })
+}
+
+impl TriggerEvent {
+    /// Invokes the handler of the trigger with the event and returns `response` of the result.
+    ///
+    /// Handlers are python scripts in `triggers` directory of hooks named by the lambda function.
+    pub fn invoke(&self) -> Result<Value, ActionError> {
+        let trigger = self.trigger;
+        log::debug!("invoke {} trigger {}: {}", trigger, self.arn, self.event);
+        let result = crate::hooks::call_trigger_hook(
+            &self.arn,
+            &self.event.to_string(),
+            crate::opts::get_opt_hooks().map(|o| o.as_ref()),
+        )
+        .map_err(|e| {
+            ActionError::new(
+                "UserLambdaValidationException",
+                &format!("{} failed with error {}.", trigger, e),
+            )
+        })?
+        .ok_or_else(|| {
+            ActionError::new(
+                "UnexpectedLambdaException",
+                &format!(
+                    "{} invocation failed due to error ResourceNotFoundException.",
+                    trigger
+                ),
+            )
+        })?;
+        let event: Value = serde_json::from_str(&result).map_err(|_| invalid_lambda_response())?;
+        match event.get("response") {
+            Some(response) if response.is_object() => Ok(response.clone()),
+            _ => Err(invalid_lambda_response()),
+        }
+    }
+}
+
+/// Invokes the trigger of the event, the store must not be locked.
+/// Returns `None` when the trigger is not configured.
+pub fn invoke_trigger(event: Option<TriggerEvent>) -> Result<Option<Value>, ActionError> {
+    event.map(|event| event.invoke()).transpose()
+}
+
+/// Builds the event of the trigger for the user with the locked store.
+fn user_trigger_event<F>(
+    user_pool_id: &str,
+    username: &str,
+    f: F,
+) -> Result<Option<TriggerEvent>, ActionError>
+where
+    F: FnOnce(&UserPool, &User) -> Option<TriggerEvent>,
+{
+    super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        Ok(f(pool, pool.user(username)?))
+    })
+}
+
+/// Response of PreSignUp trigger.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PreSignUpResponse {
+    pub auto_confirm_user: Option<bool>,
+    pub auto_verify_email: Option<bool>,
+    pub auto_verify_phone: Option<bool>,
+}
+
+impl PreSignUpResponse {
+    pub fn auto_confirm_user(&self) -> bool {
+        self.auto_confirm_user.unwrap_or_default()
+    }
+
+    /// Marks email and phone number of the user as verified by the response.
+    pub fn auto_verify(&self, user: &mut User) -> Result<(), ActionError> {
+        let verified = vec![
+            (self.auto_verify_email, EMAIL_ATTRIBUTE),
+            (self.auto_verify_phone, PHONE_NUMBER_ATTRIBUTE),
+        ]
+        .into_iter()
+        .filter(|(auto_verify, name)| *auto_verify == Some(true) && user.attribute(name).is_some())
+        .map(|(_, name)| (format!("{}_verified", name), "true".to_string()))
+        .collect::<Vec<_>>();
+        if verified.is_empty() {
+            return Ok(());
+        }
+        user.set_attributes(verified)
+    }
+}
+
+/// Invokes PreSignUp trigger for the user to be created.
+pub fn pre_sign_up(
+    user_pool_id: &str,
+    client_id: Option<&str>,
+    trigger_source: &str,
+    user: &User,
+    validation_data: &HashMap<String, String>,
+    client_metadata: &ClientMetadata,
+) -> Result<PreSignUpResponse, ActionError> {
+    let event = super::with_store(|store| {
+        Ok::<_, ActionError>(trigger_event(
+            store.user_pool(user_pool_id)?,
+            Trigger::PreSignUp,
+            trigger_source,
+            client_id,
+            &user.username,
+            serde_json::json!({
+                "userAttributes": user.attributes,
+                "validationData": validation_data,
+                "clientMetadata": client_metadata,
+            }),
+            serde_json::json!({
+                "autoConfirmUser": false,
+                "autoVerifyEmail": false,
+                "autoVerifyPhone": false,
+            }),
+        ))
+    })?;
+    invoke_trigger(event)?
+        .map(|r| serde_json::from_value(r).map_err(|_| invalid_lambda_response()))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Invokes PostConfirmation trigger for the confirmed user.
+pub fn post_confirmation(
+    user_pool_id: &str,
+    client_id: Option<&str>,
+    trigger_source: &str,
+    username: &str,
+    client_metadata: &ClientMetadata,
+) -> Result<(), ActionError> {
+    let event = user_trigger_event(user_pool_id, username, |pool, user| {
+        trigger_event(
+            pool,
+            Trigger::PostConfirmation,
+            trigger_source,
+            client_id,
+            &user.username,
+            serde_json::json!({
+                "userAttributes": event_user_attributes(user),
+                "clientMetadata": client_metadata,
+            }),
+            serde_json::json!({}),
+        )
+    })?;
+    invoke_trigger(event).map(|_| ())
+}
+
+/// Invokes PreAuthentication trigger before the user is authenticated,
+/// `ClientMetadata` of the request is passed as `validationData`.
+pub fn pre_authentication(
+    user_pool_id: &str,
+    client_id: &str,
+    username: &str,
+    client_metadata: &ClientMetadata,
+) -> Result<(), ActionError> {
+    let event = user_trigger_event(user_pool_id, username, |pool, user| {
+        trigger_event(
+            pool,
+            Trigger::PreAuthentication,
+            "PreAuthentication_Authentication",
+            Some(client_id),
+            &user.username,
+            serde_json::json!({
+                "userAttributes": event_user_attributes(user),
+                "validationData": client_metadata,
+                "userNotFound": false,
+            }),
+            serde_json::json!({}),
+        )
+    })?;
+    invoke_trigger(event).map(|_| ())
+}
+
+/// Invokes PostAuthentication trigger after the user is authenticated.
+pub fn post_authentication(
+    user_pool_id: &str,
+    client_id: &str,
+    username: &str,
+    client_metadata: &ClientMetadata,
+) -> Result<(), ActionError> {
+    let event = user_trigger_event(user_pool_id, username, |pool, user| {
+        trigger_event(
+            pool,
+            Trigger::PostAuthentication,
+            "PostAuthentication_Authentication",
+            Some(client_id),
+            &user.username,
+            serde_json::json!({
+                "userAttributes": event_user_attributes(user),
+                "newDeviceUsed": false,
+                "clientMetadata": client_metadata,
+            }),
+            serde_json::json!({}),
+        )
+    })?;
+    invoke_trigger(event).map(|_| ())
+}
+
+/// Response of UserMigration trigger.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserMigrationResponse {
+    pub user_attributes: Option<HashMap<String, String>>,
+    pub final_user_status: Option<String>,
+    pub message_action: Option<String>,
+    pub desired_delivery_mediums: Option<Vec<String>>,
+}
+
+/// Invokes UserMigration trigger for the user unknown to the user pool, `password` is given
+/// by `UserMigration_Authentication` and `None` by `UserMigration_ForgotPassword`.
+/// Returns `None` when the user exists or the trigger is not configured.
+pub fn user_migration(
+    user_pool_id: &str,
+    client_id: &str,
+    trigger_source: &str,
+    username: &str,
+    password: Option<&str>,
+    client_metadata: &ClientMetadata,
+) -> Result<Option<UserMigrationResponse>, ActionError> {
+    let mut request = serde_json::json!({
+        "validationData": client_metadata,
+        "clientMetadata": client_metadata,
+    });
+    if let Some(password) = password {
+        request["password"] = serde_json::json!(password);
+    }
+    let event = super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        if pool.resolve_username(username).is_some() {
+            return Ok::<_, ActionError>(None);
+        }
+        Ok(trigger_event(
+            pool,
+            Trigger::UserMigration,
+            trigger_source,
+            Some(client_id),
+            username,
+            request,
+            serde_json::json!({
+                "userAttributes": null,
+                "finalUserStatus": null,
+                "messageAction": null,
+                "desiredDeliveryMediums": null,
+                "forceAliasCreation": null,
+                "enableSMSMFA": null,
+            }),
+        ))
+    })?;
+    invoke_trigger(event)?
+        .map(|response| parse(&response))
+        .transpose()
+}
+
+/// Creates the user unknown to the user pool with the response of UserMigration trigger.
+/// Nothing happens when the user exists or the trigger is not configured.
+pub fn migrate_user(
+    user_pool_id: &str,
+    client_id: &str,
+    trigger_source: &str,
+    username: &str,
+    password: Option<&str>,
+    client_metadata: &ClientMetadata,
+) -> Result<(), ActionError> {
+    let response = match user_migration(
+        user_pool_id,
+        client_id,
+        trigger_source,
+        username,
+        password,
+        client_metadata,
+    )? {
+        Some(response) => response,
+        None => return Ok(()),
+    };
+    // without a password (`UserMigration_ForgotPassword`) the user has to reset it.
+    let status = match (response.final_user_status.as_deref(), password) {
+        (Some("CONFIRMED") | None, Some(_)) => UserStatus::CONFIRMED,
+        (Some("CONFIRMED") | Some("RESET_REQUIRED") | None, _) => UserStatus::RESET_REQUIRED,
+        _ => return Err(invalid_lambda_response()),
+    };
+
+    let mut user = User::new(username);
+    user.set_attributes(
+        response
+            .user_attributes
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(name, _)| name != SUB_ATTRIBUTE && name != "username"),
+    )?;
+    user.status = status;
+    if let (UserStatus::CONFIRMED, Some(password)) = (status, password) {
+        user.set_password(password);
+    }
+    let user = super::with_store(|store| {
+        let pool = store.user_pool_mut(user_pool_id)?;
+        if pool
+            .config
+            .username_attributes
+            .as_ref()
+            .is_some_and(|attributes| !attributes.is_empty())
+        {
+            // username is an email or a phone number, the user is stored with `sub`.
+            user.username = user.sub().to_string();
+        }
+        pool.add_user(user).cloned()
+    })?;
+
+    // the migrated user has no temporary password to be sent.
+    if response.message_action.as_deref() != Some("SUPPRESS") {
+        let deliveries = response
+            .desired_delivery_mediums
+            .unwrap_or_else(|| vec!["SMS".to_string()])
+            .iter()
+            .filter_map(|medium| match medium.as_str() {
+                "EMAIL" => CodeDelivery::for_attribute(&user, EMAIL_ATTRIBUTE),
+                _ => CodeDelivery::for_attribute(&user, PHONE_NUMBER_ATTRIBUTE),
+            })
+            .collect::<Vec<_>>();
+        for delivery in deliveries {
+            super::send_message(
+                user_pool_id,
+                &user.username,
+                MessageType::Welcome,
+                &delivery,
+                "",
+                &TriggerContext::new(Some(client_id), Some(client_metadata)),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Claims which PreTokenGeneration trigger cannot add, override or suppress.
+const RESERVED_CLAIMS: [&str; 19] = [
+    "acr",
+    "amr",
+    "aud",
+    "at_hash",
+    "auth_time",
+    "azp",
+    "client_id",
+    "cognito:username",
+    "event_id",
+    "exp",
+    "iat",
+    "identities",
+    "iss",
+    "jti",
+    "nonce",
+    "origin_jti",
+    "sub",
+    "token_use",
+    "username",
+];
+
+/// Claims to add, override and suppress in a token, and scopes of the access token.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimsOverrideDetails {
+    pub claims_to_add_or_override: Option<serde_json::Map<String, Value>>,
+    pub claims_to_suppress: Option<Vec<String>>,
+    pub scopes_to_add: Option<Vec<String>>,
+    pub scopes_to_suppress: Option<Vec<String>>,
+}
+
+impl ClaimsOverrideDetails {
+    /// Overrides claims of the token, reserved claims are left as is.
+    pub fn apply(&self, claims: &mut serde_json::Map<String, Value>) {
+        let is_reserved = |name: &str| RESERVED_CLAIMS.contains(&name) || name == "scope";
+        for name in self.claims_to_suppress.iter().flatten() {
+            if !is_reserved(name) {
+                claims.remove(name);
+            }
+        }
+        for (name, value) in self.claims_to_add_or_override.iter().flatten() {
+            if !is_reserved(name) {
+                claims.insert(name.clone(), value.clone());
+            }
+        }
+    }
+
+    /// Returns the space separated scopes with added and suppressed ones.
+    pub fn apply_scopes(&self, scope: &str) -> String {
+        let suppressed = self.scopes_to_suppress.clone().unwrap_or_default();
+        let mut scopes = scope
+            .split(' ')
+            .filter(|s| !suppressed.iter().any(|x| x == s))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        for added in self.scopes_to_add.iter().flatten() {
+            if !scopes.contains(added) && !suppressed.contains(added) {
+                scopes.push(added.clone());
+            }
+        }
+        scopes.join(" ")
+    }
+}
+
+/// Groups and IAM roles of tokens overridden by PreTokenGeneration trigger.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupOverrideDetails {
+    pub groups_to_override: Option<Vec<String>>,
+    pub iam_roles_to_override: Option<Vec<String>>,
+    pub preferred_role: Option<String>,
+}
+
+/// Customization of ID and access tokens returned by PreTokenGeneration trigger.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TokenOverrides {
+    pub id_token: ClaimsOverrideDetails,
+    pub access_token: ClaimsOverrideDetails,
+    pub group_override_details: Option<GroupOverrideDetails>,
+}
+
+/// Parses a part of trigger response, `null` is the default.
+fn parse<T: serde::de::DeserializeOwned + Default>(value: &Value) -> Result<T, ActionError> {
+    match value {
+        Value::Null => Ok(T::default()),
+        value => serde_json::from_value(value.clone()).map_err(|_| invalid_lambda_response()),
+    }
+}
+
+/// Returns `V1_0` or `V2_0` of PreTokenGeneration trigger event.
+fn pre_token_generation_version(pool: &UserPool) -> &str {
+    pool.config
+        .lambda_config
+        .as_ref()
+        .and_then(|config| config.pre_token_generation_config.as_ref())
+        .and_then(|config| config.lambda_version.as_deref())
+        .unwrap_or("V1_0")
+}
+
+/// Invokes PreTokenGeneration trigger, V1_0 (`claimsOverrideDetails`) customizes ID token
+/// and V2_0 (`claimsAndScopeOverrideDetails`) customizes both ID and access tokens.
+pub fn pre_token_generation(
+    user_pool_id: &str,
+    client_id: &str,
+    trigger_source: &str,
+    username: &str,
+    scopes: &[&str],
+    client_metadata: &ClientMetadata,
+) -> Result<TokenOverrides, ActionError> {
+    let (version, event) = super::with_store(|store| {
+        let pool = store.user_pool(user_pool_id)?;
+        let user = pool.user(username)?;
+        let version = pre_token_generation_version(pool).to_string();
+        let groups = super::group_configuration(pool, user);
+        let mut request = serde_json::json!({
+            "userAttributes": event_user_attributes(user),
+            "groupConfiguration": {
+                "groupsToOverride": groups.groups_to_override,
+                "iamRolesToOverride": groups.iam_roles_to_override,
+                "preferredRole": groups.preferred_role,
+            },
+            "clientMetadata": client_metadata,
+        });
+        let response = if version == "V1_0" {
+            serde_json::json!({ "claimsOverrideDetails": null })
+        } else {
+            request["scopes"] = serde_json::json!(scopes);
+            serde_json::json!({ "claimsAndScopeOverrideDetails": null })
+        };
+        let event = trigger_event(
+            pool,
+            Trigger::PreTokenGeneration,
+            trigger_source,
+            Some(client_id),
+            &user.username,
+            request,
+            response,
+        );
+        Ok::<_, ActionError>((version, event))
+    })?;
+    let response = match invoke_trigger(event)? {
+        Some(response) => response,
+        None => return Ok(TokenOverrides::default()),
+    };
+    if version == "V1_0" {
+        let details = &response["claimsOverrideDetails"];
+        Ok(TokenOverrides {
+            id_token: parse(details)?,
+            access_token: Default::default(),
+            group_override_details: parse(&details["groupOverrideDetails"])?,
+        })
+    } else {
+        let details = &response["claimsAndScopeOverrideDetails"];
+        Ok(TokenOverrides {
+            id_token: parse(&details["idTokenGeneration"])?,
+            access_token: parse(&details["accessTokenGeneration"])?,
+            group_override_details: parse(&details["groupOverrideDetails"])?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn user_attributes_have_user_status() {
+        let user = User::new("username");
+        let attributes = event_user_attributes(&user);
+        assert_eq!("CONFIRMED", attributes["cognito:user_status"]);
+        assert_eq!(user.sub(), attributes["sub"]);
+    }
+
+    #[test]
+    fn trigger_not_configured() {
+        let pool = UserPool::new("us-east-1_abcdefghi", Default::default());
+        assert!(!has_trigger(&pool, Trigger::DefineAuthChallenge));
+        let event = trigger_event(
+            &pool,
+            Trigger::DefineAuthChallenge,
+            "DefineAuthChallenge_Authentication",
+            None,
+            "username",
+            Value::Null,
+            Value::Null,
+        );
+        assert!(event.is_none());
+        assert_eq!(None, invoke_trigger(event).unwrap());
+    }
+
+    #[test]
+    fn auto_verify_existing_attributes() {
+        let mut user = User::new("username");
+        user.set_attributes(vec![(
+            EMAIL_ATTRIBUTE.to_string(),
+            "user@example.com".to_string(),
+        )])
+        .unwrap();
+        let response: PreSignUpResponse = serde_json::from_value(serde_json::json!({
+            "autoConfirmUser": true,
+            "autoVerifyEmail": true,
+            "autoVerifyPhone": true,
+        }))
+        .unwrap();
+        assert!(response.auto_confirm_user());
+        response.auto_verify(&mut user).unwrap();
+        assert_eq!(Some(&"true".to_string()), user.attribute("email_verified"));
+        assert_eq!(None, user.attribute("phone_number_verified"));
+    }
+
+    #[test]
+    fn claims_override_keeps_reserved_claims() {
+        let details: ClaimsOverrideDetails = serde_json::from_value(serde_json::json!({
+            "claimsToAddOrOverride": {"custom:tenant": "tenant", "sub": "other"},
+            "claimsToSuppress": ["email", "iss"],
+            "scopesToAdd": ["openid", "read"],
+            "scopesToSuppress": ["aws.cognito.signin.user.admin"],
+        }))
+        .unwrap();
+        let mut claims = serde_json::json!({"sub": "sub", "iss": "iss", "email": "email"})
+            .as_object()
+            .unwrap()
+            .clone();
+        details.apply(&mut claims);
+        assert_eq!(
+            serde_json::json!({"sub": "sub", "iss": "iss", "custom:tenant": "tenant"}),
+            Value::Object(claims)
+        );
+        assert_eq!(
+            "openid read",
+            details.apply_scopes("aws.cognito.signin.user.admin openid")
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::common::{ARN_REGEX, NAME_REGEX, USER_POOL_ID_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const UPDATE_GROUP_NAME: &str = "UpdateGroup";
+pub const UPDATE_GROUP_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.UpdateGroup";
+
+super::gen_response_err!(
+    UpdateGroupError,
+    InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateGroupRequest {
+    #[validate(length(min = 1, max = 2048))]
+    pub description: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "NAME_REGEX")]
+    pub group_name: Option<String>,
+    #[validate(range(min = 0, max = 2147483647))]
+    pub precedence: Option<i64>,
+    #[validate(length(min = 20, max = 2048))]
+    #[validate(regex = "ARN_REGEX")]
+    pub role_arn: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for UpdateGroupRequest {
+    fn to_action_name() -> &'static str {
+        UPDATE_GROUP_NAME
+    }
+}
+
+impl super::ToResponse for UpdateGroupRequest {
+    type E = UpdateGroupError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, UPDATE_GROUP_NAME, update_group)
+    }
+}
+
+/// Updates the given fields of the group, omitted fields are left as is.
+fn update_group(request: &UpdateGroupRequest) -> Result<serde_json::Value, super::ActionError> {
+    let user_pool_id = request.user_pool_id.as_ref().unwrap();
+    super::with_store(|store| {
+        let group = store
+            .user_pool_mut(user_pool_id)?
+            .group_mut(request.group_name.as_ref().unwrap())?;
+        if request.description.is_some() {
+            group.description = request.description.clone();
+        }
+        if request.precedence.is_some() {
+            group.precedence = request.precedence;
+        }
+        if request.role_arn.is_some() {
+            group.role_arn = request.role_arn.clone();
+        }
+        group.touch();
+        Ok(serde_json::json!({ "Group": group.to_group_type(user_pool_id) }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UpdateGroupRequest {
+            group_name: Some("group_name".to_string()),
+            precedence: Some(1),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UpdateGroupRequest {
+            group_name: Some("group_name".to_string()),
+            precedence: Some(-1),
+            user_pool_id: Some("user_pool_id".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = UpdateGroupError::ResourceNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UpdateGroupError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::common::TOKEN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const UPDATE_USER_ATTRIBUTES_NAME: &str = "UpdateUserAttributes";
+pub const UPDATE_USER_ATTRIBUTES_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.UpdateUserAttributes";
+
+super::gen_response_err!(
+    UpdateUserAttributesError,
+    AliasExistsException
+    | CodeDeliveryFailureException
+    | CodeMismatchException
+    | ExpiredCodeException
+    | ForbiddenException
+    | InvalidEmailRoleAccessPolicyException
+    | InvalidLambdaResponseException
+    | InvalidParameterException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UnexpectedLambdaException
+    | UserLambdaValidationException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateUserAttributesRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex = "TOKEN_REGEX")]
+    pub access_token: Option<String>,
+    pub client_metadata: Option<std::collections::HashMap<String, String>>,
+    #[validate(required)]
+    #[validate(required_nested)]
+    pub user_attributes: Option<Vec<super::data_types::AttributeType>>,
+}
+
+impl super::ToActionName for UpdateUserAttributesRequest {
+    fn to_action_name() -> &'static str {
+        UPDATE_USER_ATTRIBUTES_NAME
+    }
+}
+
+impl super::ToResponse for UpdateUserAttributesRequest {
+    type E = UpdateUserAttributesError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, UPDATE_USER_ATTRIBUTES_NAME, update_user_attributes)
+    }
+}
+
+/// Updates the attributes, changed email or phone number in `AutoVerifiedAttributes` of
+/// the user pool becomes unverified and a code to verify it is sent.
+fn update_user_attributes(
+    request: &UpdateUserAttributesRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let access_token = request.access_token.as_ref().unwrap();
+    let attributes = request
+        .user_attributes
+        .iter()
+        .flatten()
+        .map(|attr| {
+            (
+                attr.name.clone().unwrap_or_default(),
+                attr.value.clone().unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let (user_pool_id, username, deliveries) = super::with_store(|store| {
+        let (user_pool_id, username) = super::verify_access_token(store, access_token)?;
+        let pool = store.user_pool_mut(&user_pool_id)?;
+        if let Some((name, _)) = attributes
+            .iter()
+            .find(|(name, _)| !pool.has_attribute(name))
+        {
+            return Err(super::ActionError::invalid_parameter(&format!(
+                "user.{}: Attribute does not exist in the schema.",
+                name
+            )));
+        }
+        let auto_verified = pool
+            .config
+            .auto_verified_attributes
+            .clone()
+            .unwrap_or_default();
+        let user = pool.user_mut(&username)?;
+        let changed = attributes
+            .iter()
+            .filter(|(name, value)| {
+                auto_verified.contains(name) && user.attribute(name) != Some(value)
+            })
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        user.set_attributes(attributes.clone())?;
+        user.set_attributes(
+            changed
+                .iter()
+                .map(|name| (format!("{}_verified", name), "false".to_string())),
+        )?;
+        let deliveries = changed
+            .iter()
+            .filter_map(|name| super::CodeDelivery::for_attribute(user, name))
+            .collect::<Vec<_>>();
+        Ok((user_pool_id, username, deliveries))
+    })?;
+    let context = super::TriggerContext::new(
+        super::access_token_client_id(access_token).as_deref(),
+        request.client_metadata.as_ref(),
+    );
+    let mut code_delivery_details_list = Vec::new();
+    for delivery in deliveries {
+        code_delivery_details_list.push(super::send_code(
+            &user_pool_id,
+            &username,
+            super::MessageType::UpdateUserAttribute,
+            &delivery,
+            &context,
+        )?);
+    }
+    Ok(serde_json::json!({ "CodeDeliveryDetailsList": code_delivery_details_list }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UpdateUserAttributesRequest {
+            access_token: Some("access_token".to_string()),
+            user_attributes: Some(Default::default()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UpdateUserAttributesRequest {
+            access_token: Some("access_token".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = UpdateUserAttributesError::InvalidParameterException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UpdateUserAttributesError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::common::USER_POOL_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const UPDATE_USER_POOL_NAME: &str = "UpdateUserPool";
+pub const UPDATE_USER_POOL_ACTION_NAME: &str = "AWSCognitoIdentityProviderService.UpdateUserPool";
+
+/// Validates the settings with the rules of CreateUserPool, the pool name is optional.
+fn validate_settings(value: &super::CreateUserPoolRequest) -> Result<(), ValidationError> {
+    match value.validate() {
+        Ok(_) => Ok(()),
+        Err(errors)
+            if value.pool_name.is_none()
+                && errors.errors().keys().all(|field| *field == "pool_name") =>
+        {
+            Ok(())
+        }
+        Err(_) => Err(ValidationError::new("settings")),
+    }
+}
+
+super::gen_response_err!(
+    UpdateUserPoolError,
+    ConcurrentModificationException
+    | InvalidEmailRoleAccessPolicyException
+    | InvalidParameterException
+    | InvalidSmsRoleAccessPolicyException
+    | InvalidSmsRoleTrustRelationshipException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserImportInProgressException
+    | UserPoolTaggingException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateUserPoolRequest {
+    #[serde(flatten)]
+    #[validate(custom(function = "validate_settings"))]
+    pub settings: super::CreateUserPoolRequest,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 55))]
+    #[validate(regex = "USER_POOL_ID_REGEX")]
+    pub user_pool_id: Option<String>,
+}
+
+impl super::ToActionName for UpdateUserPoolRequest {
+    fn to_action_name() -> &'static str {
+        UPDATE_USER_POOL_NAME
+    }
+}
+
+impl super::ToResponse for UpdateUserPoolRequest {
+    type E = UpdateUserPoolError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_empty_response(self, update_user_pool)
+    }
+}
+
+/// Replaces the settings of the user pool, omitted settings are reset to their defaults.
+/// The pool name is kept when omitted and the sign-in attributes and the schema can't be changed.
+fn update_user_pool(request: &UpdateUserPoolRequest) -> Result<(), super::ActionError> {
+    super::with_store(|store| {
+        let pool = store.user_pool_mut(request.user_pool_id.as_ref().unwrap())?;
+        let mut config = request.settings.clone();
+        if config.pool_name.is_none() {
+            config.pool_name = pool.config.pool_name.take();
+        }
+        config.alias_attributes = pool.config.alias_attributes.take();
+        config.schema = pool.config.schema.take();
+        config.username_attributes = pool.config.username_attributes.take();
+        config.username_configuration = pool.config.username_configuration.take();
+        pool.config = config;
+        pool.touch();
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::CreateUserPoolRequest;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UpdateUserPoolRequest {
+            settings: CreateUserPoolRequest {
+                mfa_configuration: Some("OPTIONAL".to_string()),
+                ..Default::default()
+            },
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UpdateUserPoolRequest {
+            settings: CreateUserPoolRequest {
+                mfa_configuration: Some("ALWAYS".to_string()),
+                ..Default::default()
+            },
+            user_pool_id: Some("user_pool_id".to_string()),
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = UpdateUserPoolError::ResourceNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UpdateUserPoolError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::common::CLIENT_ID_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::{Validate, ValidationError};
+
+pub const UPDATE_USER_POOL_CLIENT_NAME: &str = "UpdateUserPoolClient";
+pub const UPDATE_USER_POOL_CLIENT_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.UpdateUserPoolClient";
+
+/// Validates the settings with the rules of CreateUserPoolClient, the client name is optional.
+fn validate_settings(value: &super::CreateUserPoolClientRequest) -> Result<(), ValidationError> {
+    match value.validate() {
+        Ok(_) => Ok(()),
+        Err(errors)
+            if value.client_name.is_none()
+                && errors.errors().keys().all(|field| *field == "client_name") =>
+        {
+            Ok(())
+        }
+        Err(_) => Err(ValidationError::new("settings")),
+    }
+}
+
+super::gen_response_err!(
+    UpdateUserPoolClientError,
+    ConcurrentModificationException
+    | InvalidOAuthFlowException
+    | InvalidParameterException
+    | NotAuthorizedException
+    | ResourceNotFoundException
+    | ScopeDoesNotExistException
+    | TooManyRequestsException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateUserPoolClientRequest {
+    #[validate(required)]
+    #[validate(length(min = 1, max = 128))]
+    #[validate(regex = "CLIENT_ID_REGEX")]
+    pub client_id: Option<String>,
+    #[serde(flatten)]
+    #[validate(custom(function = "validate_settings"))]
+    pub settings: super::CreateUserPoolClientRequest,
+}
+
+impl super::ToActionName for UpdateUserPoolClientRequest {
+    fn to_action_name() -> &'static str {
+        UPDATE_USER_POOL_CLIENT_NAME
+    }
+}
+
+impl super::ToResponse for UpdateUserPoolClientRequest {
+    type E = UpdateUserPoolClientError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(
+            self,
+            UPDATE_USER_POOL_CLIENT_NAME,
+            update_user_pool_client,
+        )
+    }
+}
+
+/// Replaces the settings of the client, omitted settings are reset to their defaults.
+/// The client name is kept when omitted and the client secret can't be changed.
+fn update_user_pool_client(
+    request: &UpdateUserPoolClientRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    super::with_store(|store| {
+        let client = store
+            .user_pool_mut(request.settings.user_pool_id.as_ref().unwrap())?
+            .client_mut(request.client_id.as_ref().unwrap())?;
+        let mut config = request.settings.clone();
+        if config.client_name.is_none() {
+            config.client_name = client.config.client_name.take();
+        }
+        config.generate_secret = client.config.generate_secret;
+        client.config = config;
+        client.touch();
+        Ok(serde_json::json!({ "UserPoolClient": client.to_user_pool_client_type() }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user_pools::CreateUserPoolClientRequest;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = UpdateUserPoolClientRequest {
+            client_id: Some("client_id".to_string()),
+            settings: CreateUserPoolClientRequest {
+                user_pool_id: Some("user_pool_id".to_string()),
+                ..Default::default()
+            },
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = UpdateUserPoolClientRequest {
+            client_id: Some("client_id".to_string()),
+            settings: CreateUserPoolClientRequest {
+                access_token_validity: Some(0),
+                user_pool_id: Some("user_pool_id".to_string()),
+                ..Default::default()
+            },
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = UpdateUserPoolClientError::ResourceNotFoundException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = UpdateUserPoolClientError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
@@ -0,0 +1,164 @@
+use super::{ActionError, User};
+
+/// Attributes which can be used in `Filter` of ListUsers.
+const SEARCH_ATTRIBUTES: [&str; 10] = [
+    "username",
+    "email",
+    "phone_number",
+    "name",
+    "given_name",
+    "family_name",
+    "preferred_username",
+    "cognito:user_status",
+    "status",
+    "sub",
+];
+
+/// Comparison of a filter, `=` or `^=`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterType {
+    Equals,
+    StartsWith,
+}
+
+/// Parsed `Filter` of ListUsers (e.g. `email ^= "bob"`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserFilter {
+    pub attribute_name: String,
+    pub filter_type: FilterType,
+    pub value: String,
+}
+
+fn parse_error() -> ActionError {
+    ActionError::invalid_parameter("Error while parsing filter.")
+}
+
+impl UserFilter {
+    /// Parses `AttributeName Filter-Type "AttributeValue"`, the value is double quoted and
+    /// `\"` or `\\` escapes a quote or a backslash in it.
+    pub fn parse(filter: &str) -> Result<Self, ActionError> {
+        let filter = filter.trim();
+        let name_end = filter
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '^')
+            .ok_or_else(parse_error)?;
+        let (attribute_name, rest) = filter.split_at(name_end);
+        let rest = rest.trim_start();
+        let (filter_type, rest) = if let Some(rest) = rest.strip_prefix("^=") {
+            (FilterType::StartsWith, rest)
+        } else if let Some(rest) = rest.strip_prefix('=') {
+            (FilterType::Equals, rest)
+        } else {
+            return Err(parse_error());
+        };
+        let mut chars = rest.trim_start().chars();
+        if chars.next() != Some('"') {
+            return Err(parse_error());
+        }
+        let mut value = String::new();
+        loop {
+            match chars.next().ok_or_else(parse_error)? {
+                '"' => break,
+                '\\' => match chars.next() {
+                    Some(c @ ('"' | '\\')) => value.push(c),
+                    _ => return Err(parse_error()),
+                },
+                c => value.push(c),
+            }
+        }
+        if !chars.as_str().trim().is_empty() {
+            return Err(parse_error());
+        }
+        if !SEARCH_ATTRIBUTES.contains(&attribute_name) {
+            return Err(ActionError::invalid_parameter(&format!(
+                "Invalid search attribute: {}",
+                attribute_name
+            )));
+        }
+        Ok(UserFilter {
+            attribute_name: attribute_name.to_string(),
+            filter_type,
+            value,
+        })
+    }
+
+    /// Returns the value of the searched attribute, `status` is `Enabled` or `Disabled`.
+    fn user_value(&self, user: &User) -> Option<String> {
+        match self.attribute_name.as_str() {
+            "username" => Some(user.username.clone()),
+            "cognito:user_status" => Some(user.status.to_string()),
+            "status" => Some(if user.enabled { "Enabled" } else { "Disabled" }.to_string()),
+            name => user.attribute(name).cloned(),
+        }
+    }
+
+    pub fn matches(&self, user: &User) -> bool {
+        self.user_value(user)
+            .is_some_and(|value| match self.filter_type {
+                FilterType::Equals => value == self.value,
+                FilterType::StartsWith => value.starts_with(&self.value),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_filters() {
+        assert_eq!(
+            UserFilter {
+                attribute_name: "email".to_string(),
+                filter_type: FilterType::StartsWith,
+                value: "bob".to_string(),
+            },
+            UserFilter::parse(r#"email ^= "bob""#).unwrap()
+        );
+        assert_eq!(
+            UserFilter {
+                attribute_name: "name".to_string(),
+                filter_type: FilterType::Equals,
+                value: r#"say "hi" \ bye"#.to_string(),
+            },
+            UserFilter::parse(r#"name="say \"hi\" \\ bye""#).unwrap()
+        );
+    }
+
+    #[test]
+    fn reject_invalid_filters() {
+        for filter in &[
+            r#"email"#,
+            r#"email = bob"#,
+            r#"email = "bob"#,
+            r#"email != "bob""#,
+            r#"email = "bob" and"#,
+            r#"email = "\n""#,
+        ] {
+            assert_eq!(
+                "Error while parsing filter.",
+                UserFilter::parse(filter).unwrap_err().message,
+                "{}",
+                filter
+            );
+        }
+        assert_eq!(
+            "Invalid search attribute: address",
+            UserFilter::parse(r#"address = "x""#).unwrap_err().message
+        );
+    }
+
+    #[test]
+    fn match_users() {
+        let mut user = User::new("bob");
+        user.set_attributes(vec![("email".to_string(), "bob@example.com".to_string())])
+            .unwrap();
+        let matches = |filter: &str| UserFilter::parse(filter).unwrap().matches(&user);
+        assert!(matches(r#"email ^= "bob""#));
+        assert!(!matches(r#"email = "bob""#));
+        assert!(matches(r#"username = "bob""#));
+        assert!(matches(r#"status = "Enabled""#));
+        assert!(matches(r#"cognito:user_status = "CONFIRMED""#));
+        assert!(!matches(r#"given_name ^= """#));
+    }
+}
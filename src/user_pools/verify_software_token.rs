@@ -0,0 +1,123 @@
+use crate::common::TOKEN_REGEX;
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const VERIFY_SOFTWARE_TOKEN_NAME: &str = "VerifySoftwareToken";
+pub const VERIFY_SOFTWARE_TOKEN_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.VerifySoftwareToken";
+
+super::gen_response_err!(
+    VerifySoftwareTokenError,
+    CodeMismatchException
+    | EnableSoftwareTokenMFAException
+    | ForbiddenException
+    | InvalidParameterException
+    | InvalidUserPoolConfigurationException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | SoftwareTokenMFANotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct VerifySoftwareTokenRequest {
+    #[validate(regex = "TOKEN_REGEX")]
+    pub access_token: Option<String>,
+    pub friendly_device_name: Option<String>,
+    #[validate(length(min = 20, max = 2048))]
+    pub session: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 6, max = 6))]
+    pub user_code: Option<String>,
+}
+
+impl super::ToActionName for VerifySoftwareTokenRequest {
+    fn to_action_name() -> &'static str {
+        VERIFY_SOFTWARE_TOKEN_NAME
+    }
+}
+
+impl super::ToResponse for VerifySoftwareTokenRequest {
+    type E = VerifySoftwareTokenError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_json_response(self, VERIFY_SOFTWARE_TOKEN_NAME, verify_software_token)
+    }
+}
+
+fn verify_software_token(
+    request: &VerifySoftwareTokenRequest,
+) -> Result<serde_json::Value, super::ActionError> {
+    let user_code = request.user_code.as_ref().unwrap();
+    let friendly_device_name = request.friendly_device_name.as_ref();
+    super::with_store(|store| match (&request.access_token, &request.session) {
+        (Some(access_token), _) => {
+            let (user_pool_id, username) = super::verify_access_token(store, access_token)?;
+            let user = store.user_pool_mut(&user_pool_id)?.user_mut(&username)?;
+            super::verify_software_token(user, user_code, friendly_device_name)?;
+            Ok(serde_json::json!({ "Status": "SUCCESS", "Session": null }))
+        }
+        (None, Some(session)) => {
+            // enabled right away to respond to the MFA_SETUP challenge
+            let mut session = super::take_mfa_setup_session(store, session)?;
+            let user = store
+                .user_pool_mut(&session.user_pool_id)?
+                .user_mut(&session.username)?;
+            if let Err(e) = super::verify_software_token(user, user_code, friendly_device_name) {
+                // the session is kept for another attempt with the right code.
+                store.add_session(session);
+                return Err(e);
+            }
+            super::enable_software_token_mfa(user);
+            session.renew();
+            let session = store.add_session(session);
+            Ok(serde_json::json!({ "Status": "SUCCESS", "Session": session }))
+        }
+        (None, None) => Err(super::ActionError::invalid_parameter(
+            "Either AccessToken or Session is required",
+        )),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = VerifySoftwareTokenRequest {
+            access_token: Some("access_token".to_string()),
+            user_code: Some("123456".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = VerifySoftwareTokenRequest {
+            access_token: Some("access_token".to_string()),
+            user_code: Some("1234".to_string()),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = VerifySoftwareTokenError::CodeMismatchException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = VerifySoftwareTokenError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
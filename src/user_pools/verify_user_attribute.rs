@@ -0,0 +1,110 @@
+use crate::common::{CODE_REGEX, NAME_REGEX, TOKEN_REGEX};
+use crate::http;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+
+pub const VERIFY_USER_ATTRIBUTE_NAME: &str = "VerifyUserAttribute";
+pub const VERIFY_USER_ATTRIBUTE_ACTION_NAME: &str =
+    "AWSCognitoIdentityProviderService.VerifyUserAttribute";
+
+super::gen_response_err!(
+    VerifyUserAttributeError,
+    AliasExistsException
+    | CodeMismatchException
+    | ExpiredCodeException
+    | ForbiddenException
+    | InvalidParameterException
+    | LimitExceededException
+    | NotAuthorizedException
+    | PasswordResetRequiredException
+    | ResourceNotFoundException
+    | TooManyRequestsException
+    | UserNotConfirmedException
+    | UserNotFoundException => http::status_code(400),
+    InternalErrorException => http::status_code(500)
+);
+
+#[derive(Serialize, Deserialize, Debug, Default, Validate)]
+#[serde(rename_all = "PascalCase")]
+pub struct VerifyUserAttributeRequest {
+    #[validate(required)]
+    #[validate(length(min = 1))]
+    #[validate(regex = "TOKEN_REGEX")]
+    pub access_token: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 32))]
+    #[validate(regex = "NAME_REGEX")]
+    pub attribute_name: Option<String>,
+    #[validate(required)]
+    #[validate(length(min = 1, max = 2048))]
+    #[validate(regex = "CODE_REGEX")]
+    pub code: Option<String>,
+}
+
+impl super::ToActionName for VerifyUserAttributeRequest {
+    fn to_action_name() -> &'static str {
+        VERIFY_USER_ATTRIBUTE_NAME
+    }
+}
+
+impl super::ToResponse for VerifyUserAttributeRequest {
+    type E = VerifyUserAttributeError;
+    fn to_response(&self) -> super::Response {
+        super::to_stateful_empty_response(self, verify_user_attribute)
+    }
+}
+
+fn verify_user_attribute(request: &VerifyUserAttributeRequest) -> Result<(), super::ActionError> {
+    let attribute_name = request.attribute_name.as_ref().unwrap();
+    super::with_store(|store| {
+        let (user_pool_id, username) =
+            super::verify_access_token(store, request.access_token.as_ref().unwrap())?;
+        let user = store.user_pool_mut(&user_pool_id)?.user_mut(&username)?;
+        user.verify_code(
+            &super::CodePurpose::VerifyAttribute(attribute_name.clone()),
+            request.code.as_ref().unwrap(),
+        )?;
+        user.set_attributes(vec![(
+            format!("{}_verified", attribute_name),
+            "true".to_string(),
+        )])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn success_to_valid_request() {
+        let request = VerifyUserAttributeRequest {
+            access_token: Some("access_token".to_string()),
+            attribute_name: Some("email".to_string()),
+            code: Some("123456".to_string()),
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn failure_to_valid_request() {
+        let request = VerifyUserAttributeRequest {
+            access_token: Some("access_token".to_string()),
+            attribute_name: Some("email".to_string()),
+            code: None,
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn error_can_convert_to_status_code() {
+        use crate::user_pools::ToStatusCode;
+
+        let error = VerifyUserAttributeError::CodeMismatchException;
+        assert_eq!(http::status_code(400), error.to_status_code());
+
+        let error = VerifyUserAttributeError::InternalErrorException;
+        assert_eq!(http::status_code(500), error.to_status_code());
+    }
+}
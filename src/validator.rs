@@ -34,6 +34,10 @@ pub fn includes_lambda_version(value: &str) -> Result<(), ValidationError> {
     includes(value, vec!["V1_0"])
 }
 
+pub fn includes_pre_token_generation_lambda_version(value: &str) -> Result<(), ValidationError> {
+    includes(value, vec!["V1_0", "V2_0"])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
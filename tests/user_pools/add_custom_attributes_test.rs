@@ -4,6 +4,7 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -13,7 +14,10 @@ async fn test_success_to_request() {
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AddCustomAttributes",
         )
-        .body(r#"{"CustomAttributes":[{}],"UserPoolId":"user_pool_id"}"#)
+        .body(
+            r#"{"CustomAttributes":[{}],"UserPoolId":"user_pool_id"}"#
+                .replace("user_pool_id", &user_pool_id),
+        )
         .reply(&filter)
         .await;
 
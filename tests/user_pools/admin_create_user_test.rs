@@ -7,6 +7,7 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -21,13 +22,14 @@ async fn test_success_to_request() {
             "ClientMetadata":{},
             "DesiredDeliveryMediums":["EMAIL"],
             "ForceAliasCreation": true,
-            "MessageAction": "RESEND",
-            "TemporaryPassword": "aaaaaa",
+            "MessageAction": "SUPPRESS",
+            "TemporaryPassword": "Passw0rd!",
             "UserAttributes": [{"Name": "string","Value": "string"}],
             "Username":"username",
             "UserPoolId":"user_pool_id",
             "ValidationData": []
-        }"#,
+        }"#
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
@@ -70,3 +72,107 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_username_exists() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "existing_user").await;
+
+    let res = super::post_action(
+        "AdminCreateUser",
+        &r#"{"Username":"existing_user","UserPoolId":"user_pool_id"}"#
+            .replace("user_pool_id", &user_pool_id),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UsernameExistsException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_temporary_password_not_conform_to_policy() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let res = super::post_action(
+        "AdminCreateUser",
+        &serde_json::json!({
+            "MessageAction": "SUPPRESS",
+            "TemporaryPassword": "aaaaaa",
+            "Username": "username",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidPasswordException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_email_as_username() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"UsernameAttributes": ["email"]})).await;
+    let request = serde_json::json!({
+        "MessageAction": "SUPPRESS",
+        "Username": "user@example.com",
+        "UserPoolId": user_pool_id,
+    })
+    .to_string();
+
+    let res = super::post_action("AdminCreateUser", &request).await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let attributes = body["User"]["Attributes"].as_array().unwrap();
+    let attribute = |name: &str| {
+        attributes
+            .iter()
+            .find(|a| a["Name"] == name)
+            .map(|a| a["Value"].clone())
+    };
+    assert_eq!(attribute("sub"), Some(body["User"]["Username"].clone()));
+    assert_eq!(
+        Some(serde_json::json!("user@example.com")),
+        attribute("email")
+    );
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "user@example.com", "UserPoolId": user_pool_id})
+            .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let user: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(body["User"]["Username"], user["Username"]);
+
+    let res = super::post_action("AdminCreateUser", &request).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UsernameExistsException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::post_action(
+        "AdminCreateUser",
+        &serde_json::json!({
+            "MessageAction": "SUPPRESS",
+            "Username": "username",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
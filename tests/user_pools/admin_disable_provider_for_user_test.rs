@@ -4,6 +4,7 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -21,7 +22,8 @@ async fn test_success_to_request() {
                 "ProviderName": "string"
             },
             "UserPoolId":"user_pool_id"
-        }"#,
+        }"#
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
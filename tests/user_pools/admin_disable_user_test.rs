@@ -4,6 +4,8 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -13,7 +15,10 @@ async fn test_success_to_request() {
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminDisableUser",
         )
-        .body(r#"{"Username":"username","UserPoolId":"user_pool_id"}"#)
+        .body(
+            r#"{"Username":"username","UserPoolId":"user_pool_id"}"#
+                .replace("user_pool_id", &user_pool_id),
+        )
         .reply(&filter)
         .await;
 
@@ -43,3 +48,19 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_disabled_user() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "disabled_user").await;
+
+    let body = &r#"{"Username":"disabled_user","UserPoolId":"user_pool_id"}"#
+        .replace("user_pool_id", &user_pool_id);
+    let res = super::post_action("AdminDisableUser", body).await;
+    assert_eq!(200, res.status());
+
+    let res = super::post_action("AdminGetUser", body).await;
+    let user: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(false, user["Enabled"]);
+}
@@ -4,6 +4,8 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -13,7 +15,10 @@ async fn test_success_to_request() {
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminGetUser",
         )
-        .body(r#"{"Username":"username","UserPoolId":"user_pool_id"}"#)
+        .body(
+            r#"{"Username":"username","UserPoolId":"user_pool_id"}"#
+                .replace("user_pool_id", &user_pool_id),
+        )
         .reply(&filter)
         .await;
 
@@ -43,3 +48,56 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_get_created_user() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::post_action(
+        "AdminCreateUser",
+        &r#"{
+            "MessageAction": "SUPPRESS",
+            "TemporaryPassword": "Passw0rd!",
+            "UserAttributes": [{"Name": "email","Value": "user@example.com"}],
+            "Username":"created_user",
+            "UserPoolId":"user_pool_id"
+        }"#
+        .replace("user_pool_id", &user_pool_id),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &r#"{"Username":"created_user","UserPoolId":"user_pool_id"}"#
+            .replace("user_pool_id", &user_pool_id),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("created_user", body["Username"]);
+    assert_eq!("FORCE_CHANGE_PASSWORD", body["UserStatus"]);
+    assert_eq!(true, body["Enabled"]);
+    let attributes = body["UserAttributes"].as_array().unwrap();
+    assert!(attributes
+        .iter()
+        .any(|a| a["Name"] == "email" && a["Value"] == "user@example.com"));
+    assert!(attributes.iter().any(|a| a["Name"] == "sub"));
+}
+
+#[tokio::test]
+async fn test_user_not_found() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::post_action(
+        "AdminGetUser",
+        &r#"{"Username":"not_found_user","UserPoolId":"user_pool_id"}"#
+            .replace("user_pool_id", &user_pool_id),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UserNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -4,6 +4,9 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -18,11 +21,12 @@ async fn test_success_to_request() {
             "AnalyticsMetadata": { 
                "AnalyticsEndpointId": "string"
             },
-            "AuthFlow": "USER_SRP_AUTH",
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
             "AuthParameters": { 
-               "string" : "string" 
+               "USERNAME" : "username",
+               "PASSWORD" : "Passw0rd!"
             },
-            "ClientId": "string",
+            "ClientId": "client_id",
             "ClientMetadata": { 
                "string" : "string" 
             },
@@ -38,8 +42,10 @@ async fn test_success_to_request() {
                "ServerName": "string",
                "ServerPath": "string"
             },
-            "UserPoolId": "string_09"
-         }"#,
+            "UserPoolId": "user_pool_id"
+         }"#
+            .replace("client_id", &client_id)
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
@@ -97,3 +103,67 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_issued_tokens_are_signed() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "token_user", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "AdminInitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "ADMIN_NO_SRP_AUTH",
+            "AuthParameters": {"USERNAME": "token_user", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let result = &body["AuthenticationResult"];
+    assert_eq!(3600, result["ExpiresIn"]);
+    assert_eq!("Bearer", result["TokenType"]);
+
+    let id_claims = user_pools::verify(result["IdToken"].as_str().unwrap()).unwrap();
+    assert_eq!("id", id_claims["token_use"]);
+    assert_eq!("token_user", id_claims["cognito:username"]);
+    assert_eq!(client_id, id_claims["aud"]);
+    assert_eq!(
+        format!("http://localhost:8080/{}", user_pool_id),
+        id_claims["iss"]
+    );
+
+    let access_claims = user_pools::verify(result["AccessToken"].as_str().unwrap()).unwrap();
+    assert_eq!("access", access_claims["token_use"]);
+    assert_eq!(client_id, access_claims["client_id"]);
+    assert_eq!(id_claims["sub"], access_claims["sub"]);
+}
+
+#[tokio::test]
+async fn test_incorrect_password() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "AdminInitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "wrong"},
+            "ClientId": client_id,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
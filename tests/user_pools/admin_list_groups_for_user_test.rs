@@ -4,6 +4,10 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "string").await;
+    super::create_group(&user_pool_id, "group_name", Some(1), None).await;
+    super::add_user_to_group(&user_pool_id, "string", "group_name").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -16,16 +20,20 @@ async fn test_success_to_request() {
         .body(
             r#"{
             "Limit": 10,
-            "NextToken": "string",
             "Username": "string",
-            "UserPoolId": "string_00"
-         }"#,
+            "UserPoolId": "user_pool_id"
+         }"#
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
 
     assert_eq!(200, res.status());
-    assert!(!res.body().is_empty());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let groups = body["Groups"].as_array().unwrap();
+    assert_eq!(1, groups.len());
+    assert_eq!("group_name", groups[0]["GroupName"]);
+    assert_eq!(1, groups[0]["Precedence"]);
 }
 
 #[tokio::test]
@@ -4,6 +4,10 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
+    super::create_group(&user_pool_id, "group_name", None, None).await;
+    super::add_user_to_group(&user_pool_id, "username", "group_name").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -13,13 +17,24 @@ async fn test_success_to_request() {
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminRemoveUserFromGroup",
         )
-        .body(r#"{"GroupName":"group_name","Username":"username","UserPoolId":"user_pool_id"}"#)
+        .body(
+            r#"{"GroupName":"group_name","Username":"username","UserPoolId":"user_pool_id"}"#
+                .replace("user_pool_id", &user_pool_id),
+        )
         .reply(&filter)
         .await;
 
     assert_eq!("".as_bytes(), res.body());
     assert_eq!(200, res.status());
     assert!(res.body().is_empty());
+    user_pools::with_store(|store| {
+        let user = store
+            .user_pool(&user_pool_id)
+            .unwrap()
+            .user("username")
+            .unwrap();
+        assert!(user.groups.is_empty());
+    });
 }
 
 #[tokio::test]
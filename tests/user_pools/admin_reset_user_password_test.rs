@@ -4,6 +4,8 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -13,7 +15,10 @@ async fn test_success_to_request() {
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminResetUserPassword",
         )
-        .body(r#"{"ClientMetadata":{},"Username":"username","UserPoolId":"user_pool_id"}"#)
+        .body(
+            r#"{"ClientMetadata":{},"Username":"username","UserPoolId":"user_pool_id"}"#
+                .replace("user_pool_id", &user_pool_id),
+        )
         .reply(&filter)
         .await;
 
@@ -43,3 +48,34 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_reset_user_requires_password_reset() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "AdminResetUserPassword",
+        &serde_json::json!({"Username": "username", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "PasswordResetRequiredException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -1,9 +1,36 @@
 use fakey_cognito::*;
 use pretty_assertions::assert_eq;
 
+/// Starts ADMIN_USER_PASSWORD_AUTH with the temporary password and returns the response.
+async fn initiate_auth(user_pool_id: &str, client_id: &str) -> serde_json::Value {
+    let res = super::post_action(
+        "AdminInitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    serde_json::from_slice(res.body()).unwrap()
+}
+
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_user(&user_pool_id, "username").await;
+    let challenge = initiate_auth(&user_pool_id, &client_id).await;
+    assert_eq!("NEW_PASSWORD_REQUIRED", challenge["ChallengeName"]);
+    assert_eq!(
+        "username",
+        challenge["ChallengeParameters"]["USER_ID_FOR_SRP"]
+    );
+    assert_eq!("[]", challenge["ChallengeParameters"]["requiredAttributes"]);
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -18,11 +45,13 @@ async fn test_success_to_request() {
             "AnalyticsMetadata": { 
                "AnalyticsEndpointId": "string"
             },
-            "ChallengeName": "SMS_MFA",
+            "ChallengeName": "NEW_PASSWORD_REQUIRED",
             "ChallengeResponses": { 
-               "string" : "string" 
+               "USERNAME": "username",
+               "NEW_PASSWORD": "NewPassw0rd!",
+               "userAttributes.name": "name"
             },
-            "ClientId": "string",
+            "ClientId": "client_id",
             "ClientMetadata": { 
                "string" : "string" 
             },
@@ -38,15 +67,95 @@ async fn test_success_to_request() {
                "ServerName": "string",
                "ServerPath": "string"
             },
-            "Session": "00000000000000000000",
-            "UserPoolId": "string_0123"
-         }"#,
+            "Session": "session",
+            "UserPoolId": "user_pool_id"
+         }"#
+            .replace("client_id", &client_id)
+            .replace("user_pool_id", &user_pool_id)
+            .replace(r#""session""#, &challenge["Session"].to_string()),
         )
         .reply(&filter)
         .await;
 
     assert_eq!(200, res.status());
-    assert!(!res.body().is_empty());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let id_token = body["AuthenticationResult"]["IdToken"].as_str().unwrap();
+    let claims = user_pools::verify(id_token).unwrap();
+    assert_eq!("name", claims["name"]);
+
+    let user = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "username", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    let user: serde_json::Value = serde_json::from_slice(user.body()).unwrap();
+    assert_eq!("CONFIRMED", user["UserStatus"]);
+}
+
+#[tokio::test]
+async fn test_required_attribute_is_missing() {
+    super::setup().await;
+    let user_pool_id = &super::create_user_pool_with(serde_json::json!({
+        "Schema": [{"Name": "email", "Required": true}],
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(user_pool_id).await;
+    super::create_user(user_pool_id, "username").await;
+    let challenge = initiate_auth(user_pool_id, &client_id).await;
+    assert_eq!(
+        r#"["userAttributes.email"]"#,
+        challenge["ChallengeParameters"]["requiredAttributes"]
+    );
+
+    let respond = |attributes: serde_json::Value, session: &serde_json::Value| {
+        let mut responses =
+            serde_json::json!({"USERNAME": "username", "NEW_PASSWORD": "NewPassw0rd!"});
+        responses
+            .as_object_mut()
+            .unwrap()
+            .extend(attributes.as_object().unwrap().clone());
+        serde_json::json!({
+            "ChallengeName": "NEW_PASSWORD_REQUIRED",
+            "ChallengeResponses": responses,
+            "ClientId": client_id,
+            "Session": session,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string()
+    };
+
+    let res = super::post_action(
+        "AdminRespondToAuthChallenge",
+        &respond(serde_json::json!({}), &challenge["Session"]),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let challenge = initiate_auth(user_pool_id, &client_id).await;
+    let res = super::post_action(
+        "AdminRespondToAuthChallenge",
+        &respond(
+            serde_json::json!({"userAttributes.unknown": "value"}),
+            &challenge["Session"],
+        ),
+    )
+    .await;
+    assert_eq!(400, res.status());
+
+    let challenge = initiate_auth(user_pool_id, &client_id).await;
+    let res = super::post_action(
+        "AdminRespondToAuthChallenge",
+        &respond(
+            serde_json::json!({"userAttributes.email": "user@example.com"}),
+            &challenge["Session"],
+        ),
+    )
+    .await;
+    assert_eq!(200, res.status());
 }
 
 #[tokio::test]
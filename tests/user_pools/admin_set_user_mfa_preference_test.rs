@@ -4,6 +4,9 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
+    super::set_phone_number(&user_pool_id, "username").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -19,19 +22,24 @@ async fn test_success_to_request() {
                "Enabled": true,
                "PreferredMfa": true
             },
-            "SoftwareTokenMfaSettings": { 
-               "Enabled": true,
-               "PreferredMfa": true
-            },
-            "Username": "string",
-            "UserPoolId": "string_0000"
-         }"#,
+            "Username": "username",
+            "UserPoolId": "user_pool_id"
+         }"#
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
 
     assert_eq!(200, res.status());
     assert!(res.body().is_empty());
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "username", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("SMS_MFA", body["PreferredMfaSetting"]);
 }
 
 #[tokio::test]
@@ -69,3 +77,27 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_software_token_not_verified() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
+
+    let res = super::post_action(
+        "AdminSetUserMFAPreference",
+        &serde_json::json!({
+            "SoftwareTokenMfaSettings": {"Enabled": true, "PreferredMfa": true},
+            "Username": "username",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -4,6 +4,8 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "string").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -15,11 +17,12 @@ async fn test_success_to_request() {
         )
         .body(
             r#"{
-            "Password": "string",
+            "Password": "Passw0rd!",
             "Permanent": true,
             "Username": "string",
             "UserPoolId": "user_pool_id"
-         }"#,
+         }"#
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
@@ -58,3 +61,49 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_password_not_conform_to_policy() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "Policies": {"PasswordPolicy": {"MinimumLength": 12, "RequireNumbers": true}},
+    }))
+    .await;
+    let body = serde_json::json!({
+        "MessageAction": "SUPPRESS",
+        "Username": "username",
+        "UserPoolId": user_pool_id,
+    });
+    let res = super::post_action("AdminCreateUser", &body.to_string()).await;
+    assert_eq!(200, res.status());
+
+    let res = super::post_action(
+        "AdminSetUserPassword",
+        &serde_json::json!({
+            "Password": "Passw0rd!",
+            "Permanent": true,
+            "Username": "username",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidPasswordException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::post_action(
+        "AdminSetUserPassword",
+        &serde_json::json!({
+            "Password": "longpassword1",
+            "Permanent": true,
+            "Username": "username",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+}
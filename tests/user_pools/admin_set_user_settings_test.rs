@@ -4,6 +4,9 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
+    super::set_phone_number(&user_pool_id, "username").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -17,13 +20,14 @@ async fn test_success_to_request() {
             r#"{
             "MFAOptions": [ 
                { 
-                  "AttributeName": "string",
-                  "DeliveryMedium": "string"
+                  "AttributeName": "phone_number",
+                  "DeliveryMedium": "SMS"
                }
             ],
-            "Username": "string",
+            "Username": "username",
             "UserPoolId": "user_pool_id"
-         }"#,
+         }"#
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
@@ -31,6 +35,18 @@ async fn test_success_to_request() {
     assert_eq!("".as_bytes(), res.body());
     assert_eq!(200, res.status());
     assert!(res.body().is_empty());
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "username", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(
+        serde_json::json!([{"AttributeName": "phone_number", "DeliveryMedium": "SMS"}]),
+        body["MFAOptions"]
+    );
+    assert_eq!(serde_json::json!(["SMS_MFA"]), body["UserMFASettingList"]);
 }
 
 #[tokio::test]
@@ -4,6 +4,8 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "string").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -26,7 +28,8 @@ async fn test_success_to_request() {
             ],
             "Username": "string",
             "UserPoolId": "user_pool_id"
-         }"#,
+         }"#
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
@@ -4,6 +4,10 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -13,12 +17,31 @@ async fn test_success_to_request() {
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminUserGlobalSignOut",
         )
-        .body(r#"{"Username":"username","UserPoolId":"user_pool_id"}"#)
+        .body(
+            r#"{"Username":"username","UserPoolId":"user_pool_id"}"#
+                .replace("user_pool_id", &user_pool_id),
+        )
         .reply(&filter)
         .await;
 
     assert_eq!(200, res.status());
     assert!(res.body().is_empty());
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "REFRESH_TOKEN_AUTH",
+            "AuthParameters": {"REFRESH_TOKEN": result["RefreshToken"]},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
 }
 
 #[tokio::test]
@@ -4,6 +4,10 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -13,12 +17,17 @@ async fn test_success_to_request() {
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AssociateSoftwareToken",
         )
-        .body(r#"{"AccessToken":"access_toen","Session":"session01234567890123"}"#)
+        .body(
+            r#"{"AccessToken":"access_token"}"#
+                .replace("access_token", result["AccessToken"].as_str().unwrap()),
+        )
         .reply(&filter)
         .await;
 
     assert_eq!(200, res.status());
-    assert!(!res.body().is_empty());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(32, body["SecretCode"].as_str().unwrap().len());
+    assert!(body.get("Session").is_none());
 }
 
 #[tokio::test]
@@ -43,3 +52,81 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_mfa_setup_with_session() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"MfaConfiguration": "ON"})).await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("MFA_SETUP", body["ChallengeName"]);
+    assert_eq!(
+        r#"["SOFTWARE_TOKEN_MFA"]"#,
+        body["ChallengeParameters"]["MFAS_CAN_SETUP"]
+    );
+
+    let res = super::post_action(
+        "AssociateSoftwareToken",
+        &serde_json::json!({ "Session": body["Session"] }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let secret = body["SecretCode"].as_str().unwrap();
+
+    // the session can be retried after a wrong code
+    let code = super::totp_code(secret);
+    let wrong_code = if code == "000000" { "111111" } else { "000000" };
+    let res = super::post_action(
+        "VerifySoftwareToken",
+        &serde_json::json!({"Session": body["Session"], "UserCode": wrong_code}).to_string(),
+    )
+    .await;
+    assert_eq!(
+        "EnableSoftwareTokenMFAException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::post_action(
+        "VerifySoftwareToken",
+        &serde_json::json!({
+            "Session": body["Session"],
+            "UserCode": super::totp_code(secret),
+            "FriendlyDeviceName": "device",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("SUCCESS", body["Status"]);
+
+    let res = super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "MFA_SETUP",
+            "ChallengeResponses": {"USERNAME": "username"},
+            "ClientId": client_id,
+            "Session": body["Session"],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body["AuthenticationResult"]["AccessToken"].is_string());
+}
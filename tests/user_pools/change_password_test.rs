@@ -4,6 +4,10 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -13,12 +17,16 @@ async fn test_success_to_request() {
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ChangePassword",
         )
-        .body(r#"{"AccessToken":"access_token01234","PreviousPassword":"previous_password","ProposedPassword":"proposed_password"}"#)
+        .body(
+            r#"{"AccessToken":"access_token","PreviousPassword":"Passw0rd!","ProposedPassword":"NewPassw0rd!"}"#
+                .replace("access_token", result["AccessToken"].as_str().unwrap()),
+        )
         .reply(&filter)
         .await;
 
     assert_eq!(200, res.status());
     assert!(res.body().is_empty());
+    super::sign_in(&client_id, "username", "NewPassw0rd!").await;
 }
 
 #[tokio::test]
@@ -43,3 +51,69 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_incorrect_previous_password() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "ChangePassword",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "PreviousPassword": "wrong",
+            "ProposedPassword": "NewPassw0rd!",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_proposed_password_not_conform_to_policy() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "ChangePassword",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "PreviousPassword": "Passw0rd!",
+            "ProposedPassword": "password",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidPasswordException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_invalid_access_token() {
+    super::setup().await;
+
+    let res = super::post_action(
+        "ChangePassword",
+        r#"{"AccessToken":"access_token01234","PreviousPassword":"Passw0rd!","ProposedPassword":"NewPassw0rd!"}"#,
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
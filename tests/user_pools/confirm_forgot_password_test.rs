@@ -4,6 +4,20 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_verified_email(&user_pool_id, "username").await;
+    let body = serde_json::json!({"ClientId": client_id, "Username": "username"}).to_string();
+    assert_eq!(
+        200,
+        super::post_action("ForgotPassword", &body).await.status()
+    );
+    let code = super::issued_code(
+        &user_pool_id,
+        "username",
+        user_pools::CodePurpose::ForgotPassword,
+    );
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -18,18 +32,20 @@ async fn test_success_to_request() {
             "AnalyticsMetadata": { 
                "AnalyticsEndpointId": "string"
             },
-            "ClientId": "string",
+            "ClientId": "client_id",
             "ClientMetadata": { 
                "string" : "string" 
             },
-            "ConfirmationCode": "string",
-            "Password": "string",
+            "ConfirmationCode": "confirmation_code",
+            "Password": "NewPassw0rd!",
             "SecretHash": "string",
             "UserContextData": { 
                "EncodedData": "string"
             },
-            "Username": "string"
-         }"#,
+            "Username": "username"
+         }"#
+            .replace("client_id", &client_id)
+            .replace("confirmation_code", &code),
         )
         .reply(&filter)
         .await;
@@ -77,3 +93,90 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_password_not_conform_to_policy() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_verified_email(&user_pool_id, "username").await;
+    let body = serde_json::json!({"ClientId": client_id, "Username": "username"}).to_string();
+    assert_eq!(
+        200,
+        super::post_action("ForgotPassword", &body).await.status()
+    );
+    let code = super::issued_code(
+        &user_pool_id,
+        "username",
+        user_pools::CodePurpose::ForgotPassword,
+    );
+
+    let res = super::post_action(
+        "ConfirmForgotPassword",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "ConfirmationCode": code,
+            "Password": "password",
+            "Username": "username",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidPasswordException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_sign_in_with_new_password() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_verified_email(&user_pool_id, "username").await;
+    let body = serde_json::json!({"ClientId": client_id, "Username": "username"}).to_string();
+    assert_eq!(
+        200,
+        super::post_action("ForgotPassword", &body).await.status()
+    );
+    let code = super::issued_code(
+        &user_pool_id,
+        "username",
+        user_pools::CodePurpose::ForgotPassword,
+    );
+
+    let body = serde_json::json!({
+        "ClientId": client_id,
+        "ConfirmationCode": code,
+        "Password": "NewPassw0rd!",
+        "Username": "username",
+    })
+    .to_string();
+    assert_eq!(
+        200,
+        super::post_action("ConfirmForgotPassword", &body)
+            .await
+            .status()
+    );
+    // the code is expired once used
+    let res = super::post_action("ConfirmForgotPassword", &body).await;
+    assert_eq!(
+        "ExpiredCodeException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "NewPassw0rd!"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+}
@@ -4,6 +4,12 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"AutoVerifiedAttributes": ["email"]}))
+            .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::sign_up(&client_id, "username").await;
+    let code = super::issued_code(&user_pool_id, "username", user_pools::CodePurpose::SignUp);
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -18,18 +24,20 @@ async fn test_success_to_request() {
             "AnalyticsMetadata": { 
                "AnalyticsEndpointId": "string"
             },
-            "ClientId": "string",
+            "ClientId": "client_id",
             "ClientMetadata": { 
                "string" : "string" 
             },
-            "ConfirmationCode": "string",
+            "ConfirmationCode": "confirmation_code",
             "ForceAliasCreation": true,
             "SecretHash": "string",
             "UserContextData": { 
                "EncodedData": "string"
             },
-            "Username": "string"
-         }"#,
+            "Username": "username"
+         }"#
+            .replace("client_id", &client_id)
+            .replace("confirmation_code", &code),
         )
         .reply(&filter)
         .await;
@@ -77,3 +85,63 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_code_mismatch() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"AutoVerifiedAttributes": ["email"]}))
+            .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::sign_up(&client_id, "username").await;
+    let code = super::issued_code(&user_pool_id, "username", user_pools::CodePurpose::SignUp);
+    let wrong_code = if code == "000000" { "111111" } else { "000000" };
+
+    let res = super::post_action(
+        "ConfirmSignUp",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "ConfirmationCode": wrong_code,
+            "Username": "username",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "CodeMismatchException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_confirmed_user_has_verified_email() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"AutoVerifiedAttributes": ["email"]}))
+            .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::sign_up(&client_id, "username").await;
+    let code = super::issued_code(&user_pool_id, "username", user_pools::CodePurpose::SignUp);
+
+    let body = serde_json::json!({
+        "ClientId": client_id,
+        "ConfirmationCode": code,
+        "Username": "username",
+    })
+    .to_string();
+    let res = super::post_action("ConfirmSignUp", &body).await;
+    assert_eq!(200, res.status());
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "username", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("CONFIRMED", body["UserStatus"]);
+    assert!(body["UserAttributes"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!({"Name": "email_verified", "Value": "true"})));
+}
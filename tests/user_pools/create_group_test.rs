@@ -4,6 +4,7 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -20,7 +21,8 @@ async fn test_success_to_request() {
             "Precedence": 0,
             "RoleArn": "arn:aws:iam::123456789012:user/Development/product_1234",
             "UserPoolId": "user_pool_id"
-         }"#,
+         }"#
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
@@ -59,3 +61,21 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_group_exists() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_group(&user_pool_id, "group_name", None, None).await;
+
+    let res = super::post_action(
+        "CreateGroup",
+        &serde_json::json!({"GroupName": "group_name", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "GroupExistsException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
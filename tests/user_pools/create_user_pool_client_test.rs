@@ -4,6 +4,7 @@ use pretty_assertions::assert_eq;
 #[tokio::test]
 async fn test_success_to_request() {
     super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
 
     let filter = routes::user_pools_routes();
     let res = warp::test::request()
@@ -45,7 +46,8 @@ async fn test_success_to_request() {
             },
             "UserPoolId": "user_pool_id",
             "WriteAttributes": [ "string" ]
-         }"#,
+         }"#
+            .replace("user_pool_id", &user_pool_id),
         )
         .reply(&filter)
         .await;
@@ -109,3 +111,50 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_created_client_has_id_and_secret() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let res = super::post_action(
+        "CreateUserPoolClient",
+        &serde_json::json!({
+            "ClientName": "client_name",
+            "GenerateSecret": true,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(user_pool_id, body["UserPoolClient"]["UserPoolId"]);
+    assert_eq!(
+        26,
+        body["UserPoolClient"]["ClientId"].as_str().unwrap().len()
+    );
+    assert!(body["UserPoolClient"]["ClientSecret"].is_string());
+}
+
+#[tokio::test]
+async fn test_unknown_client() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let res = super::post_action(
+        "AdminInitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "ClientId": "unknown",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
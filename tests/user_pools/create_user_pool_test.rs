@@ -265,3 +265,38 @@ async fn test_failure_to_request() {
         res.headers().get("x-amzn-ErrorType").unwrap()
     )
 }
+
+#[tokio::test]
+async fn test_created_user_pool_has_id() {
+    super::setup().await;
+
+    let res = super::post_action(
+        "CreateUserPool",
+        r#"{"PoolName":"pool_name","MfaConfiguration":"OPTIONAL"}"#,
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let id = body["UserPool"]["Id"].as_str().unwrap();
+    assert!(regex::Regex::new(r"^us-east-1_[0-9a-zA-Z]{9}$")
+        .unwrap()
+        .is_match(id));
+    assert_eq!("pool_name", body["UserPool"]["Name"]);
+    assert_eq!("OPTIONAL", body["UserPool"]["MfaConfiguration"]);
+}
+
+#[tokio::test]
+async fn test_unknown_user_pool() {
+    super::setup().await;
+
+    let res = super::post_action(
+        "AdminGetUser",
+        r#"{"Username":"username","UserPoolId":"us-east-1_unknown"}"#,
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,59 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
+    super::create_group(&user_pool_id, "group_name", None, None).await;
+    super::add_user_to_group(&user_pool_id, "username", "group_name").await;
+
+    let res = super::post_action(
+        "DeleteGroup",
+        &serde_json::json!({"GroupName": "group_name", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    assert!(res.body().is_empty());
+
+    user_pools::with_store(|store| {
+        let pool = store.user_pool(&user_pool_id).unwrap();
+        assert!(pool.groups.is_empty());
+        assert!(pool.user("username").unwrap().groups.is_empty());
+    });
+
+    let res = super::post_action(
+        "DeleteGroup",
+        &serde_json::json!({"GroupName": "group_name", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteGroup",
+        )
+        .body(r#"{"GroupName":"","UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
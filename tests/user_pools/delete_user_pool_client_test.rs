@@ -0,0 +1,43 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    let body = serde_json::json!({ "ClientId": client_id, "UserPoolId": user_pool_id }).to_string();
+
+    let res = super::post_action("DeleteUserPoolClient", &body).await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+
+    let res = super::post_action("DescribeUserPoolClient", &body).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteUserPoolClient",
+        )
+        .body(r#"{"ClientId":"","UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
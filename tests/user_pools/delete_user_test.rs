@@ -0,0 +1,53 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "DeleteUser",
+        &serde_json::json!({"AccessToken": result["AccessToken"]}).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    assert!(res.body().is_empty());
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "username", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UserNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DeleteUser",
+        )
+        .body(r#"{"AccessToken":""}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,44 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let res = super::post_action(
+        "DescribeUserPoolClient",
+        &serde_json::json!({ "ClientId": client_id, "UserPoolId": user_pool_id }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(client_id, body["UserPoolClient"]["ClientId"]);
+    assert_eq!("client_name", body["UserPoolClient"]["ClientName"]);
+    assert_eq!(user_pool_id, body["UserPoolClient"]["UserPoolId"]);
+    assert!(body["UserPoolClient"]["CreationDate"].is_i64());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeUserPoolClient",
+        )
+        .body(r#"{"ClientId":"","UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,43 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let res = super::post_action(
+        "DescribeUserPool",
+        &serde_json::json!({ "UserPoolId": user_pool_id }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(user_pool_id, body["UserPool"]["Id"]);
+    assert_eq!("pool_name", body["UserPool"]["Name"]);
+    assert!(body["UserPool"]["CreationDate"].is_i64());
+    assert!(body["UserPool"]["LastModifiedDate"].is_i64());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.DescribeUserPool",
+        )
+        .body(r#"{"UserPoolId":""}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
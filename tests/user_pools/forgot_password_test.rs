@@ -0,0 +1,152 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_verified_email(&user_pool_id, "username").await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ForgotPassword",
+        )
+        .body(
+            r#"{
+            "AnalyticsMetadata": { 
+               "AnalyticsEndpointId": "string"
+            },
+            "ClientId": "client_id",
+            "ClientMetadata": { 
+               "string" : "string" 
+            },
+            "UserContextData": { 
+               "EncodedData": "string"
+            },
+            "Username": "username"
+         }"#
+            .replace("client_id", &client_id),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "AttributeName": "email",
+            "DeliveryMedium": "EMAIL",
+            "Destination": "u***@e***",
+        }),
+        body["CodeDeliveryDetails"]
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ForgotPassword",
+        )
+        .body(r#"{"ClientId": "string", "Username": ""}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_no_verified_destination() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "ForgotPassword",
+        &serde_json::json!({"ClientId": client_id, "Username": "username"}).to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_user_migration_forgot_password() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "UserMigration": super::lambda_arn("user-migration"),
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let res = super::post_action(
+        "ForgotPassword",
+        &serde_json::json!({"ClientId": client_id, "Username": "legacy"}).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("EMAIL", body["CodeDeliveryDetails"]["DeliveryMedium"]);
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "legacy", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("RESET_REQUIRED", body["UserStatus"]);
+}
+
+#[tokio::test]
+async fn test_user_migration_forgot_password_without_password() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "UserMigration": super::lambda_arn("user-migration"),
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let res = super::post_action(
+        "ForgotPassword",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "ClientMetadata": {"finalUserStatus": "CONFIRMED"},
+            "Username": "legacy",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "legacy", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("RESET_REQUIRED", body["UserStatus"]);
+}
@@ -0,0 +1,67 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_group(
+        &user_pool_id,
+        "group_name",
+        Some(1),
+        Some("arn:aws:iam::123456789012:role/group_role"),
+    )
+    .await;
+
+    let res = super::post_action(
+        "GetGroup",
+        &serde_json::json!({"GroupName": "group_name", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("group_name", body["Group"]["GroupName"]);
+    assert_eq!(1, body["Group"]["Precedence"]);
+    assert_eq!(
+        "arn:aws:iam::123456789012:role/group_role",
+        body["Group"]["RoleArn"]
+    );
+    assert_eq!(user_pool_id.as_str(), body["Group"]["UserPoolId"]);
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.GetGroup")
+        .body(r#"{"GroupName":"group_name","UserPoolId":""}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_group_not_found() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let res = super::post_action(
+        "GetGroup",
+        &serde_json::json!({"GroupName": "group_name", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
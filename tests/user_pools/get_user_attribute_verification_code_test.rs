@@ -0,0 +1,85 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_phone_number(&user_pool_id, "username").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "GetUserAttributeVerificationCode",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "AttributeName": "phone_number",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("SMS", body["CodeDeliveryDetails"]["DeliveryMedium"]);
+    let messages = super::sent_messages(&user_pool_id, "username").await;
+    assert_eq!(
+        "VerifyUserAttribute",
+        messages.last().unwrap()["MessageType"]
+    );
+    assert_eq!(
+        super::issued_code(
+            &user_pool_id,
+            "username",
+            user_pools::CodePurpose::VerifyAttribute("phone_number".to_string()),
+        ),
+        messages.last().unwrap()["Code"]
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
+        )
+        .body(r#"{"AccessToken":"access_token","AttributeName":""}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_attribute_without_value() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "GetUserAttributeVerificationCode",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "AttributeName": "email",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
@@ -0,0 +1,57 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_verified_email(&user_pool_id, "username").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "GetUser",
+        &serde_json::json!({"AccessToken": result["AccessToken"]}).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("username", body["Username"]);
+    assert!(body["UserAttributes"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!({"Name": "email", "Value": "user@example.com"})));
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.GetUser")
+        .body(r#"{"AccessToken":""}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_invalid_access_token() {
+    super::setup().await;
+
+    let res = super::post_action("GetUser", r#"{"AccessToken":"access_token"}"#).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
@@ -0,0 +1,533 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.InitiateAuth",
+        )
+        .body(
+            r#"{
+            "AnalyticsMetadata": { 
+               "AnalyticsEndpointId": "string"
+            },
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { 
+               "USERNAME" : "username",
+               "PASSWORD" : "Passw0rd!"
+            },
+            "ClientId": "client_id",
+            "ClientMetadata": { 
+               "string" : "string" 
+            },
+            "UserContextData": { 
+               "EncodedData": "string"
+            }
+         }"#
+            .replace("client_id", &client_id),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(!res.body().is_empty());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.InitiateAuth",
+        )
+        .body(
+            r#"{
+            "AnalyticsMetadata": { 
+               "AnalyticsEndpointId": "string"
+            },
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": { 
+               "string" : "string" 
+            },
+            "ClientId": "",
+            "ClientMetadata": { 
+               "string" : "string" 
+            },
+            "UserContextData": { 
+               "EncodedData": "string"
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_refresh_token_auth() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let refresh_token = body["AuthenticationResult"]["RefreshToken"].clone();
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "REFRESH_TOKEN_AUTH",
+            "AuthParameters": {"REFRESH_TOKEN": refresh_token},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let result = &body["AuthenticationResult"];
+    assert!(result.get("RefreshToken").is_none());
+    let access_claims = user_pools::verify(result["AccessToken"].as_str().unwrap()).unwrap();
+    assert_eq!("username", access_claims["username"]);
+}
+
+#[tokio::test]
+async fn test_admin_auth_flow_is_not_supported() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "ADMIN_USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_user_srp_auth_returns_password_verifier() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_SRP_AUTH",
+            "AuthParameters": {"USERNAME": "username", "SRP_A": "abcdef0123456789"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("PASSWORD_VERIFIER", body["ChallengeName"]);
+    let params = &body["ChallengeParameters"];
+    assert_eq!("username", params["USER_ID_FOR_SRP"]);
+    for name in ["SALT", "SECRET_BLOCK", "SRP_B"] {
+        assert!(params[name].is_string(), "{}", name);
+    }
+}
+
+async fn create_custom_auth_user_pool() -> String {
+    super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "DefineAuthChallenge": super::lambda_arn("define-auth-challenge"),
+            "CreateAuthChallenge": super::lambda_arn("create-auth-challenge"),
+            "VerifyAuthChallengeResponse": super::lambda_arn("verify-auth-challenge-response"),
+        },
+    }))
+    .await
+}
+
+async fn respond_to_custom_challenge(
+    client_id: &str,
+    session: &serde_json::Value,
+    answer: &str,
+) -> warp::http::Response<bytes::Bytes> {
+    super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "CUSTOM_CHALLENGE",
+            "ChallengeResponses": {"USERNAME": "username", "ANSWER": answer},
+            "ClientId": client_id,
+            "Session": session,
+        })
+        .to_string(),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_custom_auth() {
+    super::setup().await;
+    let user_pool_id = create_custom_auth_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "CUSTOM_AUTH",
+            "AuthParameters": {"USERNAME": "username"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let challenge: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("CUSTOM_CHALLENGE", challenge["ChallengeName"]);
+    assert_eq!("1", challenge["ChallengeParameters"]["attempt"]);
+    assert_eq!("username", challenge["ChallengeParameters"]["USERNAME"]);
+
+    // a wrong answer is recorded to the session and the next challenge is created
+    let res = respond_to_custom_challenge(&client_id, &challenge["Session"], "wrong").await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let challenge: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("CUSTOM_CHALLENGE", challenge["ChallengeName"]);
+    assert_eq!("2", challenge["ChallengeParameters"]["attempt"]);
+
+    let res = respond_to_custom_challenge(&client_id, &challenge["Session"], "answer-2").await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body["AuthenticationResult"]["AccessToken"].is_string());
+}
+
+#[tokio::test]
+async fn test_custom_auth_fails_by_trigger() {
+    super::setup().await;
+    let user_pool_id = create_custom_auth_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "CUSTOM_AUTH",
+            "AuthParameters": {"USERNAME": "username"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    let mut challenge: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    for _ in 0..2 {
+        let res = respond_to_custom_challenge(&client_id, &challenge["Session"], "wrong").await;
+        assert_eq!(200, res.status(), "{:?}", res.body());
+        challenge = serde_json::from_slice(res.body()).unwrap();
+    }
+    let res = respond_to_custom_challenge(&client_id, &challenge["Session"], "wrong").await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_custom_auth_without_trigger() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "CUSTOM_AUTH",
+            "AuthParameters": {"USERNAME": "username"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_authentication_triggers() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "PreAuthentication": super::lambda_arn("pre-authentication"),
+            "PostAuthentication": super::lambda_arn("post-authentication"),
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    assert!(result["AccessToken"].is_string());
+
+    for client_metadata in [
+        serde_json::json!({"blocked": "true"}),
+        serde_json::json!({"fail": "true"}),
+    ] {
+        let res = super::post_action(
+            "InitiateAuth",
+            &serde_json::json!({
+                "AuthFlow": "USER_PASSWORD_AUTH",
+                "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+                "ClientId": client_id,
+                "ClientMetadata": client_metadata,
+            })
+            .to_string(),
+        )
+        .await;
+        assert_eq!(400, res.status());
+        assert_eq!(
+            "UserLambdaValidationException",
+            res.headers().get("x-amzn-ErrorType").unwrap()
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_group_claims() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let id_claims = user_pools::verify(result["IdToken"].as_str().unwrap()).unwrap();
+    assert!(id_claims.get("cognito:groups").is_none());
+
+    let admin_role = "arn:aws:iam::123456789012:role/admin";
+    let reader_role = "arn:aws:iam::123456789012:role/reader";
+    super::create_group(&user_pool_id, "readers", Some(10), Some(reader_role)).await;
+    super::create_group(&user_pool_id, "admins", Some(1), Some(admin_role)).await;
+    super::create_group(&user_pool_id, "users", None, None).await;
+    for group in &["readers", "admins", "users"] {
+        super::add_user_to_group(&user_pool_id, "username", group).await;
+    }
+
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let id_claims = user_pools::verify(result["IdToken"].as_str().unwrap()).unwrap();
+    assert_eq!(
+        serde_json::json!(["admins", "readers", "users"]),
+        id_claims["cognito:groups"]
+    );
+    assert_eq!(
+        serde_json::json!([admin_role, reader_role]),
+        id_claims["cognito:roles"]
+    );
+    assert_eq!(admin_role, id_claims["cognito:preferred_role"]);
+    let access_claims = user_pools::verify(result["AccessToken"].as_str().unwrap()).unwrap();
+    assert_eq!(
+        serde_json::json!(["admins", "readers", "users"]),
+        access_claims["cognito:groups"]
+    );
+    assert!(access_claims.get("cognito:roles").is_none());
+}
+
+#[tokio::test]
+async fn test_pre_token_generation_v1() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "PreTokenGeneration": super::lambda_arn("pre-token-generation"),
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_verified_email(&user_pool_id, "username").await;
+    super::create_group(&user_pool_id, "users", None, None).await;
+    super::add_user_to_group(&user_pool_id, "username", "users").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+            "ClientMetadata": {"tenant": "tenant-a"},
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let result = &body["AuthenticationResult"];
+    let id_claims = user_pools::verify(result["IdToken"].as_str().unwrap()).unwrap();
+    assert_eq!("tenant-a", id_claims["custom:tenant"]);
+    assert_eq!(
+        "TokenGeneration_Authentication",
+        id_claims["custom:trigger_source"]
+    );
+    assert_eq!("1", id_claims["custom:event_version"]);
+    assert_eq!(
+        serde_json::json!(["users", "admin"]),
+        id_claims["cognito:groups"]
+    );
+    assert!(id_claims.get("email").is_none());
+    assert_eq!("username", id_claims["cognito:username"]);
+    let access_claims = user_pools::verify(result["AccessToken"].as_str().unwrap()).unwrap();
+    assert!(access_claims.get("custom:tenant").is_none());
+    assert_eq!(
+        serde_json::json!(["users", "admin"]),
+        access_claims["cognito:groups"]
+    );
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "REFRESH_TOKEN_AUTH",
+            "AuthParameters": {"REFRESH_TOKEN": result["RefreshToken"]},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let id_claims =
+        user_pools::verify(body["AuthenticationResult"]["IdToken"].as_str().unwrap()).unwrap();
+    assert_eq!("default", id_claims["custom:tenant"]);
+    assert_eq!(
+        "TokenGeneration_RefreshTokens",
+        id_claims["custom:trigger_source"]
+    );
+}
+
+#[tokio::test]
+async fn test_pre_token_generation_v2() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "PreTokenGenerationConfig": {
+                "LambdaArn": super::lambda_arn("pre-token-generation-v2"),
+                "LambdaVersion": "V2_0",
+            },
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let id_claims = user_pools::verify(result["IdToken"].as_str().unwrap()).unwrap();
+    assert_eq!("default", id_claims["custom:tenant"]);
+    assert_eq!("2", id_claims["custom:event_version"]);
+    let access_claims = user_pools::verify(result["AccessToken"].as_str().unwrap()).unwrap();
+    assert_eq!("default", access_claims["custom:tenant"]);
+    assert_eq!(
+        "aws.cognito.signin.user.admin tenant/default",
+        access_claims["scope"]
+    );
+    assert_eq!(
+        serde_json::json!(["admin"]),
+        access_claims["cognito:groups"]
+    );
+}
+
+async fn sign_in_legacy(
+    client_id: &str,
+    password: &str,
+    client_metadata: serde_json::Value,
+) -> warp::http::Response<bytes::Bytes> {
+    super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "legacy", "PASSWORD": password},
+            "ClientId": client_id,
+            "ClientMetadata": client_metadata,
+        })
+        .to_string(),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_user_migration_authentication() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "UserMigration": super::lambda_arn("user-migration"),
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let res = sign_in_legacy(&client_id, "Wr0ngPassword!", serde_json::json!({})).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UserLambdaValidationException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = sign_in_legacy(
+        &client_id,
+        "Legacy0rd!",
+        serde_json::json!({"messageAction": "RESEND"}),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let id_claims =
+        user_pools::verify(body["AuthenticationResult"]["IdToken"].as_str().unwrap()).unwrap();
+    assert_eq!("legacy@example.com", id_claims["email"]);
+    let messages = super::sent_messages(&user_pool_id, "legacy").await;
+    assert_eq!(1, messages.len());
+    assert_eq!("Welcome", messages[0]["MessageType"]);
+    assert_eq!("Your username is legacy. ", messages[0]["Body"]);
+
+    // the migrated user signs in with the password without the trigger.
+    let res = sign_in_legacy(&client_id, "Wr0ngPassword!", serde_json::json!({})).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
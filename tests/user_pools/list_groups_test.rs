@@ -0,0 +1,99 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_group(&user_pool_id, "group_b", None, None).await;
+    super::create_group(&user_pool_id, "group_a", None, None).await;
+
+    let res = super::post_action(
+        "ListGroups",
+        &serde_json::json!({ "UserPoolId": user_pool_id }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let names = body["Groups"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|group| group["GroupName"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["group_a", "group_b"], names);
+}
+
+#[tokio::test]
+async fn test_pagination() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    for group_name in &["group_c", "group_a", "group_b"] {
+        super::create_group(&user_pool_id, group_name, None, None).await;
+    }
+
+    let res = super::post_action(
+        "ListGroups",
+        &serde_json::json!({ "Limit": 2, "UserPoolId": user_pool_id }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(2, body["Groups"].as_array().unwrap().len());
+    let next_token = body["NextToken"].as_str().unwrap().to_string();
+
+    let res = super::post_action(
+        "ListGroups",
+        &serde_json::json!({
+            "Limit": 2,
+            "NextToken": next_token,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("group_c", body["Groups"][0]["GroupName"]);
+    assert!(body.get("NextToken").is_none());
+
+    let tampered = format!("{}x", next_token);
+    let res = super::post_action(
+        "ListGroups",
+        &serde_json::json!({
+            "Limit": 2,
+            "NextToken": tampered,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListGroups",
+        )
+        .body(r#"{"Limit":61,"UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,70 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    for provider_name in &["provider_b", "provider_a"] {
+        let res = super::post_action(
+            "CreateIdentityProvider",
+            &serde_json::json!({
+                "ProviderDetails": { "MetadataURL": "https://example.com/metadata" },
+                "ProviderName": provider_name,
+                "ProviderType": "SAML",
+                "UserPoolId": user_pool_id,
+            })
+            .to_string(),
+        )
+        .await;
+        assert_eq!(200, res.status(), "{:?}", res.body());
+    }
+
+    let res = super::post_action(
+        "ListIdentityProviders",
+        &serde_json::json!({ "MaxResults": 1, "UserPoolId": user_pool_id }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("provider_a", body["Providers"][0]["ProviderName"]);
+    assert_eq!("SAML", body["Providers"][0]["ProviderType"]);
+
+    let res = super::post_action(
+        "ListIdentityProviders",
+        &serde_json::json!({
+            "MaxResults": 1,
+            "NextToken": body["NextToken"],
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("provider_b", body["Providers"][0]["ProviderName"]);
+    assert!(body.get("NextToken").is_none());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListIdentityProviders",
+        )
+        .body(r#"{"MaxResults":61,"UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,56 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::post_action(
+        "CreateResourceServer",
+        &serde_json::json!({
+            "Identifier": "https://api.example.com",
+            "Name": "api",
+            "Scopes": [{ "ScopeDescription": "Read access", "ScopeName": "read" }],
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+
+    let res = super::post_action(
+        "ListResourceServers",
+        &serde_json::json!({ "MaxResults": 10, "UserPoolId": user_pool_id }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let servers = body["ResourceServers"].as_array().unwrap();
+    assert_eq!(1, servers.len());
+    assert_eq!("https://api.example.com", servers[0]["Identifier"]);
+    assert_eq!("read", servers[0]["Scopes"][0]["ScopeName"]);
+    assert!(body.get("NextToken").is_none());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListResourceServers",
+        )
+        .body(r#"{"MaxResults":51,"UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,57 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::post_action(
+        "CreateUserImportJob",
+        &serde_json::json!({
+            "CloudWatchLogsRoleArn": "arn:aws:iam::123456789012:role/import",
+            "JobName": "job_name",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let job_id = body["UserImportJob"]["JobId"].clone();
+
+    let res = super::post_action(
+        "ListUserImportJobs",
+        &serde_json::json!({ "MaxResults": 10, "UserPoolId": user_pool_id }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let jobs = body["UserImportJobs"].as_array().unwrap();
+    assert_eq!(1, jobs.len());
+    assert_eq!(job_id, jobs[0]["JobId"]);
+    assert_eq!("job_name", jobs[0]["JobName"]);
+    assert!(body.get("PaginationToken").is_none());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUserImportJobs",
+        )
+        .body(r#"{"UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
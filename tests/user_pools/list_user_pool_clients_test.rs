@@ -0,0 +1,64 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let mut client_ids = vec![
+        super::create_user_pool_client(&user_pool_id).await,
+        super::create_user_pool_client(&user_pool_id).await,
+        super::create_user_pool_client(&user_pool_id).await,
+    ];
+    client_ids.sort();
+
+    let mut listed = vec![];
+    let mut next_token = serde_json::Value::Null;
+    loop {
+        let res = super::post_action(
+            "ListUserPoolClients",
+            &common::without_nulls(serde_json::json!({
+                "MaxResults": 2,
+                "NextToken": next_token,
+                "UserPoolId": user_pool_id,
+            }))
+            .to_string(),
+        )
+        .await;
+        assert_eq!(200, res.status(), "{:?}", res.body());
+        let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+        for client in body["UserPoolClients"].as_array().unwrap() {
+            assert_eq!("client_name", client["ClientName"]);
+            assert_eq!(user_pool_id, client["UserPoolId"]);
+            listed.push(client["ClientId"].as_str().unwrap().to_string());
+        }
+        next_token = body["NextToken"].clone();
+        if next_token.is_null() {
+            break;
+        }
+    }
+    assert_eq!(client_ids, listed);
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUserPoolClients",
+        )
+        .body(r#"{"MaxResults":61,"UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,62 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let mut found = None;
+    let mut next_token = serde_json::Value::Null;
+    loop {
+        let res = super::post_action(
+            "ListUserPools",
+            &common::without_nulls(serde_json::json!({
+                "MaxResults": 60,
+                "NextToken": next_token,
+            }))
+            .to_string(),
+        )
+        .await;
+        assert_eq!(200, res.status(), "{:?}", res.body());
+        let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+        found = found.or_else(|| {
+            body["UserPools"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|pool| pool["Id"] == user_pool_id.as_str())
+                .cloned()
+        });
+        next_token = body["NextToken"].clone();
+        if next_token.is_null() {
+            break;
+        }
+    }
+    let pool = found.unwrap();
+    assert_eq!("pool_name", pool["Name"]);
+    assert!(pool["CreationDate"].is_i64());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUserPools",
+        )
+        .body(r#"{"MaxResults":0}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,46 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "member").await;
+    super::create_user(&user_pool_id, "other").await;
+    super::create_group(&user_pool_id, "group_name", None, None).await;
+    super::add_user_to_group(&user_pool_id, "member", "group_name").await;
+
+    let res = super::post_action(
+        "ListUsersInGroup",
+        &serde_json::json!({"GroupName": "group_name", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let users = body["Users"].as_array().unwrap();
+    assert_eq!(1, users.len());
+    assert_eq!("member", users[0]["Username"]);
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUsersInGroup",
+        )
+        .body(r#"{"UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,130 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+async fn list_users(body: serde_json::Value) -> serde_json::Value {
+    let res = super::post_action("ListUsers", &body.to_string()).await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    serde_json::from_slice(res.body()).unwrap()
+}
+
+fn usernames(body: &serde_json::Value) -> Vec<&str> {
+    body["Users"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|user| user["Username"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "alice").await;
+    super::create_user(&user_pool_id, "bob").await;
+    super::set_verified_email(&user_pool_id, "bob").await;
+
+    let body = list_users(serde_json::json!({ "UserPoolId": user_pool_id })).await;
+    assert_eq!(vec!["alice", "bob"], usernames(&body));
+    assert!(body.get("PaginationToken").is_none());
+
+    let body = list_users(serde_json::json!({
+        "AttributesToGet": ["email"],
+        "Filter": "email ^= \"user@\"",
+        "UserPoolId": user_pool_id,
+    }))
+    .await;
+    assert_eq!(vec!["bob"], usernames(&body));
+    assert_eq!(
+        serde_json::json!([{"Name": "email", "Value": "user@example.com"}]),
+        body["Users"][0]["Attributes"]
+    );
+
+    let body = list_users(serde_json::json!({
+        "Filter": "status = \"Enabled\"",
+        "UserPoolId": user_pool_id,
+    }))
+    .await;
+    assert_eq!(vec!["alice", "bob"], usernames(&body));
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ListUsers",
+        )
+        .body(r#"{"Limit":61,"UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_pagination() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    for username in &["user1", "user2", "user3"] {
+        super::create_user(&user_pool_id, username).await;
+    }
+
+    let body = list_users(serde_json::json!({"Limit": 2, "UserPoolId": user_pool_id})).await;
+    assert_eq!(vec!["user1", "user2"], usernames(&body));
+    let token = body["PaginationToken"].clone();
+
+    // the token stays valid after the listed users are changed
+    super::create_user(&user_pool_id, "user0").await;
+    let body = list_users(serde_json::json!({
+        "Limit": 2,
+        "PaginationToken": token,
+        "UserPoolId": user_pool_id,
+    }))
+    .await;
+    assert_eq!(vec!["user3"], usernames(&body));
+    assert!(body.get("PaginationToken").is_none());
+
+    let res = super::post_action(
+        "ListUsers",
+        &serde_json::json!({
+            "PaginationToken": format!("x{}", token.as_str().unwrap()),
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_unsupported_filter() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    for filter in &["address = \"x\"", "email = bob"] {
+        let res = super::post_action(
+            "ListUsers",
+            &serde_json::json!({"Filter": filter, "UserPoolId": user_pool_id}).to_string(),
+        )
+        .await;
+        assert_eq!(400, res.status());
+        assert_eq!(
+            "InvalidParameterException",
+            res.headers().get("x-amzn-ErrorType").unwrap()
+        );
+    }
+}
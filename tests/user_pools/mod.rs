@@ -38,6 +38,41 @@ mod create_resource_server_test;
 mod create_user_import_job_test;
 mod create_user_pool_client_test;
 mod create_user_pool_test;
+mod delete_group_test;
+mod delete_user_attributes_test;
+mod delete_user_pool_client_test;
+mod delete_user_pool_test;
+mod delete_user_test;
+mod describe_user_pool_client_test;
+mod describe_user_pool_test;
+mod forgot_password_test;
+mod get_group_test;
+mod get_user_attribute_verification_code_test;
+mod get_user_test;
+mod global_sign_out_test;
+mod initiate_auth_test;
+mod list_groups_test;
+mod list_identity_providers_test;
+mod list_resource_servers_test;
+mod list_user_import_jobs_test;
+mod list_user_pool_clients_test;
+mod list_user_pools_test;
+mod list_users_in_group_test;
+mod list_users_test;
+mod oauth2_test;
+mod outbox_test;
+mod resend_confirmation_code_test;
+mod respond_to_auth_challenge_test;
+mod revoke_token_test;
+mod set_user_mfa_preference_test;
+mod sign_up_test;
+mod update_group_test;
+mod update_user_attributes_test;
+mod update_user_pool_client_test;
+mod update_user_pool_test;
+mod verify_software_token_test;
+mod verify_user_attribute_test;
+mod well_known_test;
 
 pub async fn setup() {
     opts::init_fake_opt().await;
@@ -48,3 +83,269 @@ pub async fn setup() {
         templates::init_default_template()
     );
 }
+
+/// Sends a request for the action to the user pools routes.
+pub async fn post_action(action: &str, body: &str) -> warp::http::Response<bytes::Bytes> {
+    let filter = routes::user_pools_routes();
+    warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            format!("AWSCognitoIdentityProviderService.{}", action),
+        )
+        .body(body)
+        .reply(&filter)
+        .await
+}
+
+/// Creates a user for following requests.
+pub async fn create_user(user_pool_id: &str, username: &str) {
+    let res = post_action(
+        "AdminCreateUser",
+        &serde_json::json!({
+            "Username": username,
+            "UserPoolId": user_pool_id,
+            "TemporaryPassword": "Passw0rd!",
+            "MessageAction": "SUPPRESS",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+}
+
+/// Creates a user pool and returns its id.
+pub async fn create_user_pool() -> String {
+    create_user_pool_with(serde_json::json!({})).await
+}
+
+/// Creates a user pool with the settings of CreateUserPool request and returns its id.
+pub async fn create_user_pool_with(mut config: serde_json::Value) -> String {
+    config["PoolName"] = serde_json::json!("pool_name");
+    let res = post_action("CreateUserPool", &config.to_string()).await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    body["UserPool"]["Id"].as_str().unwrap().to_string()
+}
+
+/// Creates a user pool client and returns its id.
+pub async fn create_user_pool_client(user_pool_id: &str) -> String {
+    let res = post_action(
+        "CreateUserPoolClient",
+        &serde_json::json!({
+            "ClientName": "client_name",
+            "ExplicitAuthFlows": [
+                "ALLOW_ADMIN_USER_PASSWORD_AUTH",
+                "ALLOW_CUSTOM_AUTH",
+                "ALLOW_USER_PASSWORD_AUTH",
+                "ALLOW_USER_SRP_AUTH",
+                "ALLOW_REFRESH_TOKEN_AUTH",
+            ],
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    body["UserPoolClient"]["ClientId"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+/// Creates a group with the precedence and IAM role.
+pub async fn create_group(
+    user_pool_id: &str,
+    group_name: &str,
+    precedence: Option<i64>,
+    role_arn: Option<&str>,
+) {
+    let res = post_action(
+        "CreateGroup",
+        &serde_json::json!({
+            "GroupName": group_name,
+            "Precedence": precedence,
+            "RoleArn": role_arn,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+}
+
+pub async fn add_user_to_group(user_pool_id: &str, username: &str, group_name: &str) {
+    let res = post_action(
+        "AdminAddUserToGroup",
+        &serde_json::json!({
+            "GroupName": group_name,
+            "Username": username,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+}
+
+/// Signs up an unconfirmed user with an email.
+pub async fn sign_up(client_id: &str, username: &str) {
+    let res = post_action(
+        "SignUp",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "Password": "Passw0rd!",
+            "UserAttributes": [{"Name": "email", "Value": "user@example.com"}],
+            "Username": username,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+}
+
+/// Sets a verified email to the user.
+pub async fn set_verified_email(user_pool_id: &str, username: &str) {
+    let res = post_action(
+        "AdminUpdateUserAttributes",
+        &serde_json::json!({
+            "UserAttributes": [
+                {"Name": "email", "Value": "user@example.com"},
+                {"Name": "email_verified", "Value": "true"},
+            ],
+            "Username": username,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+}
+
+/// Sets a phone number to the user.
+pub async fn set_phone_number(user_pool_id: &str, username: &str) {
+    let res = post_action(
+        "AdminUpdateUserAttributes",
+        &serde_json::json!({
+            "UserAttributes": [{"Name": "phone_number", "Value": "+12345556789"}],
+            "Username": username,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+}
+
+/// Returns the issued verification code of the user.
+pub fn issued_code(user_pool_id: &str, username: &str, purpose: user_pools::CodePurpose) -> String {
+    user_pools::with_store(|store| {
+        store
+            .user_pool(user_pool_id)
+            .unwrap()
+            .user(username)
+            .unwrap()
+            .codes[&purpose]
+            .code
+            .clone()
+    })
+}
+
+/// Returns messages sent to the user through the outbox endpoint.
+pub async fn sent_messages(user_pool_id: &str, username: &str) -> Vec<serde_json::Value> {
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path(&format!(
+            "/_outbox?UserPoolId={}&Username={}",
+            user_pool_id, username
+        ))
+        .reply(&filter)
+        .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    body["Messages"].as_array().unwrap().clone()
+}
+
+/// Creates a confirmed user with a permanent password.
+pub async fn create_confirmed_user(user_pool_id: &str, username: &str, password: &str) {
+    create_user(user_pool_id, username).await;
+    let res = post_action(
+        "AdminSetUserPassword",
+        &serde_json::json!({
+            "Password": password,
+            "Permanent": true,
+            "Username": username,
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+}
+
+/// Signs in with the password and returns the authentication result.
+pub async fn sign_in(client_id: &str, username: &str, password: &str) -> serde_json::Value {
+    let res = post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": username, "PASSWORD": password},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    body["AuthenticationResult"].clone()
+}
+
+/// Returns the ARN of the lambda function,
+/// triggers are handled by python scripts in `resources/test/hooks/triggers`.
+pub fn lambda_arn(function_name: &str) -> String {
+    format!(
+        "arn:aws:lambda:us-east-1:123456789012:function:{}",
+        function_name
+    )
+}
+
+/// Returns the current TOTP code of the software token secret.
+pub fn totp_code(secret: &str) -> String {
+    user_pools::totp_code(secret, chrono::Utc::now().timestamp()).unwrap()
+}
+
+/// Associates, verifies and enables a software token of the signed in user,
+/// and returns its secret.
+pub async fn enable_software_token_mfa(access_token: &str) -> String {
+    let res = post_action(
+        "AssociateSoftwareToken",
+        &serde_json::json!({ "AccessToken": access_token }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let secret = body["SecretCode"].as_str().unwrap().to_string();
+
+    let res = post_action(
+        "VerifySoftwareToken",
+        &serde_json::json!({ "AccessToken": access_token, "UserCode": totp_code(&secret) })
+            .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+
+    let res = post_action(
+        "SetUserMFAPreference",
+        &serde_json::json!({
+            "AccessToken": access_token,
+            "SoftwareTokenMfaSettings": {"Enabled": true, "PreferredMfa": true},
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    secret
+}
@@ -0,0 +1,458 @@
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+const CALLBACK_URL: &str = "https://example.com/callback";
+const LOGOUT_URL: &str = "https://example.com/logout";
+const CODE_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+
+/// Creates a client of the hosted UI and returns its id and secret.
+async fn create_oauth_client(
+    user_pool_id: &str,
+    flows: &[&str],
+    scopes: &[&str],
+    generate_secret: bool,
+) -> (String, Option<String>) {
+    let res = super::post_action(
+        "CreateUserPoolClient",
+        &serde_json::json!({
+            "AllowedOAuthFlows": flows,
+            "AllowedOAuthFlowsUserPoolClient": true,
+            "AllowedOAuthScopes": scopes,
+            "CallbackURLs": [CALLBACK_URL],
+            "ClientName": "client_name",
+            "GenerateSecret": generate_secret,
+            "LogoutURLs": [LOGOUT_URL],
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let client = &body["UserPoolClient"];
+    (
+        client["ClientId"].as_str().unwrap().to_string(),
+        client["ClientSecret"].as_str().map(str::to_string),
+    )
+}
+
+async fn get(path: &str) -> warp::http::Response<bytes::Bytes> {
+    warp::test::request()
+        .method("GET")
+        .path(path)
+        .reply(&routes::oauth2_routes())
+        .await
+}
+
+async fn post_form(
+    path: &str,
+    form: &[(&str, &str)],
+    authorization: Option<&str>,
+) -> warp::http::Response<bytes::Bytes> {
+    let mut request = warp::test::request()
+        .method("POST")
+        .path(path)
+        .header("content-type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(form).unwrap());
+    if let Some(authorization) = authorization {
+        request = request.header("authorization", authorization);
+    }
+    request.reply(&routes::oauth2_routes()).await
+}
+
+fn location(res: &warp::http::Response<bytes::Bytes>) -> String {
+    assert_eq!(302, res.status(), "{:?}", res.body());
+    res.headers()["location"].to_str().unwrap().to_string()
+}
+
+/// Returns parameters of the query or the fragment of the location.
+fn location_parameters(location: &str, separator: char) -> HashMap<String, String> {
+    let (_, params) = location.split_once(separator).unwrap();
+    serde_urlencoded::from_str(params).unwrap()
+}
+
+fn json_body(res: &warp::http::Response<bytes::Bytes>) -> serde_json::Value {
+    serde_json::from_slice(res.body()).unwrap()
+}
+
+/// Signs in to the login form of the authorization request and returns the redirect location.
+async fn login(query: &[(&str, &str)], username: &str, password: &str) -> String {
+    let res = get(&format!(
+        "/oauth2/authorize?{}",
+        serde_urlencoded::to_string(query).unwrap()
+    ))
+    .await;
+    assert!(location(&res).starts_with("/login?"));
+
+    let mut form = query.to_vec();
+    form.push(("username", username));
+    form.push(("password", password));
+    location(&post_form("/login", &form, None).await)
+}
+
+#[tokio::test]
+async fn test_authorization_code_grant_with_pkce() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (client_id, _) =
+        create_oauth_client(&user_pool_id, &["code"], &["openid", "email"], false).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(CODE_VERIFIER.as_bytes()));
+    let query = [
+        ("client_id", client_id.as_str()),
+        ("code_challenge", &code_challenge),
+        ("code_challenge_method", "S256"),
+        ("redirect_uri", CALLBACK_URL),
+        ("response_type", "code"),
+        ("scope", "openid email"),
+        ("state", "state"),
+    ];
+    let res = get(&format!(
+        "/login?{}",
+        serde_urlencoded::to_string(query).unwrap()
+    ))
+    .await;
+    assert_eq!(200, res.status());
+    let page = String::from_utf8(res.body().to_vec()).unwrap();
+    assert!(page.contains(r#"<form method="post" action="/login">"#));
+    assert!(page.contains(&client_id));
+
+    let redirected = login(&query, "username", "Passw0rd!").await;
+    assert!(redirected.starts_with(CALLBACK_URL));
+    let params = location_parameters(&redirected, '?');
+    assert_eq!("state", params["state"]);
+
+    let token_form = [
+        ("client_id", client_id.as_str()),
+        ("code", &params["code"]),
+        ("code_verifier", CODE_VERIFIER),
+        ("grant_type", "authorization_code"),
+        ("redirect_uri", CALLBACK_URL),
+    ];
+    let res = post_form("/oauth2/token", &token_form, None).await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let tokens = json_body(&res);
+    assert_eq!("Bearer", tokens["token_type"]);
+    let access_claims = user_pools::verify(tokens["access_token"].as_str().unwrap()).unwrap();
+    assert_eq!("openid email", access_claims["scope"]);
+    let id_claims = user_pools::verify(tokens["id_token"].as_str().unwrap()).unwrap();
+    assert_eq!("username", id_claims["cognito:username"]);
+
+    // codes can be used only once
+    let res = post_form("/oauth2/token", &token_form, None).await;
+    assert_eq!(400, res.status());
+    assert_eq!("invalid_grant", json_body(&res)["error"]);
+
+    let res = warp::test::request()
+        .method("GET")
+        .path("/oauth2/userInfo")
+        .header(
+            "authorization",
+            format!("Bearer {}", tokens["access_token"].as_str().unwrap()),
+        )
+        .reply(&routes::oauth2_routes())
+        .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let info = json_body(&res);
+    assert_eq!("username", info["username"]);
+    assert_eq!(id_claims["sub"], info["sub"]);
+
+    let refresh_token = tokens["refresh_token"].as_str().unwrap();
+    let refresh_form = [
+        ("client_id", client_id.as_str()),
+        ("grant_type", "refresh_token"),
+        ("refresh_token", refresh_token),
+    ];
+    let res = post_form("/oauth2/token", &refresh_form, None).await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let refreshed = json_body(&res);
+    assert!(refreshed["id_token"].is_string());
+    assert!(refreshed.get("refresh_token").is_none());
+    let access_claims = user_pools::verify(refreshed["access_token"].as_str().unwrap()).unwrap();
+    assert_eq!("openid email", access_claims["scope"]);
+
+    let res = post_form(
+        "/oauth2/revoke",
+        &[("client_id", client_id.as_str()), ("token", refresh_token)],
+        None,
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let res = post_form("/oauth2/token", &refresh_form, None).await;
+    assert_eq!(400, res.status());
+    assert_eq!("invalid_grant", json_body(&res)["error"]);
+}
+
+#[tokio::test]
+async fn test_authorization_code_with_invalid_code_verifier() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (client_id, _) = create_oauth_client(&user_pool_id, &["code"], &["openid"], false).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(CODE_VERIFIER.as_bytes()));
+    let redirected = login(
+        &[
+            ("client_id", client_id.as_str()),
+            ("code_challenge", &code_challenge),
+            ("code_challenge_method", "S256"),
+            ("redirect_uri", CALLBACK_URL),
+            ("response_type", "code"),
+        ],
+        "username",
+        "Passw0rd!",
+    )
+    .await;
+    let params = location_parameters(&redirected, '?');
+
+    let res = post_form(
+        "/oauth2/token",
+        &[
+            ("client_id", client_id.as_str()),
+            ("code", &params["code"]),
+            ("code_verifier", "invalid"),
+            ("grant_type", "authorization_code"),
+            ("redirect_uri", CALLBACK_URL),
+        ],
+        None,
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!("invalid_grant", json_body(&res)["error"]);
+}
+
+#[tokio::test]
+async fn test_implicit_grant() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (client_id, _) =
+        create_oauth_client(&user_pool_id, &["implicit"], &["openid"], false).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let redirected = login(
+        &[
+            ("client_id", client_id.as_str()),
+            ("nonce", "nonce"),
+            ("redirect_uri", CALLBACK_URL),
+            ("response_type", "token"),
+        ],
+        "username",
+        "Passw0rd!",
+    )
+    .await;
+    let params = location_parameters(&redirected, '#');
+    assert_eq!("Bearer", params["token_type"]);
+    assert!(params.contains_key("access_token"));
+    let id_claims = user_pools::verify(&params["id_token"]).unwrap();
+    assert_eq!("nonce", id_claims["nonce"]);
+}
+
+#[tokio::test]
+async fn test_implicit_grant_with_pre_token_generation() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "PreTokenGeneration": super::lambda_arn("pre-token-generation"),
+        },
+    }))
+    .await;
+    let (client_id, _) =
+        create_oauth_client(&user_pool_id, &["implicit"], &["openid"], false).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let redirected = login(
+        &[
+            ("client_id", client_id.as_str()),
+            ("redirect_uri", CALLBACK_URL),
+            ("response_type", "token"),
+        ],
+        "username",
+        "Passw0rd!",
+    )
+    .await;
+    let params = location_parameters(&redirected, '#');
+    let id_claims = user_pools::verify(&params["id_token"]).unwrap();
+    assert_eq!(
+        "TokenGeneration_HostedAuth",
+        id_claims["custom:trigger_source"]
+    );
+}
+
+#[tokio::test]
+async fn test_login_with_incorrect_password() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (client_id, _) = create_oauth_client(&user_pool_id, &["code"], &["openid"], false).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = post_form(
+        "/login",
+        &[
+            ("client_id", client_id.as_str()),
+            ("redirect_uri", CALLBACK_URL),
+            ("response_type", "code"),
+            ("username", "username"),
+            ("password", "invalid"),
+        ],
+        None,
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let page = String::from_utf8(res.body().to_vec()).unwrap();
+    assert!(page.contains("Incorrect username or password."));
+}
+
+#[tokio::test]
+async fn test_login_with_challenge() {
+    super::setup().await;
+    // NEW_PASSWORD_REQUIRED
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
+    // MFA_SETUP
+    let mfa_user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"MfaConfiguration": "ON"})).await;
+    super::create_confirmed_user(&mfa_user_pool_id, "username", "Passw0rd!").await;
+
+    for user_pool_id in [user_pool_id, mfa_user_pool_id] {
+        let (client_id, _) =
+            create_oauth_client(&user_pool_id, &["code"], &["openid"], false).await;
+        let res = post_form(
+            "/login",
+            &[
+                ("client_id", client_id.as_str()),
+                ("redirect_uri", CALLBACK_URL),
+                ("response_type", "code"),
+                ("username", "username"),
+                ("password", "Passw0rd!"),
+            ],
+            None,
+        )
+        .await;
+        assert_eq!(200, res.status());
+        let page = String::from_utf8(res.body().to_vec()).unwrap();
+        assert!(page.contains("Sign-in with a challenge is not supported by the hosted UI."));
+    }
+}
+
+#[tokio::test]
+async fn test_authorize_validates_client_settings() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (client_id, _) = create_oauth_client(&user_pool_id, &["code"], &["openid"], false).await;
+
+    let res = get(&format!(
+        "/oauth2/authorize?client_id={}&response_type=code&redirect_uri=https://example.com/other",
+        client_id
+    ))
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!("redirect_mismatch", json_body(&res)["error"]);
+
+    let res = get(&format!(
+        "/oauth2/authorize?client_id={}&response_type=code&redirect_uri={}&scope=email&state=s",
+        client_id, CALLBACK_URL
+    ))
+    .await;
+    let params = location_parameters(&location(&res), '?');
+    assert_eq!("invalid_scope", params["error"]);
+    assert_eq!("s", params["state"]);
+
+    let res = get(&format!(
+        "/oauth2/authorize?client_id={}&response_type=token&redirect_uri={}",
+        client_id, CALLBACK_URL
+    ))
+    .await;
+    let params = location_parameters(&location(&res), '?');
+    assert_eq!("unauthorized_client", params["error"]);
+
+    let other_client_id = super::create_user_pool_client(&user_pool_id).await;
+    let res = get(&format!(
+        "/oauth2/authorize?client_id={}&response_type=code&redirect_uri={}",
+        other_client_id, CALLBACK_URL
+    ))
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!("unauthorized_client", json_body(&res)["error"]);
+}
+
+#[tokio::test]
+async fn test_client_credentials_grant() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (client_id, client_secret) = create_oauth_client(
+        &user_pool_id,
+        &["client_credentials"],
+        &["https://api.example.com/read"],
+        true,
+    )
+    .await;
+    let credentials = STANDARD.encode(format!("{}:{}", client_id, client_secret.unwrap()));
+
+    let res = post_form(
+        "/oauth2/token",
+        &[("grant_type", "client_credentials")],
+        Some(&format!("Basic {}", credentials)),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let tokens = json_body(&res);
+    assert!(tokens.get("id_token").is_none());
+    let claims = user_pools::verify(tokens["access_token"].as_str().unwrap()).unwrap();
+    assert_eq!(client_id, claims["sub"]);
+    assert_eq!("https://api.example.com/read", claims["scope"]);
+
+    let res = post_form(
+        "/oauth2/token",
+        &[
+            ("client_id", client_id.as_str()),
+            ("grant_type", "client_credentials"),
+        ],
+        None,
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!("invalid_client", json_body(&res)["error"]);
+}
+
+#[tokio::test]
+async fn test_user_info_without_token() {
+    super::setup().await;
+
+    let res = get("/oauth2/userInfo").await;
+    assert_eq!(401, res.status());
+    assert!(res.headers().contains_key("www-authenticate"));
+}
+
+#[tokio::test]
+async fn test_logout() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let (client_id, _) = create_oauth_client(&user_pool_id, &["code"], &["openid"], false).await;
+
+    let res = get(&format!(
+        "/logout?client_id={}&logout_uri={}",
+        client_id, LOGOUT_URL
+    ))
+    .await;
+    assert_eq!(LOGOUT_URL, location(&res));
+
+    let res = get(&format!(
+        "/logout?client_id={}&response_type=code&redirect_uri={}",
+        client_id, CALLBACK_URL
+    ))
+    .await;
+    assert!(location(&res).starts_with("/login?"));
+
+    let res = get(&format!(
+        "/logout?client_id={}&logout_uri=https://example.com/other",
+        client_id
+    ))
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!("redirect_mismatch", json_body(&res)["error"]);
+}
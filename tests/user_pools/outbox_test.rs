@@ -0,0 +1,169 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_sign_up_code_is_captured() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"AutoVerifiedAttributes": ["email"]}))
+            .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::sign_up(&client_id, "username").await;
+
+    let messages = super::sent_messages(&user_pool_id, "username").await;
+    assert_eq!(1, messages.len());
+    let code = super::issued_code(&user_pool_id, "username", user_pools::CodePurpose::SignUp);
+    assert_eq!("SignUp", messages[0]["MessageType"]);
+    assert_eq!("EMAIL", messages[0]["DeliveryMedium"]);
+    assert_eq!("user@example.com", messages[0]["Destination"]);
+    assert_eq!("Your verification code", messages[0]["Subject"]);
+    assert_eq!(
+        format!("Your verification code is {}. ", code),
+        messages[0]["Body"]
+    );
+    assert_eq!(code, messages[0]["Code"]);
+}
+
+#[tokio::test]
+async fn test_verification_message_of_user_pool() {
+    super::setup().await;
+    let user_pool_id = &super::create_user_pool_with(serde_json::json!({
+        "AutoVerifiedAttributes": ["email"],
+        "VerificationMessageTemplate": {
+            "EmailMessage": "Your code is {####}",
+            "EmailSubject": "Welcome",
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(user_pool_id).await;
+    super::sign_up(&client_id, "username").await;
+
+    let messages = super::sent_messages(user_pool_id, "username").await;
+    let code = super::issued_code(user_pool_id, "username", user_pools::CodePurpose::SignUp);
+    assert_eq!("Welcome", messages[0]["Subject"]);
+    assert_eq!(format!("Your code is {}", code), messages[0]["Body"]);
+}
+
+#[tokio::test]
+async fn test_invitation_is_captured() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::post_action(
+        "AdminCreateUser",
+        &serde_json::json!({
+            "DesiredDeliveryMediums": ["EMAIL", "SMS"],
+            "TemporaryPassword": "Passw0rd!",
+            "UserAttributes": [
+                {"Name": "email", "Value": "user@example.com"},
+                {"Name": "phone_number", "Value": "+12345556789"},
+            ],
+            "Username": "username",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+
+    let messages = super::sent_messages(&user_pool_id, "username").await;
+    assert_eq!(2, messages.len());
+    assert_eq!("AdminCreateUser", messages[0]["MessageType"]);
+    assert_eq!("user@example.com", messages[0]["Destination"]);
+    assert_eq!("Your temporary password", messages[0]["Subject"]);
+    assert_eq!(
+        "Your username is username and temporary password is Passw0rd!. ",
+        messages[0]["Body"]
+    );
+    assert_eq!("SMS", messages[1]["DeliveryMedium"]);
+    assert_eq!("+12345556789", messages[1]["Destination"]);
+    assert!(messages[1].get("Subject").is_none());
+}
+
+#[tokio::test]
+async fn test_suppressed_invitation_is_not_captured() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    super::create_user(&user_pool_id, "username").await;
+
+    assert!(super::sent_messages(&user_pool_id, "username")
+        .await
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_remove_messages() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"AutoVerifiedAttributes": ["email"]}))
+            .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::sign_up(&client_id, "username").await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("DELETE")
+        .path(&format!("/_outbox?UserPoolId={}", user_pool_id))
+        .reply(&filter)
+        .await;
+    assert_eq!(204, res.status());
+    assert!(super::sent_messages(&user_pool_id, "username")
+        .await
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_custom_message_trigger() {
+    super::setup().await;
+    let user_pool_id = &super::create_user_pool_with(serde_json::json!({
+        "AutoVerifiedAttributes": ["email"],
+        "LambdaConfig": {"CustomMessage": super::lambda_arn("custom-message")},
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(user_pool_id).await;
+
+    let res = super::post_action(
+        "SignUp",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "ClientMetadata": {"brand": "Acme"},
+            "Password": "Passw0rd!",
+            "UserAttributes": [{"Name": "email", "Value": "user@example.com"}],
+            "Username": "username",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let messages = super::sent_messages(user_pool_id, "username").await;
+    let code = super::issued_code(user_pool_id, "username", user_pools::CodePurpose::SignUp);
+    assert_eq!("Acme verification", messages[0]["Subject"]);
+    assert_eq!(
+        format!("<p>{} is your code.</p>", code),
+        messages[0]["Body"]
+    );
+
+    let res = super::post_action(
+        "AdminCreateUser",
+        &serde_json::json!({
+            "DesiredDeliveryMediums": ["EMAIL", "SMS"],
+            "TemporaryPassword": "Passw0rd!",
+            "UserAttributes": [
+                {"Name": "email", "Value": "invited@example.com"},
+                {"Name": "phone_number", "Value": "+12345556789"},
+            ],
+            "Username": "invited",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let messages = super::sent_messages(user_pool_id, "invited").await;
+    assert_eq!("Welcome to Example", messages[0]["Subject"]);
+    assert_eq!("Sign in as invited with Passw0rd!", messages[0]["Body"]);
+    // the handler returns no SMS message, the default one is sent.
+    assert_eq!(
+        "Your username is invited and temporary password is Passw0rd!. ",
+        messages[1]["Body"]
+    );
+}
@@ -0,0 +1,66 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"AutoVerifiedAttributes": ["email"]}))
+            .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::sign_up(&client_id, "username").await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ResendConfirmationCode",
+        )
+        .body(
+            r#"{
+            "AnalyticsMetadata": { 
+               "AnalyticsEndpointId": "string"
+            },
+            "ClientId": "client_id",
+            "ClientMetadata": { 
+               "string" : "string" 
+            },
+            "UserContextData": { 
+               "EncodedData": "string"
+            },
+            "Username": "username"
+         }"#
+            .replace("client_id", &client_id),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("EMAIL", body["CodeDeliveryDetails"]["DeliveryMedium"]);
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.ResendConfirmationCode",
+        )
+        .body(r#"{"ClientId": "string", "Username": ""}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
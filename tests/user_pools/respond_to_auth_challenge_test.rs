@@ -0,0 +1,409 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_invalid_session() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
+        )
+        .body(
+            r#"{
+            "AnalyticsMetadata": { 
+               "AnalyticsEndpointId": "string"
+            },
+            "ChallengeName": "NEW_PASSWORD_REQUIRED",
+            "ChallengeResponses": { 
+               "USERNAME" : "username" 
+            },
+            "ClientId": "client_id",
+            "ClientMetadata": { 
+               "string" : "string" 
+            },
+            "Session": "00000000000000000000",
+            "UserContextData": { 
+               "EncodedData": "string"
+            }
+         }"#
+            .replace("client_id", &client_id),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
+        )
+        .body(
+            r#"{
+            "AnalyticsMetadata": { 
+               "AnalyticsEndpointId": "string"
+            },
+            "ChallengeName": "NEW_PASSWORD_REQUIRED",
+            "ChallengeResponses": { 
+               "string" : "string" 
+            },
+            "ClientId": "",
+            "ClientMetadata": { 
+               "string" : "string" 
+            },
+            "Session": "string",
+            "UserContextData": { 
+               "EncodedData": "string"
+            }
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_incorrect_password_claim_signature() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_SRP_AUTH",
+            "AuthParameters": {"USERNAME": "username", "SRP_A": "abcdef0123456789"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let secret_block = body["ChallengeParameters"]["SECRET_BLOCK"].clone();
+
+    let res = super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "PASSWORD_VERIFIER",
+            "ChallengeResponses": {
+                "USERNAME": "username",
+                "PASSWORD_CLAIM_SECRET_BLOCK": secret_block,
+                "PASSWORD_CLAIM_SIGNATURE": "c2lnbmF0dXJl",
+                "TIMESTAMP": "Tue Sep 5 08:02:03 UTC 2023",
+            },
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_new_password_required() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_user(&user_pool_id, "username").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let challenge: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("NEW_PASSWORD_REQUIRED", challenge["ChallengeName"]);
+    assert!(challenge.get("AuthenticationResult").is_none());
+
+    let body = serde_json::json!({
+        "ChallengeName": "NEW_PASSWORD_REQUIRED",
+        "ChallengeResponses": {"USERNAME": "username", "NEW_PASSWORD": "password"},
+        "ClientId": client_id,
+        "Session": challenge["Session"],
+    });
+    let res = super::post_action("RespondToAuthChallenge", &body.to_string()).await;
+    assert_eq!(
+        "InvalidPasswordException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    // the session is used once even if the response is rejected
+    let res = super::post_action("RespondToAuthChallenge", &body.to_string()).await;
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_software_token_mfa() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"MfaConfiguration": "OPTIONAL"})).await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let secret = super::enable_software_token_mfa(result["AccessToken"].as_str().unwrap()).await;
+
+    let initiate_auth = serde_json::json!({
+        "AuthFlow": "USER_PASSWORD_AUTH",
+        "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+        "ClientId": client_id,
+    });
+    let res = super::post_action("InitiateAuth", &initiate_auth.to_string()).await;
+    assert_eq!(200, res.status());
+    let challenge: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("SOFTWARE_TOKEN_MFA", challenge["ChallengeName"]);
+
+    let code = super::totp_code(&secret);
+    let wrong_code = if code == "000000" { "111111" } else { "000000" };
+    let res = super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "SOFTWARE_TOKEN_MFA",
+            "ChallengeResponses": {"USERNAME": "username", "SOFTWARE_TOKEN_MFA_CODE": wrong_code},
+            "ClientId": client_id,
+            "Session": challenge["Session"],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(
+        "CodeMismatchException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    // the session can be retried after a wrong code
+    let res = super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "SOFTWARE_TOKEN_MFA",
+            "ChallengeResponses": {"USERNAME": "username", "SOFTWARE_TOKEN_MFA_CODE": code},
+            "ClientId": client_id,
+            "Session": challenge["Session"],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body["AuthenticationResult"]["AccessToken"].is_string());
+}
+
+#[tokio::test]
+async fn test_sms_mfa() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"MfaConfiguration": "ON"})).await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_phone_number(&user_pool_id, "username").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let challenge: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("SMS_MFA", challenge["ChallengeName"]);
+    assert_eq!(
+        "+*******6789",
+        challenge["ChallengeParameters"]["CODE_DELIVERY_DESTINATION"]
+    );
+    assert_eq!(
+        "SMS",
+        challenge["ChallengeParameters"]["CODE_DELIVERY_DELIVERY_MEDIUM"]
+    );
+
+    let messages = super::sent_messages(&user_pool_id, "username").await;
+    assert_eq!("Authentication", messages[0]["MessageType"]);
+    let code = messages[0]["Code"].as_str().unwrap();
+    let wrong_code = if code == "000000" { "111111" } else { "000000" };
+    let res = super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "SMS_MFA",
+            "ChallengeResponses": {"USERNAME": "username", "SMS_MFA_CODE": wrong_code},
+            "ClientId": client_id,
+            "Session": challenge["Session"],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(
+        "CodeMismatchException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "SMS_MFA",
+            "ChallengeResponses": {"USERNAME": "username", "SMS_MFA_CODE": code},
+            "ClientId": client_id,
+            "Session": challenge["Session"],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert!(body["AuthenticationResult"]["AccessToken"].is_string());
+}
+
+#[tokio::test]
+async fn test_select_mfa_type() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"MfaConfiguration": "OPTIONAL"})).await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_phone_number(&user_pool_id, "username").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    super::enable_software_token_mfa(result["AccessToken"].as_str().unwrap()).await;
+    let res = super::post_action(
+        "AdminSetUserMFAPreference",
+        &serde_json::json!({
+            "SMSMfaSettings": {"Enabled": true, "PreferredMfa": false},
+            "SoftwareTokenMfaSettings": {"Enabled": true, "PreferredMfa": false},
+            "Username": "username",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    let challenge: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("SELECT_MFA_TYPE", challenge["ChallengeName"]);
+
+    let res = super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "SELECT_MFA_TYPE",
+            "ChallengeResponses": {"USERNAME": "username", "ANSWER": "EMAIL_OTP"},
+            "ClientId": client_id,
+            "Session": challenge["Session"],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+
+    let res = super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "SELECT_MFA_TYPE",
+            "ChallengeResponses": {"USERNAME": "username", "ANSWER": "SMS_MFA"},
+            "ClientId": client_id,
+            "Session": challenge["Session"],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let challenge: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("SMS_MFA", challenge["ChallengeName"]);
+    assert_eq!(
+        "+*******6789",
+        challenge["ChallengeParameters"]["CODE_DELIVERY_DESTINATION"]
+    );
+}
+
+#[tokio::test]
+async fn test_new_password_required_with_pre_token_generation() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "PreTokenGeneration": super::lambda_arn("pre-token-generation"),
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_user(&user_pool_id, "username").await;
+
+    let res = super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "USER_PASSWORD_AUTH",
+            "AuthParameters": {"USERNAME": "username", "PASSWORD": "Passw0rd!"},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let challenge: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("NEW_PASSWORD_REQUIRED", challenge["ChallengeName"]);
+
+    let res = super::post_action(
+        "RespondToAuthChallenge",
+        &serde_json::json!({
+            "ChallengeName": "NEW_PASSWORD_REQUIRED",
+            "ChallengeResponses": {"USERNAME": "username", "NEW_PASSWORD": "NewPassw0rd!"},
+            "ClientId": client_id,
+            "Session": challenge["Session"],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let id_claims =
+        user_pools::verify(body["AuthenticationResult"]["IdToken"].as_str().unwrap()).unwrap();
+    assert_eq!(
+        "TokenGeneration_NewPasswordChallenge",
+        id_claims["custom:trigger_source"]
+    );
+}
@@ -0,0 +1,132 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+async fn refresh(client_id: &str, refresh_token: &str) -> warp::http::Response<bytes::Bytes> {
+    super::post_action(
+        "InitiateAuth",
+        &serde_json::json!({
+            "AuthFlow": "REFRESH_TOKEN_AUTH",
+            "AuthParameters": {"REFRESH_TOKEN": refresh_token},
+            "ClientId": client_id,
+        })
+        .to_string(),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let other = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let refresh_token = result["RefreshToken"].as_str().unwrap();
+
+    let res = super::post_action(
+        "RevokeToken",
+        &serde_json::json!({"ClientId": client_id, "Token": refresh_token}).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    assert!(res.body().is_empty());
+
+    let res = refresh(&client_id, refresh_token).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::post_action(
+        "AssociateSoftwareToken",
+        &serde_json::json!({"AccessToken": result["AccessToken"]}).to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "NotAuthorizedException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    // tokens of other sessions are still valid
+    let res = refresh(&client_id, other["RefreshToken"].as_str().unwrap()).await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.RevokeToken",
+        )
+        .body(r#"{"ClientId":"client_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_access_token_is_not_supported() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "RevokeToken",
+        &serde_json::json!({"ClientId": client_id, "Token": result["AccessToken"]}).to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UnsupportedTokenTypeException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_token_revocation_disabled() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let res = super::post_action(
+        "CreateUserPoolClient",
+        &serde_json::json!({
+            "ClientName": "client_name",
+            "EnableTokenRevocation": false,
+            "ExplicitAuthFlows": ["ALLOW_USER_PASSWORD_AUTH", "ALLOW_REFRESH_TOKEN_AUTH"],
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let client_id = body["UserPoolClient"]["ClientId"].as_str().unwrap();
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "RevokeToken",
+        &serde_json::json!({"ClientId": client_id, "Token": result["RefreshToken"]}).to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UnsupportedOperationException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
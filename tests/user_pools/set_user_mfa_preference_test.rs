@@ -0,0 +1,96 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    super::enable_software_token_mfa(result["AccessToken"].as_str().unwrap()).await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUserMFAPreference",
+        )
+        .body(
+            r#"{
+            "AccessToken": "access_token",
+            "SoftwareTokenMfaSettings": {
+               "Enabled": true,
+               "PreferredMfa": true
+            }
+         }"#
+            .replace("access_token", result["AccessToken"].as_str().unwrap()),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    assert!(res.body().is_empty());
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "username", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("SOFTWARE_TOKEN_MFA", body["PreferredMfaSetting"]);
+    assert_eq!(
+        serde_json::json!(["SOFTWARE_TOKEN_MFA"]),
+        body["UserMFASettingList"]
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.SetUserMFAPreference",
+        )
+        .body(r#"{"SoftwareTokenMfaSettings":{"Enabled":true}}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_software_token_not_verified() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "SetUserMFAPreference",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "SoftwareTokenMfaSettings": {"Enabled": true},
+        })
+        .to_string(),
+    )
+    .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,245 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"AutoVerifiedAttributes": ["email"]}))
+            .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp")
+        .body(
+            r#"{
+            "AnalyticsMetadata": { 
+               "AnalyticsEndpointId": "string"
+            },
+            "ClientId": "client_id",
+            "ClientMetadata": { 
+               "string" : "string" 
+            },
+            "Password": "Passw0rd!",
+            "UserAttributes": [ 
+               { 
+                  "Name": "email",
+                  "Value": "user@example.com"
+               }
+            ],
+            "UserContextData": { 
+               "EncodedData": "string"
+            },
+            "Username": "username",
+            "ValidationData": [ 
+               { 
+                  "Name": "string",
+                  "Value": "string"
+               }
+            ]
+         }"#
+            .replace("client_id", &client_id),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(false, body["UserConfirmed"]);
+    assert_eq!(
+        serde_json::json!({
+            "AttributeName": "email",
+            "DeliveryMedium": "EMAIL",
+            "Destination": "u***@e***",
+        }),
+        body["CodeDeliveryDetails"]
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp")
+        .body(
+            r#"{
+            "ClientId": "string",
+            "Password": "Passw0rd!",
+            "Username": ""
+         }"#,
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_username_exists() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::sign_up(&client_id, "username").await;
+
+    let res = super::post_action(
+        "SignUp",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "Password": "Passw0rd!",
+            "Username": "username",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UsernameExistsException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_email_as_username() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"UsernameAttributes": ["email"]})).await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let res = super::post_action(
+        "SignUp",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "Password": "Passw0rd!",
+            "Username": "user@example.com",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let user_sub = body["UserSub"].clone();
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "user@example.com", "UserPoolId": user_pool_id})
+            .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(user_sub, body["Username"]);
+    assert_eq!("UNCONFIRMED", body["UserStatus"]);
+}
+
+#[tokio::test]
+async fn test_password_not_conform_to_policy() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let res = super::post_action(
+        "SignUp",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "Password": "password",
+            "Username": "username",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidPasswordException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_sign_up_auto_confirmed_by_trigger() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "PreSignUp": super::lambda_arn("pre-sign-up"),
+            "PostConfirmation": super::lambda_arn("post-confirmation"),
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let res = super::post_action(
+        "SignUp",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "Password": "Passw0rd!",
+            "UserAttributes": [{"Name": "email", "Value": "user@example.com"}],
+            "Username": "username",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(true, body["UserConfirmed"]);
+    assert_eq!(serde_json::Value::Null, body["CodeDeliveryDetails"]);
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "username", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("CONFIRMED", body["UserStatus"]);
+    assert!(body["UserAttributes"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!({"Name": "email_verified", "Value": "true"})));
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    assert!(result["AccessToken"].is_string());
+}
+
+#[tokio::test]
+async fn test_sign_up_denied_by_trigger() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool_with(serde_json::json!({
+        "LambdaConfig": {
+            "PreSignUp": super::lambda_arn("pre-sign-up"),
+        },
+    }))
+    .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let res = super::post_action(
+        "SignUp",
+        &serde_json::json!({
+            "ClientId": client_id,
+            "Password": "Passw0rd!",
+            "Username": "username",
+            "ValidationData": [{"Name": "deny", "Value": "true"}],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "UserLambdaValidationException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+
+    let res = super::post_action(
+        "AdminGetUser",
+        &serde_json::json!({"Username": "username", "UserPoolId": user_pool_id}).to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+}
@@ -0,0 +1,136 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "UpdateUserAttributes",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "UserAttributes": [{"Name": "name", "Value": "John"}],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(serde_json::json!([]), body["CodeDeliveryDetailsList"]);
+
+    user_pools::with_store(|store| {
+        let user = store
+            .user_pool(&user_pool_id)
+            .unwrap()
+            .user("username")
+            .unwrap();
+        assert_eq!(Some(&"John".to_string()), user.attribute("name"));
+    });
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserAttributes",
+        )
+        .body(r#"{"AccessToken":"access_token"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_changed_email_is_verified_by_code() {
+    super::setup().await;
+    let user_pool_id =
+        super::create_user_pool_with(serde_json::json!({"AutoVerifiedAttributes": ["email"]}))
+            .await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_verified_email(&user_pool_id, "username").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "UpdateUserAttributes",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "UserAttributes": [{"Name": "email", "Value": "new@example.com"}],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(
+        serde_json::json!([{
+            "AttributeName": "email",
+            "DeliveryMedium": "EMAIL",
+            "Destination": "n***@e***",
+        }]),
+        body["CodeDeliveryDetailsList"]
+    );
+    let messages = super::sent_messages(&user_pool_id, "username").await;
+    let message = messages.last().unwrap();
+    assert_eq!("UpdateUserAttribute", message["MessageType"]);
+    assert_eq!("new@example.com", message["Destination"]);
+
+    let res = super::post_action(
+        "VerifyUserAttribute",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "AttributeName": "email",
+            "Code": message["Code"],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    user_pools::with_store(|store| {
+        let user = store
+            .user_pool(&user_pool_id)
+            .unwrap()
+            .user("username")
+            .unwrap();
+        assert_eq!(Some(&"true".to_string()), user.attribute("email_verified"));
+    });
+}
+
+#[tokio::test]
+async fn test_unknown_attribute() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+
+    let res = super::post_action(
+        "UpdateUserAttributes",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "UserAttributes": [{"Name": "custom:unknown", "Value": "value"}],
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
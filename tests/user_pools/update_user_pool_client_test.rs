@@ -0,0 +1,59 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+
+    let res = super::post_action(
+        "UpdateUserPoolClient",
+        &serde_json::json!({
+            "AccessTokenValidity": 2,
+            "ClientId": client_id,
+            "ExplicitAuthFlows": ["ALLOW_USER_SRP_AUTH", "ALLOW_REFRESH_TOKEN_AUTH"],
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("client_name", body["UserPoolClient"]["ClientName"]);
+    assert_eq!(2, body["UserPoolClient"]["AccessTokenValidity"]);
+
+    let res = super::post_action(
+        "DescribeUserPoolClient",
+        &serde_json::json!({ "ClientId": client_id, "UserPoolId": user_pool_id }).to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!(
+        serde_json::json!(["ALLOW_USER_SRP_AUTH", "ALLOW_REFRESH_TOKEN_AUTH"]),
+        body["UserPoolClient"]["ExplicitAuthFlows"]
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserPoolClient",
+        )
+        .body(r#"{"AccessTokenValidity":0,"ClientId":"client_id","UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,68 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let res = super::post_action(
+        "UpdateUserPool",
+        &serde_json::json!({
+            "AutoVerifiedAttributes": ["email"],
+            "MfaConfiguration": "OPTIONAL",
+            "UserPoolId": user_pool_id,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+
+    let res = super::post_action(
+        "DescribeUserPool",
+        &serde_json::json!({ "UserPoolId": user_pool_id }).to_string(),
+    )
+    .await;
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("pool_name", body["UserPool"]["Name"]);
+    assert_eq!("OPTIONAL", body["UserPool"]["MfaConfiguration"]);
+    assert_eq!(
+        serde_json::json!(["email"]),
+        body["UserPool"]["AutoVerifiedAttributes"]
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_user_pool() {
+    super::setup().await;
+
+    let res = super::post_action("UpdateUserPool", r#"{"UserPoolId":"us-east-1_unknown"}"#).await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "ResourceNotFoundException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.UpdateUserPool",
+        )
+        .body(r#"{"MfaConfiguration":"ALWAYS","UserPoolId":"user_pool_id"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
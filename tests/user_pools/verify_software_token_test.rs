@@ -0,0 +1,96 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+async fn associate_software_token(access_token: &str) -> String {
+    let res = super::post_action(
+        "AssociateSoftwareToken",
+        &serde_json::json!({ "AccessToken": access_token }).to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    body["SecretCode"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let access_token = result["AccessToken"].as_str().unwrap();
+    let secret = associate_software_token(access_token).await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.VerifySoftwareToken",
+        )
+        .body(
+            serde_json::json!({
+                "AccessToken": access_token,
+                "FriendlyDeviceName": "device",
+                "UserCode": super::totp_code(&secret),
+            })
+            .to_string(),
+        )
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    assert_eq!("SUCCESS", body["Status"]);
+    assert!(body.get("Session").is_none());
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.VerifySoftwareToken",
+        )
+        .body(r#"{"AccessToken":"access_token","UserCode":"1234"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_code_mismatch() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let access_token = result["AccessToken"].as_str().unwrap();
+    let secret = associate_software_token(access_token).await;
+    let code = super::totp_code(&secret);
+    let wrong_code = if code == "000000" { "111111" } else { "000000" };
+
+    let res = super::post_action(
+        "VerifySoftwareToken",
+        &serde_json::json!({ "AccessToken": access_token, "UserCode": wrong_code }).to_string(),
+    )
+    .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "EnableSoftwareTokenMFAException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
@@ -0,0 +1,110 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_success_to_request() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_phone_number(&user_pool_id, "username").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let res = super::post_action(
+        "GetUserAttributeVerificationCode",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "AttributeName": "phone_number",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    let code = super::issued_code(
+        &user_pool_id,
+        "username",
+        user_pools::CodePurpose::VerifyAttribute("phone_number".to_string()),
+    );
+
+    let res = super::post_action(
+        "VerifyUserAttribute",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "AttributeName": "phone_number",
+            "Code": code,
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+    assert!(res.body().is_empty());
+    user_pools::with_store(|store| {
+        let user = store
+            .user_pool(&user_pool_id)
+            .unwrap()
+            .user("username")
+            .unwrap();
+        assert_eq!(
+            Some(&"true".to_string()),
+            user.attribute("phone_number_verified")
+        );
+    });
+}
+
+#[tokio::test]
+async fn test_failure_to_request() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("POST")
+        .path("/")
+        .header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.VerifyUserAttribute",
+        )
+        .body(r#"{"AccessToken":"access_token","AttributeName":"email"}"#)
+        .reply(&filter)
+        .await;
+
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "InvalidParameterValue",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    )
+}
+
+#[tokio::test]
+async fn test_code_mismatch() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+    let client_id = super::create_user_pool_client(&user_pool_id).await;
+    super::create_confirmed_user(&user_pool_id, "username", "Passw0rd!").await;
+    super::set_verified_email(&user_pool_id, "username").await;
+    let result = super::sign_in(&client_id, "username", "Passw0rd!").await;
+    let res = super::post_action(
+        "GetUserAttributeVerificationCode",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "AttributeName": "email",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(200, res.status(), "{:?}", res.body());
+
+    let res = super::post_action(
+        "VerifyUserAttribute",
+        &serde_json::json!({
+            "AccessToken": result["AccessToken"],
+            "AttributeName": "email",
+            "Code": "wrong",
+        })
+        .to_string(),
+    )
+    .await;
+    assert_eq!(400, res.status());
+    assert_eq!(
+        "CodeMismatchException",
+        res.headers().get("x-amzn-ErrorType").unwrap()
+    );
+}
@@ -0,0 +1,62 @@
+use fakey_cognito::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_jwks() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path(&format!("/{}/.well-known/jwks.json", user_pool_id))
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let key = &body["keys"][0];
+    assert_eq!("RSA", key["kty"]);
+    assert_eq!("RS256", key["alg"]);
+    assert_eq!("sig", key["use"]);
+    assert_eq!(user_pools::signing_key().kid, key["kid"]);
+}
+
+#[tokio::test]
+async fn test_openid_configuration() {
+    super::setup().await;
+    let user_pool_id = super::create_user_pool().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path(&format!(
+            "/{}/.well-known/openid-configuration",
+            user_pool_id
+        ))
+        .reply(&filter)
+        .await;
+
+    assert_eq!(200, res.status());
+    let body: serde_json::Value = serde_json::from_slice(res.body()).unwrap();
+    let issuer = format!("http://localhost:8080/{}", user_pool_id);
+    assert_eq!(issuer, body["issuer"]);
+    assert_eq!(
+        format!("{}/.well-known/jwks.json", issuer),
+        body["jwks_uri"]
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_user_pool() {
+    super::setup().await;
+
+    let filter = routes::user_pools_routes();
+    let res = warp::test::request()
+        .method("GET")
+        .path("/us-east-1_unknown/.well-known/jwks.json")
+        .reply(&filter)
+        .await;
+
+    assert_eq!(404, res.status());
+}